@@ -0,0 +1,36 @@
+//! Benchmarks for emoji shortcode replacement on comment-sized bodies.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use trakt_core::EmojiString;
+
+const PLAIN: &str = "This show was great, the ending was a bit rushed though. ";
+const SHORTCODES: &str = "Loved it :heart: :fire: best episode ever :tada: 10/10 :star: ";
+const COLONS: &str = "Times: 10:30, 11:45 and 12:15 :: see note: the end: ";
+
+fn bench_from_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("EmojiString::from");
+    for (name, unit) in [
+        ("plain", PLAIN),
+        ("shortcodes", SHORTCODES),
+        ("colons", COLONS),
+    ] {
+        for repeat in [1, 100, 1000] {
+            let input = unit.repeat(repeat);
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new(name, input.len()), &input, |b, input| {
+                b.iter(|| EmojiString::from(black_box(input.as_str())));
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_deserialize(c: &mut Criterion) {
+    let json = serde_json::to_string(&SHORTCODES.repeat(1000)).unwrap();
+    c.bench_function("EmojiString::deserialize", |b| {
+        b.iter(|| serde_json::from_str::<EmojiString>(black_box(&json)).unwrap());
+    });
+}
+
+criterion_group!(benches, bench_from_str, bench_deserialize);
+criterion_main!(benches);
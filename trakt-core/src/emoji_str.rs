@@ -1,4 +1,4 @@
-use std::{fmt::Formatter, ops::Deref};
+use std::{borrow::Cow, fmt::Formatter, ops::Deref};
 
 use serde::{de::Error, Deserialize, Deserializer};
 
@@ -19,42 +19,61 @@ impl Deref for EmojiString {
     }
 }
 
-impl From<&str> for EmojiString {
-    fn from(mut value: &str) -> Self {
-        let mut o = String::new();
-
-        // Shamelessly stolen from:
-        // https://github.com/rossmacarthur/emojis/blob/b088b129c59124df8c3d7c3a5aff116114c78acf/examples/replace.rs#L23
-        // The meaning of the index values is as follows.
-        //
-        //  : r o c k e t :
-        // ^ ^           ^ ^
-        // i m           n j
-        //
-        // i..j gives ":rocket:"
-        // m..n gives "rocket"
-        while let Some((i, m, n, j)) = value
-            .find(':')
-            .map(|i| (i, i + 1))
-            .and_then(|(i, m)| value[m..].find(':').map(|x| (i, m, m + x, m + x + 1)))
-        {
-            if let Some(emoji) = emojis::get_by_shortcode(&value[m..n]) {
+impl EmojiString {
+    /// Replaces emoji shortcodes in `s` with their respective unicode
+    /// characters.
+    ///
+    /// Only allocates if `s` contains at least one known shortcode.
+    #[must_use]
+    pub fn replace_shortcodes(s: &str) -> Cow<'_, str> {
+        let mut out: Option<String> = None;
+        // End of the input that has been copied to `out`
+        let mut written = 0;
+        // Position of the colon that may start a shortcode
+        let mut open: Option<usize> = None;
+
+        for close in memchr::memchr_iter(b':', s.as_bytes()) {
+            let Some(start) = open else {
+                open = Some(close);
+                continue;
+            };
+
+            if let Some(emoji) = emojis::get_by_shortcode(&s[start + 1..close]) {
+                let out = out.get_or_insert_with(|| String::with_capacity(s.len()));
                 // Output everything preceding, except the first colon
-                o.push_str(&value[..i]);
-                // Output the emoji.
-                o.push_str(emoji.as_str());
-                // Update the string to past the last colon.
-                value = &value[j..];
+                out.push_str(&s[written..start]);
+                // Output the emoji
+                out.push_str(emoji.as_str());
+                // Continue past the last colon
+                written = close + 1;
+                open = None;
             } else {
-                // Output everything preceding but not including the colon
-                o.push_str(&value[..n]);
-                // Update the string to start with the last colon
-                value = &value[n..];
+                // The closing colon may start the next shortcode
+                open = Some(close);
             }
         }
 
-        o.push_str(value);
-        Self(o)
+        out.map_or(Cow::Borrowed(s), |mut out| {
+            out.push_str(&s[written..]);
+            Cow::Owned(out)
+        })
+    }
+}
+
+impl From<&str> for EmojiString {
+    fn from(value: &str) -> Self {
+        Self(Self::replace_shortcodes(value).into_owned())
+    }
+}
+
+impl From<String> for EmojiString {
+    /// Reuses the allocation of `value` if it contains no shortcodes.
+    fn from(value: String) -> Self {
+        let replaced = match Self::replace_shortcodes(&value) {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(s) => Some(s),
+        };
+        Self(replaced.unwrap_or(value))
     }
 }
 
@@ -71,7 +90,7 @@ impl<'de> Deserialize<'de> for EmojiString {
     {
         struct Visitor;
 
-        impl<'de> serde::de::Visitor<'de> for Visitor {
+        impl serde::de::Visitor<'_> for Visitor {
             type Value = EmojiString;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
@@ -85,6 +104,13 @@ impl<'de> Deserialize<'de> for EmojiString {
                 Ok(v.into())
             }
 
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(v.into())
+            }
+
             fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
             where
                 E: Error,
@@ -120,6 +146,34 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_replace_shortcodes() {
+        assert!(matches!(
+            EmojiString::replace_shortcodes("launch nothing"),
+            Cow::Borrowed("launch nothing")
+        ));
+        assert!(matches!(
+            EmojiString::replace_shortcodes("? :unknown: emoji"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            EmojiString::replace_shortcodes("launch :rocket:"),
+            Cow::Owned(s) if s == "launch 🚀"
+        ));
+    }
+
+    #[test]
+    pub fn test_from_string() {
+        let s = String::from("launch nothing");
+        let ptr = s.as_ptr();
+        let e = EmojiString::from(s);
+        assert_eq!(&*e, "launch nothing");
+        assert_eq!(e.as_ptr(), ptr);
+
+        let e = EmojiString::from(String::from(":rocket::rocket:"));
+        assert_eq!(&*e, "🚀🚀");
+    }
+
     #[test]
     pub fn test_deserialize() {
         let tests = [
@@ -135,5 +189,10 @@ mod tests {
             let i: EmojiString = serde_json::from_str(&format!("\"{i}\"")).unwrap();
             assert_eq!(&*i, o);
         }
+
+        for (i, o) in tests {
+            let i: EmojiString = serde_json::from_value(serde_json::Value::from(i)).unwrap();
+            assert_eq!(&*i, o);
+        }
     }
 }
@@ -0,0 +1,151 @@
+use std::env::VarError;
+
+use crate::{error::EnvError, Context};
+
+/// Environment variable holding the client ID.
+pub const CLIENT_ID_VAR: &str = "TRAKT_CLIENT_ID";
+/// Environment variable holding the OAuth token.
+pub const OAUTH_TOKEN_VAR: &str = "TRAKT_OAUTH_TOKEN";
+/// Environment variable holding the base URL.
+pub const BASE_URL_VAR: &str = "TRAKT_BASE_URL";
+
+/// Default base URL used when [`BASE_URL_VAR`] is not set.
+pub const DEFAULT_BASE_URL: &str = "https://api.trakt.tv";
+
+/// Owned version of [`Context`].
+///
+/// [`Context`] borrows its fields, so this struct holds the values that a
+/// [`Context`] is created from with [`ContextBuf::as_context`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContextBuf {
+    /// The base URL for the API.
+    pub base_url: String,
+    /// The client ID for the API.
+    pub client_id: String,
+    /// The OAuth token for the API, if requesting an authenticated endpoint.
+    pub oauth_token: Option<String>,
+}
+
+impl ContextBuf {
+    /// Reads the context from the environment.
+    ///
+    /// | Variable            | Required | Default                |
+    /// |---------------------|----------|------------------------|
+    /// | `TRAKT_CLIENT_ID`   | Yes      |                        |
+    /// | `TRAKT_OAUTH_TOKEN` | No       |                        |
+    /// | `TRAKT_BASE_URL`    | No       | `https://api.trakt.tv` |
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the required variables that are missing, or if
+    /// any variable is not valid unicode.
+    pub fn from_env() -> Result<Self, EnvError> {
+        Self::from_vars(std::env::var)
+    }
+
+    fn from_vars(var: impl Fn(&'static str) -> Result<String, VarError>) -> Result<Self, EnvError> {
+        let get = |key| match var(key) {
+            Ok(value) => Ok(Some(value)),
+            Err(VarError::NotPresent) => Ok(None),
+            Err(VarError::NotUnicode(_)) => Err(EnvError::NotUnicode(key)),
+        };
+
+        let client_id = get(CLIENT_ID_VAR)?;
+        let oauth_token = get(OAUTH_TOKEN_VAR)?;
+        let base_url = get(BASE_URL_VAR)?;
+
+        let Some(client_id) = client_id else {
+            return Err(EnvError::Missing(vec![CLIENT_ID_VAR]));
+        };
+
+        Ok(Self {
+            base_url: base_url.unwrap_or_else(|| DEFAULT_BASE_URL.to_owned()),
+            client_id,
+            oauth_token,
+        })
+    }
+
+    /// Borrows the context for use in a request.
+    #[must_use]
+    pub fn as_context(&self) -> Context<'_> {
+        Context {
+            base_url: &self.base_url,
+            client_id: &self.client_id,
+            oauth_token: self.oauth_token.as_deref(),
+        }
+    }
+}
+
+impl From<Context<'_>> for ContextBuf {
+    fn from(value: Context<'_>) -> Self {
+        Self {
+            base_url: value.base_url.to_owned(),
+            client_id: value.client_id.to_owned(),
+            oauth_token: value.oauth_token.map(ToOwned::to_owned),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, ffi::OsString};
+
+    use super::*;
+
+    fn vars(vars: &[(&'static str, &str)]) -> impl Fn(&'static str) -> Result<String, VarError> {
+        let vars = vars
+            .iter()
+            .map(|&(k, v)| (k, v.to_owned()))
+            .collect::<HashMap<_, _>>();
+        move |key| vars.get(key).cloned().ok_or(VarError::NotPresent)
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let ctx = ContextBuf::from_vars(vars(&[(CLIENT_ID_VAR, "abc")])).unwrap();
+        assert_eq!(
+            ctx.as_context(),
+            Context {
+                base_url: DEFAULT_BASE_URL,
+                client_id: "abc",
+                oauth_token: None,
+            }
+        );
+
+        let ctx = ContextBuf::from_vars(vars(&[
+            (CLIENT_ID_VAR, "abc"),
+            (OAUTH_TOKEN_VAR, "token"),
+            (BASE_URL_VAR, "https://api-staging.trakt.tv"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            ctx.as_context(),
+            Context {
+                base_url: "https://api-staging.trakt.tv",
+                client_id: "abc",
+                oauth_token: Some("token"),
+            }
+        );
+        assert_eq!(ContextBuf::from(ctx.as_context()), ctx);
+    }
+
+    #[test]
+    fn test_from_vars_errors() {
+        let err = ContextBuf::from_vars(vars(&[(OAUTH_TOKEN_VAR, "token")])).unwrap_err();
+        assert_eq!(err, EnvError::Missing(vec![CLIENT_ID_VAR]));
+        assert_eq!(
+            err.to_string(),
+            "Missing environment variables: TRAKT_CLIENT_ID"
+        );
+
+        let err = ContextBuf::from_vars(|key| {
+            if key == OAUTH_TOKEN_VAR {
+                Err(VarError::NotUnicode(OsString::new()))
+            } else {
+                Ok("value".to_owned())
+            }
+        })
+        .unwrap_err();
+        assert_eq!(err, EnvError::NotUnicode(OAUTH_TOKEN_VAR));
+    }
+}
@@ -3,6 +3,7 @@
 use http::{header::InvalidHeaderValue, StatusCode};
 
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum ApiError {
     #[error("Bad Request")]
     BadRequest,
@@ -66,6 +67,7 @@ impl From<StatusCode> for ApiError {
 
 /// Error type for converting a request into an HTTP request.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum IntoHttpError {
     #[error("JSON Error: {0}")]
     Json(#[from] serde_json::Error),
@@ -79,11 +81,31 @@ pub enum IntoHttpError {
     QueryParams(#[from] serde_urlencoded::ser::Error),
     #[error("Missing oauth token")]
     MissingToken,
+    #[error(
+        "Invalid oauth token: tokens must be non-empty and only contain visible ASCII characters, \
+         check that the token wasn't read with surrounding whitespace or newlines"
+    )]
+    InvalidToken,
     #[error("Validation Error: {0}")]
     Validation(String),
+    #[error("Pagination Error: {0}")]
+    Pagination(#[from] PaginationError),
+}
+
+/// Error type for invalid [`Pagination`](crate::Pagination) values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum PaginationError {
+    #[error("Page must be at least 1")]
+    ZeroPage,
+    #[error("Limit must be at least 1")]
+    ZeroLimit,
+    #[error("Limit must be at most {max}, got {0}", max = crate::Pagination::MAX_LIMIT)]
+    LimitTooLarge(usize),
 }
 
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum FromHttpError {
     #[error("API Error: {0}")]
     Api(#[from] ApiError),
@@ -92,6 +114,7 @@ pub enum FromHttpError {
 }
 
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum DeserializeError {
     #[error("JSON Error: {0}")]
     Json(#[from] serde_json::Error),
@@ -99,9 +122,12 @@ pub enum DeserializeError {
     Header(#[from] HeaderError),
     #[error("Integer Parse Error: {0}")]
     ParseInt(#[from] std::num::ParseIntError),
+    #[error("Body Too Large: {size} bytes exceeds the maximum of {max} bytes")]
+    BodyTooLarge { size: usize, max: usize },
 }
 
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum HeaderError {
     #[error("Invalid Header Value: {0}")]
     ToStrError(#[from] http::header::ToStrError),
@@ -110,6 +136,7 @@ pub enum HeaderError {
 }
 
 #[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
 pub enum UrlError {
     #[error("{0}")]
     Message(String),
@@ -120,9 +147,28 @@ pub enum UrlError {
     #[error("Value not supported")]
     ValueNotSupported,
     #[error("Key not found: {0}")]
-    KeyNotFound(&'static str),
+    KeyNotFound(String),
     #[error("Unfilled field: {0}")]
     UnfilledField(String),
+    /// A path parameter has a value although the parameter `{0}` before it was
+    /// omitted, which would shift the value into its segment.
+    #[error("Path parameter given after omitted parameter: {0}")]
+    ValueAfterOmitted(String),
+    /// The base URL isn't an ASCII `http` or `https` URL with a host.
+    #[error("Invalid base URL: {0}")]
+    InvalidBaseUrl(String),
+}
+
+/// Error type for reading a [`ContextBuf`](crate::ContextBuf) from the
+/// environment.
+#[cfg(feature = "env")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum EnvError {
+    #[error("Missing environment variables: {}", .0.join(", "))]
+    Missing(Vec<&'static str>),
+    #[error("Environment variable is not valid unicode: {0}")]
+    NotUnicode(&'static str),
 }
 
 impl serde::ser::Error for UrlError {
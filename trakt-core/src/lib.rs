@@ -1,3 +1,4 @@
+#![forbid(unsafe_code)]
 #![warn(
     clippy::pedantic,
     clippy::nursery,
@@ -11,14 +12,31 @@
 #![allow(clippy::module_name_repetitions)]
 
 mod emoji_str;
+#[cfg(feature = "env")]
+mod env;
 pub mod error;
+mod name;
+mod plan;
 mod request;
 mod response;
 mod url;
 mod utils;
 
 pub use emoji_str::*;
+#[cfg(feature = "env")]
+pub use env::*;
+pub use plan::*;
 pub use request::*;
 pub use response::*;
 pub use url::*;
 pub use utils::*;
+
+#[doc(hidden)]
+pub mod __private {
+    //! Re-exports used by the `trakt-macros` derives.
+    pub use bytes;
+    pub use http;
+    pub use serde;
+
+    pub use crate::name::{endpoint_name, endpoint_name_len};
+}
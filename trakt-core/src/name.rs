@@ -0,0 +1,62 @@
+//! Const functions used by [`endpoint_name!`](crate::endpoint_name).
+
+/// Returns the index in `module` the name of its endpoint starts at.
+const fn name_start(module: &[u8]) -> usize {
+    const API: &[u8] = b"::api::";
+
+    let mut i = 0;
+    while i + API.len() <= module.len() {
+        let mut j = 0;
+        while j < API.len() && module[i + j] == API[j] {
+            j += 1;
+        }
+        if j == API.len() {
+            return i + API.len();
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i + 1 < module.len() {
+        if module[i] == b':' && module[i + 1] == b':' {
+            return i + 2;
+        }
+        i += 1;
+    }
+    0
+}
+
+#[must_use]
+pub const fn endpoint_name_len(module: &str) -> usize {
+    let module = module.as_bytes();
+    let mut len = 0;
+    let mut i = name_start(module);
+    while i < module.len() {
+        if module[i] == b':' {
+            // `::` becomes `.`
+            i += 1;
+        }
+        len += 1;
+        i += 1;
+    }
+    len
+}
+
+#[must_use]
+pub const fn endpoint_name<const N: usize>(module: &str) -> [u8; N] {
+    let module = module.as_bytes();
+    let mut name = [0; N];
+    let mut len = 0;
+    let mut i = name_start(module);
+    while i < module.len() {
+        if module[i] == b':' {
+            name[len] = b'.';
+            i += 1;
+        } else {
+            name[len] = module[i];
+        }
+        len += 1;
+        i += 1;
+    }
+    name
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::IntoHttpError, Context, Request};
+
+/// Serializable description of the HTTP request a [`Request`] would send.
+///
+/// Useful for snapshot tests and debugging tools that need to inspect exactly
+/// what would be sent, without sending anything.
+///
+/// # Example
+///
+/// ```
+/// use trakt_core::{construct_req, AuthRequirement, Context, Metadata, RequestPlan};
+///
+/// let ctx = Context {
+///     base_url: "https://api.trakt.tv",
+///     client_id: "client_id",
+///     oauth_token: None,
+/// };
+/// let md = Metadata::new("/oauth/revoke", http::Method::POST, AuthRequirement::None);
+/// let req = construct_req(&ctx, &md, &(), &(), r#"{"token":"abc"}"#).unwrap();
+///
+/// let plan = RequestPlan::from_http(&req).unwrap();
+/// assert_eq!(plan.method, "POST");
+/// assert_eq!(plan.url, "https://api.trakt.tv/oauth/revoke");
+/// assert_eq!(plan.headers["trakt-api-key"], "client_id");
+/// assert_eq!(plan.body_json, Some(serde_json::json!({ "token": "abc" })));
+/// ```
+///
+/// Use [`RequestPlan::new`] to create the plan of a [`Request`] directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestPlan {
+    /// The HTTP method, e.g. `GET`.
+    pub method: String,
+    /// The full URL, including the query string.
+    pub url: String,
+    /// The headers, sorted by name. Non UTF-8 values are converted lossily.
+    pub headers: BTreeMap<String, String>,
+    /// The JSON body, `None` if the request has no body.
+    pub body_json: Option<serde_json::Value>,
+}
+
+impl RequestPlan {
+    /// Creates the plan of `req` by converting it into an HTTP request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be converted into an HTTP
+    /// request.
+    pub fn new<R: Request>(req: R, ctx: Context) -> Result<Self, IntoHttpError> {
+        Self::from_http(&req.try_into_http_request::<Vec<u8>>(ctx)?)
+    }
+
+    /// Creates the plan of an already built HTTP request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not empty and not valid JSON.
+    pub fn from_http<B: AsRef<[u8]>>(req: &http::Request<B>) -> Result<Self, IntoHttpError> {
+        let body = req.body().as_ref();
+        let body_json = if body.is_empty() {
+            None
+        } else {
+            Some(serde_json::from_slice(body)?)
+        };
+
+        Ok(Self {
+            method: req.method().to_string(),
+            url: req.uri().to_string(),
+            headers: req
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+                    (name.as_str().to_owned(), value)
+                })
+                .collect(),
+            body_json,
+        })
+    }
+
+    /// Replaces the OAuth token in the `Authorization` header, so that the plan
+    /// can be stored or logged.
+    #[must_use]
+    pub fn redacted(mut self) -> Self {
+        if let Some(value) = self.headers.get_mut("authorization") {
+            "Bearer [redacted]".clone_into(value);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AuthRequirement, Metadata};
+
+    fn request(body: &'static str) -> http::Request<&'static str> {
+        let ctx = Context {
+            base_url: "https://api.trakt.tv",
+            client_id: "client id",
+            oauth_token: Some("token"),
+        };
+        let md = Metadata::new("/test", http::Method::POST, AuthRequirement::Required);
+        crate::construct_req(&ctx, &md, &(), &(), body).unwrap()
+    }
+
+    #[test]
+    fn test_from_http() {
+        let plan = RequestPlan::from_http(&request(r#"{"a":[1,2]}"#)).unwrap();
+        assert_eq!(plan.method, "POST");
+        assert_eq!(plan.url, "https://api.trakt.tv/test");
+        assert_eq!(
+            plan.headers.keys().collect::<Vec<_>>(),
+            [
+                "authorization",
+                "content-type",
+                "trakt-api-key",
+                "trakt-api-version"
+            ]
+        );
+        assert_eq!(plan.headers["authorization"], "Bearer token");
+        assert_eq!(plan.body_json, Some(serde_json::json!({ "a": [1, 2] })));
+
+        let plan = plan.redacted();
+        assert_eq!(plan.headers["authorization"], "Bearer [redacted]");
+
+        let json = serde_json::to_value(&plan).unwrap();
+        assert_eq!(json["url"], "https://api.trakt.tv/test");
+        assert_eq!(serde_json::from_value::<RequestPlan>(json).unwrap(), plan);
+    }
+
+    #[test]
+    fn test_from_http_body() {
+        let plan = RequestPlan::from_http(&request("")).unwrap();
+        assert_eq!(plan.body_json, None);
+
+        let err = RequestPlan::from_http(&request("not json")).unwrap_err();
+        assert!(matches!(err, IntoHttpError::Json(_)));
+    }
+}
@@ -35,12 +35,31 @@ pub trait Request: Sized + Clone {
         self,
         ctx: Context,
     ) -> Result<http::Request<T>, IntoHttpError>;
+
+    /// Returns the URI the request would be sent to, without building the
+    /// whole HTTP request.
+    ///
+    /// Useful for cache keys, logging, or clients that only need the URL.
+    ///
+    /// The default implementation builds the HTTP request and takes its URI,
+    /// so it fails in the same cases as [`Self::try_into_http_request`].
+    /// Derived implementations only build the URI, and don't require an OAuth
+    /// token.
+    ///
+    /// # Errors
+    /// This function will return an error if the URI cannot be constructed.
+    fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+        let request: http::Request<Vec<u8>> = self.clone().try_into_http_request(ctx)?;
+        Ok(request.uri().clone())
+    }
 }
 
 /// Represents metadata for an API endpoint.
 ///
 /// This struct holds information about the endpoint, such as the URL endpoint,
 /// HTTP method, and authorization requirement.
+///
+/// Use [`Metadata::new`] to create it, as more fields may be added.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Metadata {
     /// The URL endpoint for the request.
@@ -49,6 +68,113 @@ pub struct Metadata {
     pub method: Method,
     /// Authorization requirement for the request.
     pub auth: AuthRequirement,
+    name: &'static str,
+    optional_params: &'static [&'static str],
+}
+
+impl Metadata {
+    /// Creates the metadata of an endpoint without a [name](Self::name).
+    #[must_use]
+    pub const fn new(endpoint: &'static str, method: Method, auth: AuthRequirement) -> Self {
+        Self {
+            endpoint,
+            method,
+            auth,
+            name: "",
+            optional_params: &[],
+        }
+    }
+
+    /// Sets the [name](Self::name) of the endpoint.
+    ///
+    /// Use [`endpoint_name!`](crate::endpoint_name) to name the endpoint after
+    /// the module it is defined in, as the derived implementations do.
+    #[must_use]
+    pub const fn with_name(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Returns a stable, machine-readable name for the endpoint, e.g.
+    /// `"shows.trending"`, or `""` if the endpoint wasn't given a name.
+    ///
+    /// This makes it suitable for labeling metrics or logs without matching
+    /// on endpoint templates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use trakt_core::{AuthRequirement, Metadata};
+    ///
+    /// let md = Metadata::new("/shows/trending", http::Method::GET, AuthRequirement::None)
+    ///     .with_name("shows.trending");
+    /// assert_eq!(md.name(), "shows.trending");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Sets the [optional parameters](Self::optional_params) of the endpoint.
+    #[must_use]
+    pub const fn with_optional_params(mut self, params: &'static [&'static str]) -> Self {
+        self.optional_params = params;
+        self
+    }
+
+    /// Returns the parameters of [`Self::endpoint`] that may be omitted.
+    ///
+    /// Derived implementations treat parameters of type `Option` or marked
+    /// with `#[trakt(optional)]` as optional, and every other parameter as
+    /// required.
+    #[inline]
+    #[must_use]
+    pub const fn optional_params(&self) -> &'static [&'static str] {
+        self.optional_params
+    }
+
+    /// Returns `true` if the response may depend on the OAuth token of the
+    /// request.
+    ///
+    /// See [`AuthRequirement::varies_with_auth`].
+    #[inline]
+    #[must_use]
+    pub const fn varies_with_auth(&self) -> bool {
+        self.auth.varies_with_auth()
+    }
+}
+
+/// Expands to the name of the endpoint defined in the current module, as a
+/// `&'static str` for [`Metadata::with_name`].
+///
+/// The name is the path of the module after the `api` module, or after the
+/// crate name if there is no `api` module, joined with dots.
+///
+/// # Example
+///
+/// ```
+/// mod api {
+///     pub mod shows {
+///         pub mod trending {
+///             pub const NAME: &str = trakt_core::endpoint_name!();
+///         }
+///     }
+/// }
+///
+/// assert_eq!(api::shows::trending::NAME, "shows.trending");
+/// ```
+#[macro_export]
+macro_rules! endpoint_name {
+    () => {{
+        const MODULE: &::core::primitive::str = ::core::module_path!();
+        const LEN: ::core::primitive::usize = $crate::__private::endpoint_name_len(MODULE);
+        const NAME: [::core::primitive::u8; LEN] = $crate::__private::endpoint_name(MODULE);
+        match ::core::str::from_utf8(&NAME) {
+            ::core::result::Result::Ok(name) => name,
+            ::core::result::Result::Err(_) => ::core::panic!("module path is not valid UTF-8"),
+        }
+    }};
 }
 
 /// Authorization requirement for an API request.
@@ -64,6 +190,26 @@ pub enum AuthRequirement {
     Required,
 }
 
+impl AuthRequirement {
+    /// Returns `true` if the response may depend on the OAuth token of the
+    /// request.
+    ///
+    /// Responses to [`AuthRequirement::Optional`] requests can change with a
+    /// token, e.g. comments exclude users blocked by the authenticated user,
+    /// and responses to [`AuthRequirement::Required`] requests are specific
+    /// to the user. Caches should include the token (or the user it belongs
+    /// to) in the keys of these responses, so that they aren't shared between
+    /// users.
+    #[inline]
+    #[must_use]
+    pub const fn varies_with_auth(self) -> bool {
+        match self {
+            Self::None => false,
+            Self::Optional | Self::Required => true,
+        }
+    }
+}
+
 /// Represents the universal context for an API request.
 ///
 /// This struct contains the information needed to make an API request, such as
@@ -77,3 +223,73 @@ pub struct Context<'a> {
     /// The OAuth token for the API, if requesting an authenticated endpoint.
     pub oauth_token: Option<&'a str>,
 }
+
+/// A request along with the [`Context`] to send it with.
+///
+/// Implementing `TryFrom<(R, Context)>` for [`http::Request`] directly isn't
+/// allowed by the orphan rules, so this type is converted instead. It can be
+/// created from a `(request, ctx)` tuple, and converts into an
+/// [`http::Request`] with [`TryFrom`], for code that works over
+/// `TryFrom`/`TryInto` bounds rather than [`Request`]:
+///
+/// ```
+/// # use trakt_core::{Context, Metadata, Request, WithContext};
+/// # #[derive(Clone)]
+/// # struct Ping;
+/// # impl Request for Ping {
+/// #     type Response = Pong;
+/// #     const METADATA: Metadata =
+/// #         Metadata::new("/ping", http::Method::GET, trakt_core::AuthRequirement::None);
+/// #     fn try_into_http_request<T: Default + bytes::BufMut>(
+/// #         self,
+/// #         ctx: Context,
+/// #     ) -> Result<http::Request<T>, trakt_core::error::IntoHttpError> {
+/// #         trakt_core::construct_req(&ctx, &Self::METADATA, &(), &(), T::default())
+/// #     }
+/// # }
+/// # struct Pong;
+/// # impl trakt_core::Response for Pong {
+/// #     fn try_from_http_response<T: AsRef<[u8]>>(
+/// #         _: http::Response<T>,
+/// #     ) -> Result<Self, trakt_core::error::FromHttpError> {
+/// #         Ok(Self)
+/// #     }
+/// # }
+/// let ctx = Context {
+///     base_url: "https://api.trakt.tv",
+///     client_id: "client_id",
+///     oauth_token: None,
+/// };
+///
+/// let request: http::Request<Vec<u8>> = WithContext::from((Ping, ctx)).try_into().unwrap();
+/// assert_eq!(request.uri(), "https://api.trakt.tv/ping");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WithContext<'a, R> {
+    pub request: R,
+    pub ctx: Context<'a>,
+}
+
+impl<'a, R> WithContext<'a, R> {
+    #[inline]
+    #[must_use]
+    pub const fn new(request: R, ctx: Context<'a>) -> Self {
+        Self { request, ctx }
+    }
+}
+
+impl<'a, R: Request> From<(R, Context<'a>)> for WithContext<'a, R> {
+    #[inline]
+    fn from((request, ctx): (R, Context<'a>)) -> Self {
+        Self::new(request, ctx)
+    }
+}
+
+impl<R: Request, T: Default + BufMut> TryFrom<WithContext<'_, R>> for http::Request<T> {
+    type Error = IntoHttpError;
+
+    #[inline]
+    fn try_from(value: WithContext<'_, R>) -> Result<Self, Self::Error> {
+        value.request.try_into_http_request(value.ctx)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::FromHttpError;
+use crate::{error::FromHttpError, ParseOptions};
 
 /// A trait for converting an HTTP response into a result of `Self`.
 pub trait Response: Sized {
@@ -19,6 +19,65 @@ pub trait Response: Sized {
     fn try_from_http_response<T: AsRef<[u8]>>(
         response: http::Response<T>,
     ) -> Result<Self, FromHttpError>;
+
+    /// Like [`try_from_http_response`](Self::try_from_http_response), but
+    /// rejects responses that don't satisfy the given [`ParseOptions`] before
+    /// parsing them.
+    ///
+    /// # Errors
+    ///
+    /// Will error if the response is rejected by the options, or for the same
+    /// reasons as [`try_from_http_response`](Self::try_from_http_response).
+    fn try_from_http_response_with<T: AsRef<[u8]>>(
+        response: http::Response<T>,
+        options: &ParseOptions,
+    ) -> Result<Self, FromHttpError> {
+        options.check(&response)?;
+        Self::try_from_http_response(response)
+    }
+}
+
+/// Implements `TryFrom<http::Response<T>>` for a type that implements
+/// [`Response`] by hand, like the `Response` derive does for derived types.
+///
+/// # Example
+///
+/// ```
+/// use trakt_core::{error::FromHttpError, parse_from_header, Response};
+///
+/// struct Count(u32);
+///
+/// impl Response for Count {
+///     fn try_from_http_response<T: AsRef<[u8]>>(
+///         response: http::Response<T>,
+///     ) -> Result<Self, FromHttpError> {
+///         Ok(Self(parse_from_header(response.headers(), "X-Count")?))
+///     }
+/// }
+///
+/// trakt_core::impl_try_from_response!(Count);
+///
+/// let response = http::Response::builder()
+///     .header("X-Count", "3")
+///     .body(Vec::new())
+///     .unwrap();
+/// assert_eq!(Count::try_from(response).unwrap().0, 3);
+/// ```
+#[macro_export]
+macro_rules! impl_try_from_response {
+    ($ty:ty) => {
+        impl<T: ::core::convert::AsRef<[::core::primitive::u8]>>
+            ::core::convert::TryFrom<$crate::__private::http::Response<T>> for $ty
+        {
+            type Error = $crate::error::FromHttpError;
+
+            fn try_from(
+                response: $crate::__private::http::Response<T>,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                <Self as $crate::Response>::try_from_http_response(response)
+            }
+        }
+    };
 }
 
 /// A sub-trait of `Response` for paginated responses.
@@ -8,12 +8,18 @@ use crate::error::{IntoHttpError, UrlError};
 /// - The `base_url` is the base URL of the API: `https://api.trakt.tv`.
 /// - The `endpoint` is the path of the specific endpoint with parameters
 ///   enclosed in curly braces:
-/// `/shows/{id}/seasons/{season}/episodes/{episode}`.
+///   `/shows/{id}/seasons/{season}/episodes/{episode}`.
 /// - The `params` is a struct that will be serialized into the parameters of
 ///   the `endpoint`.
 /// - The `query` is a struct that will be serialized into the query parameters
 ///   of the URL.
 ///
+/// Parameters that serialize to `None` are omitted from the URL along with the
+/// `/` before them, e.g. `/movies/{id}/releases/{country}` becomes
+/// `/movies/1/releases` if `country` is `None`. This allows optional trailing
+/// path segments, so a parameter with a value after an omitted one is an
+/// error.
+///
 /// # Errors
 ///
 /// Returns an [`IntoHttpError`] if the URL cannot be constructed.
@@ -39,11 +45,77 @@ pub fn construct_url(
     Ok(url)
 }
 
+/// Validates and normalizes the base URL of a request.
+///
+/// Trailing slashes are stripped so that they don't produce `//` when joined
+/// with an endpoint. An empty base URL is allowed and produces relative URLs.
+///
+/// ```
+/// # use trakt_core::normalize_base_url;
+/// assert_eq!(normalize_base_url("https://api.trakt.tv/").unwrap(), "https://api.trakt.tv");
+/// assert!(normalize_base_url("api.trakt.tv").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`UrlError::InvalidBaseUrl`] if the base URL isn't ASCII, doesn't
+/// have an `http` or `https` scheme, or doesn't have a host.
+pub fn normalize_base_url(base_url: &str) -> Result<&str, UrlError> {
+    if base_url.is_empty() {
+        return Ok(base_url);
+    }
+
+    let invalid = || UrlError::InvalidBaseUrl(base_url.to_owned());
+    if !base_url.is_ascii() {
+        return Err(invalid());
+    }
+
+    let trimmed = base_url.trim_end_matches('/');
+    let host = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+        .ok_or_else(invalid)?;
+    if host.is_empty()
+        || host.starts_with('/')
+        || host
+            .bytes()
+            .any(|b| b.is_ascii_whitespace() || b.is_ascii_control() || b == b'?' || b == b'#')
+    {
+        return Err(invalid());
+    }
+
+    Ok(trimmed)
+}
+
+/// Returns the names of the parameters of an endpoint, in order.
+///
+/// ```
+/// # use trakt_core::endpoint_params;
+/// let params = endpoint_params("/shows/{id}/seasons/{season}").unwrap();
+/// assert_eq!(params, ["id", "season"]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`UrlError::InvalidEndpoint`] if the endpoint has unbalanced or
+/// nested braces.
+pub fn endpoint_params(endpoint: &str) -> Result<Vec<&str>, UrlError> {
+    Ok(parse_endpoint(endpoint)?
+        .into_iter()
+        .filter_map(|part| match part {
+            Part::Param(Param::Key(key)) => Some(key),
+            _ => None,
+        })
+        .collect())
+}
+
 struct UrlSerializer<'a> {
     /// The URL being built
     url: String,
     /// The parts of the URL endpoint
     parts: Vec<Part<'a>>,
+    /// The key of the map entry being serialized
+    key: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -60,12 +132,16 @@ enum Param<'a> {
     Key(&'a str),
     /// The serialized value of the parameter
     Value(String),
+    /// The parameter with the given key was `None` and should be omitted from
+    /// the URL
+    Omitted(&'a str),
 }
 
 fn to_string<T: Serialize>(base_url: &str, endpoint: &str, value: &T) -> Result<String, UrlError> {
     let mut serializer = UrlSerializer {
-        url: base_url.to_owned(),
+        url: normalize_base_url(base_url)?.to_owned(),
         parts: parse_endpoint(endpoint)?,
+        key: None,
     };
     value.serialize(&mut serializer)?;
     serializer.end()
@@ -82,7 +158,7 @@ fn to_string<T: Serialize>(base_url: &str, endpoint: &str, value: &T) -> Result<
 /// - `Param("season")`
 /// - `Raw("/episodes/")`
 /// - `Param("episode")`
-fn parse_endpoint(s: &str) -> Result<Vec<Part>, UrlError> {
+fn parse_endpoint(s: &str) -> Result<Vec<Part<'_>>, UrlError> {
     let mut parts = Vec::new();
     let mut start = 0;
     let mut in_param = false;
@@ -136,15 +212,59 @@ fn parse_endpoint(s: &str) -> Result<Vec<Part>, UrlError> {
     Ok(parts)
 }
 
-impl<'a> UrlSerializer<'a> {
+impl UrlSerializer<'_> {
+    /// Serializes `value` into the parameter named `key`.
+    ///
+    /// Returns `false` if the endpoint has no such parameter.
+    fn fill<T: ?Sized + Serialize>(&mut self, key: &str, value: &T) -> Result<bool, UrlError> {
+        // Search for the key in the parts
+        let part = self.parts.iter_mut().find_map(|p| match p {
+            Part::Param(p @ Param::Key(_)) if *p == Param::Key(key) => Some(p),
+            _ => None,
+        });
+
+        // If the key was not found, let the caller handle it
+        let Some(part) = part else {
+            return Ok(false);
+        };
+
+        // Serialize the value into the part
+        let mut serializer = UrlValueSerializer::default();
+        value.serialize(&mut serializer)?;
+
+        *part = if serializer.omitted {
+            let Param::Key(key) = *part else {
+                unreachable!("only keys are filled")
+            };
+            Param::Omitted(key)
+        } else {
+            Param::Value(serializer.value)
+        };
+
+        Ok(true)
+    }
+
     pub fn end(self) -> Result<String, UrlError> {
         let mut url = self.url;
+        let mut omitted: Option<&str> = None;
         for part in self.parts {
             match part {
                 Part::Raw(s) => url.push_str(s),
                 Part::Param(p) => match p {
                     Param::Key(k) => return Err(UrlError::UnfilledField(k.to_owned())),
-                    Param::Value(v) => url.push_str(&v),
+                    Param::Value(v) => {
+                        // A value after an omitted param would shift into its segment
+                        if let Some(key) = omitted {
+                            return Err(UrlError::ValueAfterOmitted(key.to_owned()));
+                        }
+                        url.push_str(&v);
+                    }
+                    Param::Omitted(key) => {
+                        omitted.get_or_insert(key);
+                        if url.ends_with('/') {
+                            url.pop();
+                        }
+                    }
                 },
             }
         }
@@ -152,7 +272,7 @@ impl<'a> UrlSerializer<'a> {
     }
 }
 
-impl<'a, 'b> ser::Serializer for &'a mut UrlSerializer<'b> {
+impl ser::Serializer for &mut UrlSerializer<'_> {
     type Ok = ();
 
     type Error = UrlError;
@@ -160,7 +280,7 @@ impl<'a, 'b> ser::Serializer for &'a mut UrlSerializer<'b> {
     type SerializeTuple = ErrorSerializer;
     type SerializeTupleStruct = ErrorSerializer;
     type SerializeTupleVariant = ErrorSerializer;
-    type SerializeMap = ErrorSerializer;
+    type SerializeMap = Self;
     type SerializeStruct = Self;
     type SerializeStructVariant = ErrorSerializer;
 
@@ -290,7 +410,7 @@ impl<'a, 'b> ser::Serializer for &'a mut UrlSerializer<'b> {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(UrlError::ValueNotSupported)
+        Ok(self)
     }
 
     fn serialize_struct(
@@ -312,7 +432,7 @@ impl<'a, 'b> ser::Serializer for &'a mut UrlSerializer<'b> {
     }
 }
 
-impl<'a, 'b> ser::SerializeStruct for &'a mut UrlSerializer<'b> {
+impl ser::SerializeStruct for &mut UrlSerializer<'_> {
     type Ok = ();
     type Error = UrlError;
 
@@ -321,34 +441,40 @@ impl<'a, 'b> ser::SerializeStruct for &'a mut UrlSerializer<'b> {
         key: &'static str,
         value: &T,
     ) -> Result<(), Self::Error> {
-        // Search for the key in the parts
-        let mut part = None;
-        for p in &mut self.parts {
-            match p {
-                Part::Param(p) => match p {
-                    Param::Key(k) if *k == key => {
-                        part = Some(p);
-                        break;
-                    }
-                    _ => {}
-                },
-                Part::Raw(_) => {}
-            }
+        if self.fill(key, value)? {
+            Ok(())
+        } else {
+            Err(UrlError::KeyNotFound(key.to_owned()))
         }
+    }
 
-        // If the key was not found, this is an error
-        let part = part.ok_or(UrlError::KeyNotFound(key))?;
-
-        // Serialize the value into the part
-        let mut serializer = UrlValueSerializer::default();
-        value.serialize(&mut serializer)?;
-        let value = serializer.value;
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
 
-        *part = Param::Value(value);
+impl ser::SerializeMap for &mut UrlSerializer<'_> {
+    type Ok = ();
+    type Error = UrlError;
 
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        // Parameter names don't contain characters that need encoding, so the
+        // encoded key can be compared with them directly.
+        let mut serializer = UrlValueSerializer::default();
+        key.serialize(&mut serializer)?;
+        self.key = Some(serializer.value);
         Ok(())
     }
 
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().ok_or(UrlError::ValueNotSupported)?;
+        if self.fill(&key, value)? {
+            Ok(())
+        } else {
+            Err(UrlError::KeyNotFound(key))
+        }
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         Ok(())
     }
@@ -357,6 +483,8 @@ impl<'a, 'b> ser::SerializeStruct for &'a mut UrlSerializer<'b> {
 #[derive(Debug, Clone, Default)]
 struct UrlValueSerializer {
     value: String,
+    /// Whether the value was `None`
+    omitted: bool,
 }
 
 const PATH_SET: &AsciiSet = &CONTROLS
@@ -371,7 +499,7 @@ const PATH_SET: &AsciiSet = &CONTROLS
     .add(b'{')
     .add(b'}');
 
-impl<'a> ser::Serializer for &'a mut UrlValueSerializer {
+impl ser::Serializer for &mut UrlValueSerializer {
     type Ok = ();
     type Error = UrlError;
 
@@ -465,6 +593,7 @@ impl<'a> ser::Serializer for &'a mut UrlValueSerializer {
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.omitted = true;
         self.serialize_unit()
     }
 
@@ -674,6 +803,71 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_normalize_base_url() {
+        #[derive(Serialize)]
+        struct Params {
+            id: i32,
+        }
+
+        assert_eq!(normalize_base_url("").unwrap(), "");
+        assert_eq!(
+            normalize_base_url("https://api.trakt.tv").unwrap(),
+            "https://api.trakt.tv"
+        );
+        assert_eq!(
+            normalize_base_url("http://localhost:8080//").unwrap(),
+            "http://localhost:8080"
+        );
+        assert_eq!(
+            normalize_base_url("https://example.com/api/").unwrap(),
+            "https://example.com/api"
+        );
+
+        for base_url in [
+            "api.trakt.tv",
+            "ftp://api.trakt.tv",
+            "https://",
+            "https:///api",
+            "https://api.trakt.tv?x=1",
+            "https://api trakt.tv",
+            "https://apï.trakt.tv",
+        ] {
+            assert_eq!(
+                normalize_base_url(base_url),
+                Err(UrlError::InvalidBaseUrl(base_url.to_owned())),
+                "{base_url}"
+            );
+        }
+
+        let url = construct_url(
+            "https://example.com/",
+            "/shows/{id}",
+            &Params { id: 1 },
+            &(),
+        );
+        assert_eq!(url.unwrap(), "https://example.com/shows/1");
+    }
+
+    #[test]
+    fn test_endpoint_params() {
+        assert_eq!(
+            endpoint_params("/shows/{id}/seasons/{season}/episodes/{episode}").unwrap(),
+            ["id", "season", "episode"]
+        );
+        assert_eq!(endpoint_params("/{tp}").unwrap(), ["tp"]);
+        assert!(endpoint_params("/shows/trending").unwrap().is_empty());
+        assert!(endpoint_params("").unwrap().is_empty());
+        assert!(matches!(
+            endpoint_params("/shows/{id"),
+            Err(UrlError::InvalidEndpoint)
+        ));
+        assert!(matches!(
+            endpoint_params("/shows/{{id}}"),
+            Err(UrlError::InvalidEndpoint)
+        ));
+    }
+
     #[test]
     fn test_parse_endpoint() {
         let endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}";
@@ -719,6 +913,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn construct_url_map() {
+        use std::collections::BTreeMap;
+
+        let endpoint = "/shows/{id}/seasons/{season}";
+        let params = BTreeMap::from([("id", "the office"), ("season", "2")]);
+        let query = BTreeMap::from([("extended", "full")]);
+        let url = construct_url("https://example.com", endpoint, &params, &query).unwrap();
+        assert_eq!(
+            url,
+            "https://example.com/shows/the%20office/seasons/2?extended=full"
+        );
+
+        let params = BTreeMap::from([("id", "1"), ("episode", "2")]);
+        assert!(matches!(
+            construct_url("", endpoint, &params, &()),
+            Err(IntoHttpError::UrlParams(UrlError::KeyNotFound(key))) if key == "episode"
+        ));
+
+        let params = BTreeMap::from([("id", "1")]);
+        assert!(matches!(
+            construct_url("", endpoint, &params, &()),
+            Err(IntoHttpError::UrlParams(UrlError::UnfilledField(key))) if key == "season"
+        ));
+    }
+
     #[test]
     fn construct_url_normal() {
         #[derive(Serialize)]
@@ -800,6 +1020,62 @@ mod tests {
         assert_eq!(res.to_string(), "Url params error: Invalid endpoint");
     }
 
+    #[test]
+    fn construct_url_omitted() {
+        #[derive(Serialize)]
+        struct Params {
+            id: i32,
+            tp: Option<&'static str>,
+            sort: Option<&'static str>,
+        }
+
+        let base_url = "https://example.com";
+        let endpoint = "/shows/{id}/lists/{tp}/{sort}";
+
+        let params = Params {
+            id: 1,
+            tp: Some("official"),
+            sort: Some("popular"),
+        };
+        let url = construct_url(base_url, endpoint, &params, &()).unwrap();
+        assert_eq!(url, "https://example.com/shows/1/lists/official/popular");
+
+        let params = Params {
+            id: 1,
+            tp: Some("official"),
+            sort: None,
+        };
+        let url = construct_url(base_url, endpoint, &params, &()).unwrap();
+        assert_eq!(url, "https://example.com/shows/1/lists/official");
+
+        let params = Params {
+            id: 1,
+            tp: None,
+            sort: None,
+        };
+        let url = construct_url(base_url, endpoint, &params, &()).unwrap();
+        assert_eq!(url, "https://example.com/shows/1/lists");
+
+        let params = Params {
+            id: 1,
+            tp: Some(""),
+            sort: None,
+        };
+        let url = construct_url(base_url, endpoint, &params, &()).unwrap();
+        assert_eq!(url, "https://example.com/shows/1/lists/");
+
+        let params = Params {
+            id: 1,
+            tp: None,
+            sort: Some("popular"),
+        };
+        let err = construct_url(base_url, endpoint, &params, &()).unwrap_err();
+        assert!(matches!(
+            err,
+            IntoHttpError::UrlParams(UrlError::ValueAfterOmitted(key)) if key == "tp"
+        ));
+    }
+
     #[test]
     fn construct_url_empty() {
         #[derive(Serialize)]
@@ -886,6 +1162,7 @@ mod tests {
         let mut serializer = UrlValueSerializer::default();
         serializer.serialize_none().unwrap();
         assert_eq!(serializer.value, "");
+        assert!(serializer.omitted);
 
         let mut serializer = UrlValueSerializer::default();
         serializer.serialize_some(&true).unwrap();
@@ -968,6 +1245,7 @@ mod tests {
         let mut serializer = UrlSerializer {
             url: String::new(),
             parts: vec![],
+            key: None,
         };
 
         assert_eq!(
@@ -1060,6 +1338,7 @@ mod tests {
                 Part::Param(Param::Value("raw".to_owned())),
                 Part::Param(Param::Key("id")),
             ],
+            key: None,
         };
 
         params.serialize(&mut serializer).unwrap();
@@ -1079,11 +1358,12 @@ mod tests {
                 Part::Param(Param::Value("raw".to_owned())),
                 Part::Param(Param::Key("i")),
             ],
+            key: None,
         };
 
         assert_eq!(
             params.serialize(&mut serializer).unwrap_err(),
-            UrlError::KeyNotFound("id")
+            UrlError::KeyNotFound("id".to_owned())
         );
     }
 }
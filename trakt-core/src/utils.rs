@@ -4,7 +4,9 @@ use http::{header::AsHeaderName, HeaderMap, StatusCode};
 use serde::Serialize;
 
 use crate::{
-    error::{ApiError, DeserializeError, FromHttpError, HeaderError, IntoHttpError},
+    error::{
+        ApiError, DeserializeError, FromHttpError, HeaderError, IntoHttpError, PaginationError,
+    },
     AuthRequirement, Context, Metadata,
 };
 
@@ -27,25 +29,129 @@ impl Default for Pagination {
 impl Pagination {
     const DEFAULT: Self = Self::new(1, 10);
 
+    /// Maximum number of items per page accepted by the API.
+    pub const MAX_LIMIT: usize = 100;
+
+    /// Creates a `Pagination` without checking its values.
+    ///
+    /// Invalid values are rejected when the request is converted into an HTTP
+    /// request. Use [`Pagination::try_new`] to check them up front.
     #[inline]
     #[must_use]
     pub const fn new(page: usize, limit: usize) -> Self {
         Self { page, limit }
     }
+
+    /// Creates a `Pagination`, checking that `page` and `limit` are non-zero
+    /// and that `limit` is at most [`Pagination::MAX_LIMIT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PaginationError` if the values are invalid.
+    #[inline]
+    pub const fn try_new(page: usize, limit: usize) -> Result<Self, PaginationError> {
+        let pagination = Self::new(page, limit);
+        match pagination.validate() {
+            Ok(()) => Ok(pagination),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Checks that the values are accepted by the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PaginationError` if the values are invalid.
+    #[inline]
+    pub const fn validate(&self) -> Result<(), PaginationError> {
+        if self.page == 0 {
+            Err(PaginationError::ZeroPage)
+        } else {
+            Self::validate_limit(self.limit)
+        }
+    }
+
+    /// Checks that `limit` is non-zero and at most [`Pagination::MAX_LIMIT`].
+    ///
+    /// Also used for the limit of endpoints that aren't paginated, e.g.
+    /// recommendations.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PaginationError` if the limit is invalid.
+    #[inline]
+    pub const fn validate_limit(limit: usize) -> Result<(), PaginationError> {
+        if limit == 0 {
+            Err(PaginationError::ZeroLimit)
+        } else if limit > Self::MAX_LIMIT {
+            Err(PaginationError::LimitTooLarge(limit))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// `PaginationResponse` struct is used to store the paginated response from the
 /// API.
+///
+/// The fields are deprecated in favor of the accessor methods, and will become
+/// private in a future release.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct PaginationResponse<T> {
+    #[deprecated(note = "use `PaginationResponse::items` or `into_items` instead")]
     pub items: Vec<T>,
+    #[deprecated(note = "use `PaginationResponse::current_page` instead")]
     pub current_page: usize,
+    #[deprecated(note = "use `PaginationResponse::items_per_page` instead")]
     pub items_per_page: usize,
+    #[deprecated(note = "use `PaginationResponse::total_pages` instead")]
     pub total_pages: usize,
+    #[deprecated(note = "use `PaginationResponse::total_items` instead")]
     pub total_items: usize,
+    /// The limit of the request that produced this response, if known.
+    ///
+    /// Set with [`PaginationResponse::with_request`].
+    requested_limit: Option<usize>,
 }
 
+#[allow(deprecated)]
 impl<T> PaginationResponse<T> {
+    /// Create a new `PaginationResponse` instance from items and pagination
+    /// info.
+    ///
+    /// `total_pages` is clamped to the number of pages needed to hold
+    /// `total_items`.
+    #[must_use]
+    pub const fn new(
+        items: Vec<T>,
+        current_page: usize,
+        items_per_page: usize,
+        total_pages: usize,
+        total_items: usize,
+    ) -> Self {
+        // Guard against inconsistent headers: there can't be more pages than
+        // needed to hold all the items.
+        let total_pages = if items_per_page == 0 {
+            0
+        } else {
+            let max_pages = total_items.div_ceil(items_per_page);
+            if total_pages > max_pages {
+                max_pages
+            } else {
+                total_pages
+            }
+        };
+
+        Self {
+            items,
+            current_page,
+            items_per_page,
+            total_pages,
+            total_items,
+            requested_limit: None,
+        }
+    }
+
     /// Create a new `PaginationResponse` instance from items and Trakt.tv API
     /// response headers.
     ///
@@ -59,26 +165,158 @@ impl<T> PaginationResponse<T> {
         let total_pages = parse_from_header(map, "X-Pagination-Page-Count")?;
         let total_items = parse_from_header(map, "X-Pagination-Item-Count")?;
 
-        Ok(Self {
+        Ok(Self::new(
             items,
             current_page,
             items_per_page,
             total_pages,
             total_items,
-        })
+        ))
+    }
+
+    /// Returns the items of the current page.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the current page number, starting at 1.
+    #[inline]
+    #[must_use]
+    pub const fn current_page(&self) -> usize {
+        self.current_page
+    }
+
+    /// Returns the number of items per page used by the API.
+    #[inline]
+    #[must_use]
+    pub const fn items_per_page(&self) -> usize {
+        self.items_per_page
+    }
+
+    /// Returns the total number of pages.
+    #[inline]
+    #[must_use]
+    pub const fn total_pages(&self) -> usize {
+        self.total_pages
+    }
+
+    /// Returns the total number of items across all pages.
+    #[inline]
+    #[must_use]
+    pub const fn total_items(&self) -> usize {
+        self.total_items
+    }
+
+    /// Returns the limit of the request that produced this response, if known.
+    ///
+    /// Set with [`PaginationResponse::with_request`].
+    #[inline]
+    #[must_use]
+    pub const fn requested_limit(&self) -> Option<usize> {
+        self.requested_limit
+    }
+
+    /// Consumes the response, returning only the items.
+    #[inline]
+    #[must_use]
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Records the pagination of the request that produced this response.
+    ///
+    /// This allows [`PaginationResponse::was_clamped`] to detect when the API
+    /// used a different limit than requested.
+    #[inline]
+    #[must_use]
+    pub const fn with_request(mut self, request: Pagination) -> Self {
+        self.requested_limit = Some(request.limit);
+        self
+    }
+
+    /// Returns `true` if the API used a different limit than requested.
+    ///
+    /// Limits above [`Pagination::MAX_LIMIT`] are rejected before the request
+    /// is sent, so this only detects the API clamping a valid limit to a lower
+    /// maximum of its own, e.g. on endpoints that return fewer items per page.
+    ///
+    /// Always returns `false` if the request wasn't recorded with
+    /// [`PaginationResponse::with_request`].
+    #[inline]
+    #[must_use]
+    pub const fn was_clamped(&self) -> bool {
+        match self.requested_limit {
+            Some(limit) => limit != self.items_per_page,
+            None => false,
+        }
     }
 
+    /// Returns the pagination of the current page.
+    #[inline]
+    #[must_use]
+    pub const fn pagination(&self) -> Pagination {
+        Pagination::new(self.current_page, self.items_per_page)
+    }
+
+    /// Returns the pagination of the next page, or `None` if this is the last
+    /// page.
     #[inline]
     #[must_use]
     pub const fn next_page(&self) -> Option<Pagination> {
-        if self.current_page < self.total_pages {
-            Some(Pagination::new(self.current_page + 1, self.items_per_page))
+        if self.items_per_page == 0 || self.current_page >= self.total_pages {
+            return None;
+        }
+        match self.current_page.checked_add(1) {
+            Some(page) => Some(Pagination::new(page, self.items_per_page)),
+            None => None,
+        }
+    }
+
+    /// Returns the pagination of the previous page, or `None` if this is the
+    /// first page.
+    ///
+    /// If the current page is past the last page, the last page is returned.
+    #[inline]
+    #[must_use]
+    pub const fn prev_page(&self) -> Option<Pagination> {
+        if self.items_per_page == 0 || self.total_pages == 0 {
+            return None;
+        }
+        let page = if self.current_page > self.total_pages {
+            self.total_pages
         } else {
+            self.current_page.saturating_sub(1)
+        };
+        if page == 0 {
+            None
+        } else {
+            Some(Pagination::new(page, self.items_per_page))
+        }
+    }
+
+    /// Returns the pagination of the last page, or `None` if there are no
+    /// pages.
+    #[inline]
+    #[must_use]
+    pub const fn last_page(&self) -> Option<Pagination> {
+        if self.items_per_page == 0 || self.total_pages == 0 {
             None
+        } else {
+            Some(Pagination::new(self.total_pages, self.items_per_page))
         }
     }
 }
 
+#[allow(deprecated)]
+impl<T> From<PaginationResponse<T>> for Vec<T> {
+    #[inline]
+    fn from(value: PaginationResponse<T>) -> Self {
+        value.into_items()
+    }
+}
+
 /// Helper function to parse a header value to an integer.
 ///
 /// # Errors
@@ -99,6 +337,63 @@ where
         .map_err(DeserializeError::ParseInt)
 }
 
+/// Helper function to check the response status from the API.
+///
+/// Useful for responses that don't have a body.
+///
+/// # Errors
+///
+/// Returns a `FromHttpError` if the response status code is not the expected
+/// one.
+pub fn handle_response_status<B>(
+    response: &http::Response<B>,
+    expected: StatusCode,
+) -> Result<(), FromHttpError> {
+    if response.status() == expected {
+        Ok(())
+    } else {
+        Err(FromHttpError::Api(ApiError::from(response.status())))
+    }
+}
+
+/// Options for parsing responses.
+///
+/// The default options don't limit the response in any way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParseOptions {
+    /// Maximum size of a response body in bytes.
+    ///
+    /// Larger bodies are rejected without being deserialized, which saves the
+    /// time spent parsing responses that would be discarded anyway. The body
+    /// has already been read into memory by then, so this doesn't limit how
+    /// much memory a response takes.
+    pub max_body_size: Option<usize>,
+}
+
+impl ParseOptions {
+    /// Options that don't limit the response in any way.
+    pub const DEFAULT: Self = Self {
+        max_body_size: None,
+    };
+
+    /// Checks that `response` can be parsed with these options.
+    ///
+    /// This is done before the status code is checked, so an oversized error
+    /// response is rejected as too large.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FromHttpError` if the response body is larger than
+    /// [`max_body_size`](Self::max_body_size).
+    pub fn check<B: AsRef<[u8]>>(&self, response: &http::Response<B>) -> Result<(), FromHttpError> {
+        let size = response.body().as_ref().len();
+        match self.max_body_size {
+            Some(max) if size > max => Err(DeserializeError::BodyTooLarge { size, max }.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Helper function to handle the response body from the API.
 ///
 /// Will check if the response has the expected status code and will try to
@@ -116,11 +411,135 @@ where
     B: AsRef<[u8]>,
     T: serde::de::DeserializeOwned,
 {
-    if response.status() == expected {
-        Ok(serde_json::from_slice(response.body().as_ref()).map_err(DeserializeError::Json)?)
-    } else {
-        Err(FromHttpError::Api(ApiError::from(response.status())))
+    handle_response_body_with(response, expected, &ParseOptions::DEFAULT)
+}
+
+/// Like [`handle_response_body`], but parses the body with the given
+/// [`ParseOptions`].
+///
+/// # Errors
+///
+/// Returns a `FromHttpError` if the body is rejected by the options, if the
+/// response status code is not the expected one, or if the body failed to be
+/// deserialized.
+pub fn handle_response_body_with<B, T>(
+    response: &http::Response<B>,
+    expected: StatusCode,
+    options: &ParseOptions,
+) -> Result<T, FromHttpError>
+where
+    B: AsRef<[u8]>,
+    T: serde::de::DeserializeOwned,
+{
+    options.check(response)?;
+    handle_response_status(response, expected)?;
+    Ok(serde_json::from_slice(response.body().as_ref()).map_err(DeserializeError::Json)?)
+}
+
+/// Status and headers of a response.
+///
+/// Returned along with the body by [`handle_response`], so that values can be
+/// parsed from the headers without going back to the response.
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseContext<'a> {
+    status: StatusCode,
+    headers: &'a HeaderMap,
+}
+
+impl<'a> ResponseContext<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new<B>(response: &'a http::Response<B>) -> Self {
+        Self {
+            status: response.status(),
+            headers: response.headers(),
+        }
     }
+
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn headers(&self) -> &'a HeaderMap {
+        self.headers
+    }
+
+    /// Parses a header value to an integer, see [`parse_from_header`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeserializeError` if the header is missing or invalid.
+    #[inline]
+    pub fn header<T, K>(&self, key: K) -> Result<T, DeserializeError>
+    where
+        T: FromStr<Err = ParseIntError>,
+        K: AsHeaderName,
+    {
+        parse_from_header(self.headers, key)
+    }
+
+    /// Wraps `items` in a [`PaginationResponse`] using the pagination headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeserializeError` if the pagination headers are missing or
+    /// invalid.
+    #[inline]
+    pub fn paginated<T>(&self, items: Vec<T>) -> Result<PaginationResponse<T>, DeserializeError> {
+        PaginationResponse::from_headers(items, self.headers)
+    }
+}
+
+/// Like [`handle_response_body`], but also returns the [`ResponseContext`] of
+/// the response for parsing headers.
+///
+/// ```
+/// # use http::StatusCode;
+/// # use trakt_core::{error::FromHttpError, handle_response, PaginationResponse};
+/// fn parse(
+///     response: &http::Response<Vec<u8>>,
+/// ) -> Result<(PaginationResponse<u64>, u64), FromHttpError> {
+///     let (body, ctx) = handle_response(response, StatusCode::OK)?;
+///     Ok((ctx.paginated(body)?, ctx.header("X-Trending-User-Count")?))
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a `FromHttpError` if the response status code is not the expected
+/// one or if the body failed to be deserialized.
+pub fn handle_response<B, T>(
+    response: &http::Response<B>,
+    expected: StatusCode,
+) -> Result<(T, ResponseContext<'_>), FromHttpError>
+where
+    B: AsRef<[u8]>,
+    T: serde::de::DeserializeOwned,
+{
+    let body = handle_response_body(response, expected)?;
+    Ok((body, ResponseContext::new(response)))
+}
+
+/// Helper function to construct the URI of a request using the given context,
+/// metadata, and path/query values.
+///
+/// Unlike [`construct_req`], this doesn't check the authorization requirement.
+///
+/// # Errors
+///
+/// Returns an `IntoHttpError` if the URI cannot be constructed.
+pub fn construct_uri(
+    ctx: &Context,
+    md: &Metadata,
+    path: &impl Serialize,
+    query: &impl Serialize,
+) -> Result<http::Uri, IntoHttpError> {
+    let url = crate::construct_url(ctx.base_url, md.endpoint, path, query)?;
+    Ok(http::Uri::try_from(url).map_err(http::Error::from)?)
 }
 
 /// Helper function to construct an HTTP request using the given context,
@@ -147,6 +566,9 @@ pub fn construct_req<B>(
     let request = match (md.auth, ctx.oauth_token) {
         (AuthRequirement::None, _) | (AuthRequirement::Optional, None) => request,
         (AuthRequirement::Optional | AuthRequirement::Required, Some(token)) => {
+            if !is_valid_token(token) {
+                return Err(IntoHttpError::InvalidToken);
+            }
             request.header("Authorization", format!("Bearer {token}"))
         }
         (AuthRequirement::Required, None) => {
@@ -156,11 +578,150 @@ pub fn construct_req<B>(
     Ok(request.body(body)?)
 }
 
+/// Checks that an OAuth token can be used in a bearer `Authorization` header.
+fn is_valid_token(token: &str) -> bool {
+    !token.is_empty() && token.bytes().all(|b| b.is_ascii_graphic())
+}
+
 #[cfg(test)]
 mod tests {
     use http::HeaderValue;
+    use trakt_fixtures::pagination_headers;
 
     use super::*;
+    use crate::error::UrlError;
+
+    #[test]
+    fn test_handle_response() {
+        let mut response = http::Response::builder()
+            .status(StatusCode::OK)
+            .header("X-Trending-User-Count", "42")
+            .body(b"[1, 2]".to_vec())
+            .unwrap();
+        response
+            .headers_mut()
+            .extend(pagination_headers("1", "2", "3", "6"));
+
+        let (body, ctx) = handle_response::<_, Vec<u64>>(&response, StatusCode::OK).unwrap();
+        assert_eq!(ctx.status(), StatusCode::OK);
+        assert_eq!(ctx.header::<u64, _>("X-Trending-User-Count").unwrap(), 42);
+        assert!(ctx.header::<u64, _>("X-Missing").is_err());
+
+        let items = ctx.paginated(body).unwrap();
+        assert_eq!(items.items(), [1, 2]);
+        assert_eq!(items.total_pages(), 3);
+
+        let res = handle_response::<_, Vec<u64>>(&response, StatusCode::CREATED);
+        assert!(matches!(
+            res,
+            Err(FromHttpError::Api(ApiError::UnknownError(_)))
+        ));
+    }
+
+    #[test]
+    fn test_pagination_try_new() {
+        assert_eq!(Pagination::try_new(1, 10), Ok(Pagination::new(1, 10)));
+        assert_eq!(
+            Pagination::try_new(2, Pagination::MAX_LIMIT),
+            Ok(Pagination::new(2, 100))
+        );
+        assert_eq!(Pagination::try_new(0, 10), Err(PaginationError::ZeroPage));
+        assert_eq!(Pagination::try_new(1, 0), Err(PaginationError::ZeroLimit));
+        assert_eq!(
+            Pagination::try_new(1, 101),
+            Err(PaginationError::LimitTooLarge(101))
+        );
+        assert!(Pagination::default().validate().is_ok());
+        assert_eq!(Pagination::validate_limit(Pagination::MAX_LIMIT), Ok(()));
+        assert_eq!(
+            Pagination::validate_limit(0),
+            Err(PaginationError::ZeroLimit)
+        );
+    }
+
+    #[test]
+    fn test_pagination_response_pages() {
+        let map = pagination_headers("2", "10", "3", "25");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.pagination(), Pagination::new(2, 10));
+        assert_eq!(res.next_page(), Some(Pagination::new(3, 10)));
+        assert_eq!(res.prev_page(), Some(Pagination::new(1, 10)));
+        assert_eq!(res.last_page(), Some(Pagination::new(3, 10)));
+
+        let map = pagination_headers("1", "10", "3", "25");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.prev_page(), None);
+
+        let map = pagination_headers("3", "10", "3", "25");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.next_page(), None);
+
+        // Past the last page
+        let map = pagination_headers("7", "10", "3", "25");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.next_page(), None);
+        assert_eq!(res.prev_page(), Some(Pagination::new(3, 10)));
+
+        // No items
+        let map = pagination_headers("1", "10", "0", "0");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.next_page(), None);
+        assert_eq!(res.prev_page(), None);
+        assert_eq!(res.last_page(), None);
+    }
+
+    #[test]
+    fn test_pagination_response_was_clamped() {
+        let map = pagination_headers("1", "50", "5", "250");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.requested_limit(), None);
+        assert!(!res.was_clamped());
+
+        let res = res.with_request(Pagination::new(1, Pagination::MAX_LIMIT));
+        assert_eq!(res.requested_limit(), Some(Pagination::MAX_LIMIT));
+        assert!(res.was_clamped());
+
+        let res = res.with_request(Pagination::new(1, 50));
+        assert!(!res.was_clamped());
+    }
+
+    #[test]
+    fn test_pagination_response_clamping() {
+        // Page count larger than the item count allows
+        let map = pagination_headers("1", "10", "18446744073709551615", "25");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.total_pages(), 3);
+        assert_eq!(res.last_page(), Some(Pagination::new(3, 10)));
+
+        // Zero limit
+        let map = pagination_headers("1", "0", "5", "25");
+        let res = PaginationResponse::<()>::from_headers(vec![], &map).unwrap();
+        assert_eq!(res.total_pages(), 0);
+        assert_eq!(res.next_page(), None);
+        assert_eq!(res.prev_page(), None);
+        assert_eq!(res.last_page(), None);
+
+        // Current page at the maximum
+        #[allow(deprecated)]
+        let res = PaginationResponse::<()> {
+            items: vec![],
+            current_page: usize::MAX,
+            items_per_page: 10,
+            total_pages: usize::MAX,
+            total_items: usize::MAX,
+            requested_limit: None,
+        };
+        assert_eq!(res.next_page(), None);
+        assert_eq!(res.prev_page(), Some(Pagination::new(usize::MAX - 1, 10)));
+    }
+
+    #[test]
+    fn test_pagination_response_into_items() {
+        let map = pagination_headers("1", "10", "1", "3");
+        let res = PaginationResponse::from_headers(vec![1, 2, 3], &map).unwrap();
+        assert_eq!(res.clone().into_items(), [1, 2, 3]);
+        assert_eq!(Vec::from(res), [1, 2, 3]);
+    }
 
     #[test]
     fn test_parse_from_header() {
@@ -184,6 +745,21 @@ mod tests {
         assert_eq!(parse_from_header::<u32, _>(&map, "D").unwrap(), 10);
     }
 
+    #[test]
+    fn test_handle_response_status() {
+        let response = http::Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(b"")
+            .unwrap();
+        assert!(handle_response_status(&response, StatusCode::NO_CONTENT).is_ok());
+        assert!(matches!(
+            handle_response_status(&response, StatusCode::OK),
+            Err(FromHttpError::Api(ApiError::UnknownError(
+                StatusCode::NO_CONTENT
+            )))
+        ));
+    }
+
     #[test]
     fn test_handle_response_body_ok() {
         let response = http::Response::builder()
@@ -220,6 +796,47 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_handle_response_body_max_size() {
+        let response = http::Response::builder()
+            .status(StatusCode::OK)
+            .body(b"\"hello\"")
+            .unwrap();
+        let options = ParseOptions {
+            max_body_size: Some(7),
+        };
+        assert_eq!(
+            handle_response_body_with::<_, String>(&response, StatusCode::OK, &options).unwrap(),
+            "hello"
+        );
+
+        let options = ParseOptions {
+            max_body_size: Some(6),
+        };
+        assert!(matches!(
+            handle_response_body_with::<_, String>(&response, StatusCode::OK, &options),
+            Err(FromHttpError::Deserialize(DeserializeError::BodyTooLarge {
+                size: 7,
+                max: 6
+            }))
+        ));
+        assert!(ParseOptions::default().check(&response).is_ok());
+
+        // The size is checked before the status, like in
+        // `Response::try_from_http_response_with`
+        let response = http::Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(b"\"hello\"")
+            .unwrap();
+        assert!(matches!(
+            handle_response_body_with::<_, String>(&response, StatusCode::OK, &options),
+            Err(FromHttpError::Deserialize(DeserializeError::BodyTooLarge {
+                size: 7,
+                max: 6
+            }))
+        ));
+    }
+
     #[allow(clippy::cognitive_complexity)]
     #[test]
     fn test_construct_req() {
@@ -228,11 +845,7 @@ mod tests {
             client_id: "client id",
             oauth_token: None,
         };
-        let mut md = Metadata {
-            endpoint: "/test",
-            method: http::Method::GET,
-            auth: AuthRequirement::None,
-        };
+        let mut md = Metadata::new("/test", http::Method::GET, AuthRequirement::None);
 
         let req = construct_req(&ctx, &md, &(), &(), "body").unwrap();
         assert_eq!(req.method(), &http::Method::GET);
@@ -296,4 +909,59 @@ mod tests {
         assert_eq!(req.headers().get("Authorization").unwrap(), "Bearer token");
         assert_eq!(req.into_body(), "body");
     }
+
+    #[test]
+    fn test_construct_uri() {
+        #[derive(Serialize)]
+        struct Path {
+            id: u32,
+        }
+        #[derive(Serialize)]
+        struct Query {
+            page: u32,
+        }
+
+        let ctx = Context {
+            base_url: "https://api.trakt.tv",
+            client_id: "client id",
+            oauth_token: None,
+        };
+        let md = Metadata::new("/test/{id}", http::Method::GET, AuthRequirement::Required);
+
+        // Doesn't require the token
+        let uri = construct_uri(&ctx, &md, &Path { id: 1 }, &Query { page: 2 }).unwrap();
+        assert_eq!(uri, "https://api.trakt.tv/test/1?page=2");
+
+        let ctx = Context {
+            base_url: "not a url",
+            ..ctx
+        };
+        let err = construct_uri(&ctx, &md, &Path { id: 1 }, &()).unwrap_err();
+        assert!(matches!(
+            err,
+            IntoHttpError::UrlParams(UrlError::InvalidBaseUrl(_))
+        ));
+
+        let ctx = Context {
+            base_url: "https://api.trakt.tv/",
+            ..ctx
+        };
+        let uri = construct_uri(&ctx, &md, &Path { id: 1 }, &()).unwrap();
+        assert_eq!(uri, "https://api.trakt.tv/test/1");
+    }
+
+    #[test]
+    fn test_construct_req_invalid_token() {
+        let md = Metadata::new("/test", http::Method::GET, AuthRequirement::Optional);
+
+        for token in ["", "token\n", " token", "tok en", "tøken", "token\0"] {
+            let ctx = Context {
+                base_url: "https://api.trakt.tv",
+                client_id: "client id",
+                oauth_token: Some(token),
+            };
+            let result = construct_req(&ctx, &md, &(), &(), "body").unwrap_err();
+            assert!(matches!(result, IntoHttpError::InvalidToken), "{token:?}");
+        }
+    }
 }
@@ -0,0 +1,91 @@
+//! Property tests for URL construction and emoji shortcode replacement.
+
+use proptest::prelude::*;
+use serde::Serialize;
+use trakt_core::{construct_url, EmojiString};
+
+#[derive(Serialize)]
+struct Id<'a> {
+    id: &'a str,
+}
+
+#[derive(Serialize)]
+struct Optional<'a> {
+    id: Option<&'a str>,
+}
+
+/// Characters that must always be percent-encoded in a path segment.
+const ENCODED: &[char] = &[' ', '"', '#', '<', '>', '?', '`', '{', '}'];
+
+proptest! {
+    #[test]
+    fn construct_url_arbitrary_endpoint(endpoint in "\\PC*", id in "\\PC*") {
+        let _ = construct_url("https://api.trakt.tv", &endpoint, &Id { id: &id }, &());
+    }
+
+    #[test]
+    fn construct_url_arbitrary_value(id in "\\PC*") {
+        let url = construct_url("", "/shows/{id}/seasons", &Id { id: &id }, &()).unwrap();
+        prop_assert!(url.starts_with("/shows/"));
+        prop_assert!(url.ends_with("/seasons"));
+
+        let segment = &url["/shows/".len()..url.len() - "/seasons".len()];
+        prop_assert!(!segment.contains(ENCODED));
+        prop_assert!(!segment.contains(char::is_control));
+    }
+
+    #[test]
+    fn construct_url_optional_value(id in proptest::option::of("[a-z0-9]+")) {
+        let url =
+            construct_url("", "/shows/{id}", &Optional { id: id.as_deref() }, &()).unwrap();
+        match id {
+            Some(id) => prop_assert_eq!(url, format!("/shows/{id}")),
+            None => prop_assert_eq!(url, "/shows"),
+        }
+    }
+
+    #[test]
+    fn construct_url_numeric_values(a in any::<u64>(), b in any::<i64>(), c in any::<f64>()) {
+        #[derive(Serialize)]
+        struct Params {
+            a: u64,
+            b: i64,
+            c: f64,
+        }
+
+        let url = construct_url("", "/{a}/{b}/{c}", &Params { a, b, c }, &()).unwrap();
+        let prefix = format!("/{a}/{b}/");
+        prop_assert!(url.starts_with(&prefix));
+    }
+
+    #[test]
+    fn emoji_string_arbitrary(s in "\\PC*") {
+        let _ = EmojiString::from(s.as_str());
+    }
+
+    #[test]
+    fn emoji_string_without_colons(s in "[^:]*") {
+        prop_assert_eq!(&*EmojiString::from(s.as_str()), s.as_str());
+    }
+
+    #[test]
+    fn emoji_string_shortcodes(parts in proptest::collection::vec(
+        prop_oneof![
+            "[^:]*".prop_map(|s| (s.clone(), s)),
+            Just((":rocket:".to_owned(), "🚀".to_owned())),
+            Just((":tada:".to_owned(), "🎉".to_owned())),
+        ],
+        0..16,
+    )) {
+        let input = parts.iter().map(|(i, _)| i.as_str()).collect::<String>();
+        let output = parts.iter().map(|(_, o)| o.as_str()).collect::<String>();
+        prop_assert_eq!(&*EmojiString::from(input.as_str()), output.as_str());
+    }
+
+    #[test]
+    fn emoji_string_deserialize(s in "\\PC*") {
+        let json = serde_json::to_string(&s).unwrap();
+        let de: EmojiString = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(de, EmojiString::from(s.as_str()));
+    }
+}
@@ -0,0 +1,102 @@
+//! Trakt.tv API payloads shared by the tests of the trakt crates.
+//!
+//! The payloads are based on the examples of the
+//! [API documentation](https://trakt.docs.apiary.io), and are stored as JSON files in the
+//! `fixtures` directory.
+#![forbid(unsafe_code)]
+#![warn(
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::cargo,
+    clippy::as_underscore,
+    clippy::clone_on_ref_ptr,
+    clippy::format_push_string,
+    clippy::mod_module_files,
+    clippy::str_to_string
+)]
+
+use http::{HeaderMap, HeaderValue};
+
+/// A user, without extended info.
+pub const USER: &str = include_str!("../fixtures/user.json");
+/// A movie, without extended info.
+pub const MOVIE: &str = include_str!("../fixtures/movie.json");
+/// A show, without extended info.
+pub const SHOW: &str = include_str!("../fixtures/show.json");
+/// An episode, without extended info.
+pub const EPISODE: &str = include_str!("../fixtures/episode.json");
+/// Search results with a single [`MOVIE`].
+pub const SEARCH_MOVIE: &str = include_str!("../fixtures/search_movie.json");
+/// A personal list of [`USER`].
+pub const LIST: &str = include_str!("../fixtures/list.json");
+/// Studios of [`MOVIE`].
+pub const STUDIOS: &str = include_str!("../fixtures/studios.json");
+
+/// Parses a fixture, to embed it in a larger payload with
+/// [`serde_json::json!`].
+///
+/// # Panics
+///
+/// Panics if the fixture is not valid JSON.
+#[must_use]
+pub fn json(fixture: &str) -> serde_json::Value {
+    serde_json::from_str(fixture).expect("fixture is valid JSON")
+}
+
+/// Returns the pagination headers of a paginated response.
+///
+/// # Panics
+///
+/// Panics if a value is not a valid header value.
+#[must_use]
+pub fn pagination_headers(page: &str, limit: &str, pages: &str, items: &str) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (name, value) in [
+        ("X-Pagination-Page", page),
+        ("X-Pagination-Limit", limit),
+        ("X-Pagination-Page-Count", pages),
+        ("X-Pagination-Item-Count", items),
+    ] {
+        map.insert(
+            name,
+            HeaderValue::from_str(value).expect("valid header value"),
+        );
+    }
+    map
+}
+
+/// Creates a successful paginated response with the given body and pagination
+/// headers.
+///
+/// # Panics
+///
+/// Panics if a value is not a valid header value.
+#[must_use]
+pub fn paginated<B>(
+    body: B,
+    page: usize,
+    limit: usize,
+    pages: usize,
+    items: usize,
+) -> http::Response<B> {
+    let mut response = http::Response::new(body);
+    *response.headers_mut() = pagination_headers(
+        &page.to_string(),
+        &limit.to_string(),
+        &pages.to_string(),
+        &items.to_string(),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixtures_are_valid_json() {
+        for fixture in [USER, MOVIE, SHOW, EPISODE, SEARCH_MOVIE, STUDIOS] {
+            assert!(!json(fixture).is_null());
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#![forbid(unsafe_code)]
 #![warn(
     clippy::pedantic,
     clippy::nursery,
@@ -13,6 +14,7 @@
 mod paginated;
 mod request;
 mod response;
+mod utils;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
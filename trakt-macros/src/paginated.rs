@@ -15,7 +15,11 @@ pub fn derive_paginated<const WRAP: bool>(input: &DeriveInput) -> syn::Result<To
         ));
     }
 
-    let Some(Pagination { field }) = check_pagination(input)? else {
+    let Some(Pagination {
+        field,
+        member: i_field,
+    }) = check_pagination(input)?
+    else {
         return Err(syn::Error::new(
             Span::call_site(),
             "missing #[trakt(pagination)] attribute",
@@ -23,30 +27,26 @@ pub fn derive_paginated<const WRAP: bool>(input: &DeriveInput) -> syn::Result<To
     };
     let tp = extract_item(&field.ty)?;
 
-    let Some(i_field) = &field.ident else {
-        return Err(syn::Error::new(Span::call_site(), "missing field name"));
-    };
-
     let expanded = quote! {
         #[automatically_derived]
         impl _trakt_core::PaginatedResponse for #name {
             type Item = #tp;
 
             fn items(&self) -> &[Self::Item] {
-                &self.#i_field.items
+                self.#i_field.items()
             }
 
-            fn next_page(&self) -> Option<_trakt_core::Pagination> {
+            fn next_page(&self) -> ::core::option::Option<_trakt_core::Pagination> {
                 self.#i_field.next_page()
             }
         }
     };
 
     let wrap = if WRAP {
+        let import = crate::utils::import_core(get_crate(input)?.as_ref());
         quote! {
             const _: () = {
-                #[allow(unused_extern_crates, clippy::useless_attribute)]
-                extern crate trakt_core as _trakt_core;
+                #import
                 #expanded
             };
         }
@@ -57,6 +57,23 @@ pub fn derive_paginated<const WRAP: bool>(input: &DeriveInput) -> syn::Result<To
     Ok(wrap)
 }
 
+fn get_crate(input: &DeriveInput) -> syn::Result<Option<syn::Path>> {
+    let mut krate = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("trakt") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    krate = Some(crate::utils::parse_crate(&meta)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown attribute"))
+                }
+            })?;
+        }
+    }
+    Ok(krate)
+}
+
 /// Extracts the inner type of `PaginationResponse<T>` type.
 fn extract_item(tp: &syn::Type) -> syn::Result<&syn::Type> {
     let syn::Type::Path(type_path) = tp else {
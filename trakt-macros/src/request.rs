@@ -2,8 +2,8 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote};
 use syn::{
-    parse_macro_input, punctuated::Punctuated, spanned::Spanned, DeriveInput, Field, Fields,
-    LitStr, Token, Type,
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Attribute, DeriveInput, Field,
+    Fields, LitStr, Path, Token, Type,
 };
 
 pub fn derive_request(input: TokenStream) -> TokenStream {
@@ -23,6 +23,8 @@ pub fn derive_request(input: TokenStream) -> TokenStream {
         method,
         auth,
         response,
+        krate,
+        example,
     } = match derive_request_attrs(&input) {
         Ok(a) => a,
         Err(e) => return e.to_compile_error().into(),
@@ -40,29 +42,44 @@ pub fn derive_request(input: TokenStream) -> TokenStream {
     let SerializeStructs {
         q_ident,
         p_ident,
+        optional,
         stream,
     } = match derive_request_structs(&input, &endpoint.value()) {
         Ok(s) => s,
         Err(e) => return e.to_compile_error().into(),
     };
 
+    let example = example.map(|example| example_doc(&example, krate.as_ref()));
+    let limits = match limit_checks(&input) {
+        Ok(limits) => limits,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let validate = pagination_fields(&input)
+        .map(|field| quote!(self.#field.validate()?;))
+        .chain(limits)
+        .collect::<proc_macro2::TokenStream>();
+
     let expanded = quote! {
         #stream
+        #example
         #[automatically_derived]
         impl _trakt_core::Request for #name {
             type Response = #response;
 
-            const METADATA: _trakt_core::Metadata = _trakt_core::Metadata {
-                endpoint: #endpoint,
-                method: _http::Method::#method,
-                auth: _trakt_core::AuthRequirement::#auth,
-            };
+            const METADATA: _trakt_core::Metadata = _trakt_core::Metadata::new(
+                #endpoint,
+                _http::Method::#method,
+                _trakt_core::AuthRequirement::#auth,
+            )
+            .with_name(_trakt_core::endpoint_name!())
+            .with_optional_params(&[#(#optional),*]);
 
-            fn try_into_http_request<T: Default + _bytes::BufMut>(
+            fn try_into_http_request<T: ::core::default::Default + _bytes::BufMut>(
                 self,
                 ctx: _trakt_core::Context,
-            ) -> Result<_http::Request<T>, _trakt_core::error::IntoHttpError> {
-                let (path, query): (#p_ident, #q_ident) = self.into();
+            ) -> ::core::result::Result<_http::Request<T>, _trakt_core::error::IntoHttpError> {
+                #validate
+                let (path, query): (#p_ident, #q_ident) = ::core::convert::Into::into(self);
                 _trakt_core::construct_req(
                     &ctx,
                     &Self::METADATA,
@@ -71,19 +88,23 @@ pub fn derive_request(input: TokenStream) -> TokenStream {
                     T::default(),
                 )
             }
+
+            fn uri(
+                &self,
+                ctx: _trakt_core::Context,
+            ) -> ::core::result::Result<_http::Uri, _trakt_core::error::IntoHttpError> {
+                #validate
+                let (path, query): (#p_ident, #q_ident) =
+                    ::core::convert::Into::into(::core::clone::Clone::clone(self));
+                _trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+            }
         }
     };
 
+    let import = crate::utils::import_core(krate.as_ref());
     let wrap = quote! {
         const _: () = {
-            #[allow(unused_extern_crates, clippy::useless_attribute)]
-            extern crate http as _http;
-            #[allow(unused_extern_crates, clippy::useless_attribute)]
-            extern crate bytes as _bytes;
-            #[allow(unused_extern_crates, clippy::useless_attribute)]
-            extern crate trakt_core as _trakt_core;
-            #[allow(unused_extern_crates, clippy::useless_attribute)]
-            extern crate serde as _serde;
+            #import
             #expanded
         };
     };
@@ -91,6 +112,49 @@ pub fn derive_request(input: TokenStream) -> TokenStream {
     TokenStream::from(wrap)
 }
 
+/// Fields of type `Pagination`, which are validated before building the
+/// request.
+fn pagination_fields(input: &DeriveInput) -> impl Iterator<Item = &Ident> {
+    let fields = match &input.data {
+        syn::Data::Struct(data) => Some(&data.fields),
+        _ => None,
+    };
+    fields
+        .into_iter()
+        .flatten()
+        .filter_map(|field| match &field.ty {
+            Type::Path(ty) if ty.path.segments.last()?.ident == "Pagination" => {
+                field.ident.as_ref()
+            }
+            _ => None,
+        })
+}
+
+/// Checks of the fields marked `#[trakt(limit)]`, which are validated like the
+/// limit of a `Pagination` before building the request.
+fn limit_checks(input: &DeriveInput) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let syn::Data::Struct(data) = &input.data else {
+        return Ok(Vec::new());
+    };
+    let mut checks = Vec::new();
+    for field in &data.fields {
+        if !field_attrs(field)?.limit {
+            continue;
+        }
+        let ident = &field.ident;
+        checks.push(if is_option(&field.ty) {
+            quote! {
+                if let ::core::option::Option::Some(limit) = self.#ident {
+                    _trakt_core::Pagination::validate_limit(limit)?;
+                }
+            }
+        } else {
+            quote!(_trakt_core::Pagination::validate_limit(self.#ident)?;)
+        });
+    }
+    Ok(checks)
+}
+
 fn parse_url_params(endpoint: &str) -> Vec<&str> {
     let mut params = vec![];
     for (i, c) in endpoint.char_indices() {
@@ -107,6 +171,74 @@ struct RequestAttrs {
     method: Ident,
     auth: Ident,
     response: Option<Type>,
+    krate: Option<Path>,
+    example: Option<Example>,
+}
+
+/// A `#[trakt(example(request = "...", url = "..."))]` attribute.
+struct Example {
+    /// Expression constructing the request
+    request: LitStr,
+    /// URL of the request
+    url: LitStr,
+}
+
+fn parse_example(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Example> {
+    let mut request = None;
+    let mut url = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("request") {
+            let value: LitStr = meta.value()?.parse()?;
+            // Report invalid expressions here instead of in the doc test
+            value.parse::<syn::Expr>()?;
+            request = Some(value);
+            Ok(())
+        } else if meta.path.is_ident("url") {
+            url = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported example attribute"))
+        }
+    })?;
+
+    match (request, url) {
+        (Some(request), Some(url)) => Ok(Example { request, url }),
+        (None, _) => Err(meta.error("missing `request` in example")),
+        (_, None) => Err(meta.error("missing `url` in example")),
+    }
+}
+
+/// Generates the doc comment of the `Request` impl, with a doc test that
+/// constructs the example request and checks its URL.
+fn example_doc(example: &Example, krate: Option<&Path>) -> proc_macro2::TokenStream {
+    let core = krate.map_or_else(|| String::from("trakt_core"), |k| quote!(#k).to_string());
+    let request = example.request.value();
+    let url = example.url.value();
+
+    let lines = [
+        String::from(" # Example"),
+        String::new(),
+        String::from(" ```"),
+        format!(" # use {core}::Request as _;"),
+    ];
+    let module = " # #[allow(unused_imports)] use ";
+    let rest = [
+        format!(" let ctx = {core}::Context {{"),
+        String::from("     base_url: \"https://api.trakt.tv\","),
+        String::from("     client_id: \"client_id\","),
+        String::from("     oauth_token: None,"),
+        String::from(" };"),
+        String::new(),
+        format!(" let req = {request};"),
+        format!(" assert_eq!(req.uri(ctx).unwrap(), {url:?});"),
+        String::from(" ```"),
+    ];
+
+    quote! {
+        #(#[doc = #lines])*
+        #[doc = ::core::concat!(#module, ::core::module_path!(), "::*;")]
+        #(#[doc = #rest])*
+    }
 }
 
 fn derive_request_attrs(input: &DeriveInput) -> syn::Result<RequestAttrs> {
@@ -115,6 +247,8 @@ fn derive_request_attrs(input: &DeriveInput) -> syn::Result<RequestAttrs> {
         method: format_ident!("GET"),
         auth: format_ident!("None"),
         response: None,
+        krate: None,
+        example: None,
     };
 
     for attr in &input.attrs {
@@ -135,6 +269,12 @@ fn derive_request_attrs(input: &DeriveInput) -> syn::Result<RequestAttrs> {
                 } else if meta.path.is_ident("auth") {
                     ret.auth = meta.value()?.parse()?;
                     Ok(())
+                } else if meta.path.is_ident("crate") {
+                    ret.krate = Some(crate::utils::parse_crate(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("example") {
+                    ret.example = Some(parse_example(&meta)?);
+                    Ok(())
                 } else {
                     Err(meta.error("unsupported attribute"))
                 }
@@ -148,6 +288,8 @@ fn derive_request_attrs(input: &DeriveInput) -> syn::Result<RequestAttrs> {
 struct SerializeStructs {
     q_ident: Ident,
     p_ident: Ident,
+    /// Names of the path params that may be omitted
+    optional: Vec<String>,
     stream: proc_macro2::TokenStream,
 }
 
@@ -158,18 +300,27 @@ fn derive_request_structs(input: &DeriveInput, endpoint: &str) -> syn::Result<Se
             "Request structs must be structs",
         ));
     };
+    // Container serde attributes (e.g. `rename_all`) apply to the query params.
+    // Path params are matched to the endpoint by field name, so renaming them
+    // would break the endpoint.
+    let serde_attrs = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .collect::<Vec<_>>();
     match &data.fields {
-        Fields::Named(f) => make_structs(&input.ident, &f.named, endpoint),
+        Fields::Named(f) => make_structs(&input.ident, &serde_attrs, &f.named, endpoint),
         Fields::Unnamed(_) => Err(syn::Error::new(
             Span::call_site(),
             "Request structs cannot have unnamed fields",
         )),
-        Fields::Unit => make_structs(&input.ident, &Punctuated::new(), endpoint),
+        Fields::Unit => make_structs(&input.ident, &serde_attrs, &Punctuated::new(), endpoint),
     }
 }
 
 fn make_structs(
     ident: &Ident,
+    serde_attrs: &[&Attribute],
     fields: &Punctuated<Field, Token![,]>,
     endpoint: &str,
 ) -> syn::Result<SerializeStructs> {
@@ -177,16 +328,30 @@ fn make_structs(
 
     let mut path_params = Punctuated::<_, Token![,]>::new();
     let mut query_params = Punctuated::<_, Token![,]>::new();
+    let mut optional = Vec::new();
     for field in fields {
         let ident = field.ident.as_ref().unwrap();
+        let marked_optional = field_attrs(field)?.optional;
 
         let idx = path_params_str
             .iter()
             .position(|&s| s == &*ident.to_string());
         if let Some(idx) = idx {
             path_params_str.swap_remove(idx);
+            if marked_optional || is_option(&field.ty) {
+                optional.push(ident.to_string());
+            }
+            let mut field = field.clone();
+            field.attrs.retain(|attr| !attr.path().is_ident("trakt"));
             path_params.push(field);
+        } else if marked_optional {
+            return Err(syn::Error::new(
+                field.span(),
+                "only path parameters can be optional",
+            ));
         } else {
+            let mut field = skip_none(field);
+            field.attrs.retain(|attr| !attr.path().is_ident("trakt"));
             query_params.push(field);
         }
     }
@@ -211,17 +376,20 @@ fn make_structs(
     let stream = quote! {
         #[doc(hidden)]
         #[derive(Debug, Clone, _serde::Serialize)]
+        #[serde(crate = "_serde")]
+        #(#serde_attrs)*
         struct #q_ident {
             #query_params
         }
 
         #[doc(hidden)]
         #[derive(Debug, Clone, _serde::Serialize)]
+        #[serde(crate = "_serde")]
         struct #p_ident {
             #path_params
         }
 
-        impl std::convert::From<#ident> for (#p_ident, #q_ident) {
+        impl ::core::convert::From<#ident> for (#p_ident, #q_ident) {
             fn from(req: #ident) -> Self {
                 let #ident { #(#p_names,)* #(#q_names,)* } = req;
                 (#p_ident { #(#p_names,)* }, #q_ident { #(#q_names,)* })
@@ -232,6 +400,93 @@ fn make_structs(
     Ok(SerializeStructs {
         q_ident,
         p_ident,
+        optional,
         stream,
     })
 }
+
+/// Attributes of a request field.
+#[derive(Default)]
+struct FieldAttrs {
+    /// `#[trakt(optional)]` marks a path param that may be omitted even though
+    /// it isn't an `Option`, e.g. because it serializes to `None` when empty.
+    optional: bool,
+    /// `#[trakt(limit)]` marks a `usize` limit that must be accepted by the
+    /// API, see `Pagination::validate_limit`.
+    limit: bool,
+}
+
+fn field_attrs(field: &Field) -> syn::Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("trakt"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("optional") {
+                attrs.optional = true;
+                Ok(())
+            } else if meta.path.is_ident("limit") {
+                attrs.limit = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        })?;
+    }
+    Ok(attrs)
+}
+
+/// Skips serializing `None` query params, unless the field already controls its
+/// serialization.
+///
+/// Path params must not be skipped, as `None` is what omits them from the URL.
+fn skip_none(field: &Field) -> Field {
+    let mut field = field.clone();
+    if is_option(&field.ty) && !controls_serialization(&field) {
+        field.attrs.push(
+            syn::parse_quote!(#[serde(skip_serializing_if = "::core::option::Option::is_none")]),
+        );
+    }
+    field
+}
+
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option")
+}
+
+fn controls_serialization(field: &Field) -> bool {
+    let mut found = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+    {
+        // Other serde attributes are handled by serde itself
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip")
+                || meta.path.is_ident("skip_serializing")
+                || meta.path.is_ident("skip_serializing_if")
+                || meta.path.is_ident("flatten")
+                || meta.path.is_ident("serialize_with")
+                || meta.path.is_ident("with")
+            {
+                found = true;
+            }
+            if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        });
+    }
+    found
+}
@@ -1,37 +1,54 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{spanned::Spanned, DeriveInput, Error, Field, Fields, Ident, Result};
+use syn::{
+    spanned::Spanned, DeriveInput, Error, Field, Fields, Ident, Index, Member, Path, Result,
+};
 
 pub fn derive_response(input: &DeriveInput) -> Result<TokenStream> {
     let name = &input.ident;
 
-    let expected = get_expected(input)?;
+    let Attrs {
+        expected,
+        empty,
+        krate,
+    } = get_attrs(input)?;
 
-    let pagination = check_pagination(input)?;
-
-    let DeriveResponse { body, extra } = match pagination {
-        Some(pagination) => derive_pagination(input, pagination, &expected)?,
-        None => derive_normal(input, &expected)?,
+    let DeriveResponse { body, extra } = if let syn::Data::Enum(data) = &input.data {
+        if let Some(empty) = &empty {
+            return Err(Error::new(
+                empty.span(),
+                "`empty` is not supported on enum responses",
+            ));
+        }
+        derive_enum(data, &expected)?
+    } else {
+        derive_struct_response(input, &expected, empty.as_ref())?
     };
 
     let expanded = quote! {
         #[automatically_derived]
         impl _trakt_core::Response for #name {
-            fn try_from_http_response<T: AsRef<[u8]>>(
-                response: http::Response<T>,
-            ) -> Result<Self, _trakt_core::error::FromHttpError> {
+            fn try_from_http_response<T: ::core::convert::AsRef<[u8]>>(
+                response: _http::Response<T>,
+            ) -> ::core::result::Result<Self, _trakt_core::error::FromHttpError> {
                 #body
             }
         }
+        #[automatically_derived]
+        impl<T: ::core::convert::AsRef<[u8]>> ::core::convert::TryFrom<_http::Response<T>> for #name {
+            type Error = _trakt_core::error::FromHttpError;
+
+            fn try_from(response: _http::Response<T>) -> ::core::result::Result<Self, Self::Error> {
+                <Self as _trakt_core::Response>::try_from_http_response(response)
+            }
+        }
         #extra
     };
 
+    let import = crate::utils::import_core(krate.as_ref());
     let wrap = quote! {
         const _: () = {
-            #[allow(unused_extern_crates, clippy::useless_attribute)]
-            extern crate http as _http;
-            #[allow(unused_extern_crates, clippy::useless_attribute)]
-            extern crate trakt_core as _trakt_core;
+            #import
             #expanded
         };
     };
@@ -39,8 +56,19 @@ pub fn derive_response(input: &DeriveInput) -> Result<TokenStream> {
     Ok(wrap)
 }
 
-fn get_expected(input: &DeriveInput) -> Result<Ident> {
+struct Attrs {
+    /// The status code of a successful response
+    expected: Ident,
+    /// The status code of a successful response without a body
+    empty: Option<Ident>,
+    /// The path to `trakt_core`
+    krate: Option<Path>,
+}
+
+fn get_attrs(input: &DeriveInput) -> Result<Attrs> {
     let mut expected = None;
+    let mut empty = None;
+    let mut krate = None;
     for attr in &input.attrs {
         if attr.path().is_ident("trakt") {
             attr.parse_nested_meta(|meta| {
@@ -48,6 +76,13 @@ fn get_expected(input: &DeriveInput) -> Result<Ident> {
                     let value = meta.value()?;
                     expected = Some(value.parse()?);
                     Ok(())
+                } else if meta.path.is_ident("empty") {
+                    let value = meta.value()?;
+                    empty = Some(value.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    krate = Some(crate::utils::parse_crate(&meta)?);
+                    Ok(())
                 } else {
                     Err(meta.error("unknown attribute"))
                 }
@@ -55,31 +90,55 @@ fn get_expected(input: &DeriveInput) -> Result<Ident> {
         }
     }
 
-    Ok(expected.unwrap_or_else(|| Ident::new("OK", Span::call_site())))
+    Ok(Attrs {
+        expected: expected.unwrap_or_else(|| Ident::new("OK", Span::call_site())),
+        empty,
+        krate,
+    })
 }
 
-#[derive(Copy, Clone)]
+fn derive_struct_response(
+    input: &DeriveInput,
+    expected: &Ident,
+    empty: Option<&Ident>,
+) -> Result<DeriveResponse> {
+    let pagination = check_pagination(input)?;
+
+    match pagination {
+        Some(pagination) => {
+            if let Some(empty) = &empty {
+                return Err(Error::new(
+                    empty.span(),
+                    "`empty` is not supported on paginated responses",
+                ));
+            }
+            derive_pagination(input, &pagination, expected)
+        }
+        None => derive_normal(input, expected, empty),
+    }
+}
+
+#[derive(Clone)]
 pub struct Pagination<'a> {
     /// The field containing the `PaginationResponse`
     pub field: &'a Field,
+    /// How to access the field, by name or by index for tuple structs
+    pub member: Member,
 }
 
-pub fn check_pagination(input: &DeriveInput) -> Result<Option<Pagination>> {
+pub fn check_pagination(input: &DeriveInput) -> Result<Option<Pagination<'_>>> {
     let syn::Data::Struct(data) = &input.data else {
         return Err(Error::new(input.ident.span(), "Must be a struct"));
     };
-    let Fields::Named(fields) = &data.fields else {
-        return Ok(None);
-    };
 
     let mut ret = None;
 
-    for field in &fields.named {
+    for (i, field) in data.fields.iter().enumerate() {
         for attr in &field.attrs {
             if attr.path().is_ident("trakt") {
                 attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("pagination") {
-                        ret = Some(field);
+                        ret = Some((i, field));
                         Ok(())
                     } else {
                         Err(meta.error("unknown attribute"))
@@ -89,7 +148,15 @@ pub fn check_pagination(input: &DeriveInput) -> Result<Option<Pagination>> {
         }
     }
 
-    Ok(ret.map(|field| Pagination { field }))
+    let Some((i, field)) = ret else {
+        return Ok(None);
+    };
+
+    let member = field
+        .ident
+        .clone()
+        .map_or_else(|| Member::Unnamed(Index::from(i)), Member::Named);
+    Ok(Some(Pagination { field, member }))
 }
 
 #[derive(Debug)]
@@ -100,16 +167,28 @@ struct DeriveResponse {
 
 fn derive_pagination(
     input: &DeriveInput,
-    pagination: Pagination,
+    pagination: &Pagination,
     expected: &Ident,
 ) -> Result<DeriveResponse> {
-    let Pagination { field } = pagination;
-    let ident = field.ident.as_ref().unwrap();
+    let Pagination { field, .. } = pagination;
+
+    if let syn::Data::Struct(data) = &input.data {
+        if data.fields.len() != 1 {
+            return Err(Error::new(
+                data.fields.span(),
+                "Paginated responses must have exactly one field",
+            ));
+        }
+    }
+    let construct = field.ident.as_ref().map_or_else(
+        || quote! { Self(items) },
+        |ident| quote! { Self { #ident: items } },
+    );
 
     let body = quote! {
-        let body = _trakt_core::handle_response_body(&response, _http::StatusCode::#expected)?;
-        let #ident = _trakt_core::PaginationResponse::from_headers(body, response.headers())?;
-        Ok(Self { #ident })
+        let (body, ctx) = _trakt_core::handle_response(&response, _http::StatusCode::#expected)?;
+        let items = ctx.paginated(body)?;
+        ::core::result::Result::Ok(#construct)
     };
 
     let extra = crate::paginated::derive_paginated::<false>(input)?;
@@ -117,13 +196,20 @@ fn derive_pagination(
     Ok(DeriveResponse { body, extra })
 }
 
-fn derive_normal(input: &DeriveInput, expected: &Ident) -> Result<DeriveResponse> {
+fn derive_normal(
+    input: &DeriveInput,
+    expected: &Ident,
+    empty: Option<&Ident>,
+) -> Result<DeriveResponse> {
     let syn::Data::Struct(data) = &input.data else {
         return Err(Error::new(input.ident.span(), "Must be a struct"));
     };
 
-    Ok(match &data.fields {
-        Fields::Named(_) => derive_struct(expected),
+    let (mut res, default) = match &data.fields {
+        Fields::Named(_) => (
+            derive_struct(expected),
+            quote! { ::core::default::Default::default() },
+        ),
         Fields::Unnamed(fields) => {
             if fields.unnamed.len() != 1 {
                 return Err(Error::new(
@@ -131,15 +217,79 @@ fn derive_normal(input: &DeriveInput, expected: &Ident) -> Result<DeriveResponse
                     "Expected exactly one field",
                 ));
             }
-            derive_newtype(expected)
+            (
+                derive_newtype(expected),
+                quote! { Self(::core::default::Default::default()) },
+            )
         }
-        Fields::Unit => derive_unit(expected),
+        Fields::Unit => (derive_unit(expected), quote! { Self }),
+    };
+
+    if let Some(empty) = empty {
+        let body = res.body;
+        res.body = quote! {
+            if response.status() == _http::StatusCode::#empty {
+                return ::core::result::Result::Ok(#default);
+            }
+            #body
+        };
+    }
+
+    Ok(res)
+}
+
+/// Derives responses with multiple shapes, e.g. depending on the query params.
+///
+/// Each variant holds a single shape, and the body is deserialized into the
+/// first variant that it matches, like `#[serde(untagged)]`.
+fn derive_enum(data: &syn::DataEnum, expected: &Ident) -> Result<DeriveResponse> {
+    if data.variants.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "enum responses must have at least one variant",
+        ));
+    }
+
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut types = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let Fields::Unnamed(fields) = &variant.fields else {
+            return Err(Error::new(
+                variant.span(),
+                "enum response variants must have exactly one unnamed field",
+            ));
+        };
+        if fields.unnamed.len() != 1 {
+            return Err(Error::new(
+                fields.unnamed.span(),
+                "enum response variants must have exactly one unnamed field",
+            ));
+        }
+        variants.push(&variant.ident);
+        types.push(&fields.unnamed[0].ty);
+    }
+
+    let body = quote! {
+        #[derive(_serde::Deserialize)]
+        #[serde(crate = "_serde", untagged)]
+        enum Body {
+            #(#variants(#types),)*
+        }
+
+        let body = _trakt_core::handle_response_body(&response, _http::StatusCode::#expected)?;
+        ::core::result::Result::Ok(match body {
+            #(Body::#variants(value) => Self::#variants(value),)*
+        })
+    };
+    Ok(DeriveResponse {
+        body,
+        extra: TokenStream::default(),
     })
 }
 
 fn derive_newtype(expected: &Ident) -> DeriveResponse {
     let body = quote! {
-        Ok(Self(_trakt_core::handle_response_body(&response, _http::StatusCode::#expected)?))
+        ::core::result::Result::Ok(Self(_trakt_core::handle_response_body(&response, _http::StatusCode::#expected)?))
     };
     DeriveResponse {
         body,
@@ -159,8 +309,8 @@ fn derive_struct(expected: &Ident) -> DeriveResponse {
 
 fn derive_unit(expected: &Ident) -> DeriveResponse {
     let body = quote! {
-        _trakt_core::handle_response_body(&response, http::StatusCode::#expected)?;
-        Ok(Self)
+        _trakt_core::handle_response_status(&response, _http::StatusCode::#expected)?;
+        ::core::result::Result::Ok(Self)
     };
     DeriveResponse {
         body,
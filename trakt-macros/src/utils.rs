@@ -0,0 +1,33 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{meta::ParseNestedMeta, LitStr, Path, Result};
+
+/// Parses the value of a `crate = "..."` attribute.
+pub fn parse_crate(meta: &ParseNestedMeta) -> Result<Path> {
+    let value: LitStr = meta.value()?.parse()?;
+    value.parse()
+}
+
+/// Imports `trakt_core` and the crates re-exported by it into the current
+/// scope.
+///
+/// `trakt_core` is imported from `krate` if given (e.g. `#[trakt(crate =
+/// "trakt_rs::trakt_core")]`), so that the generated code doesn't require
+/// dependents to depend on `trakt_core`, `http`, `bytes`, or `serde` directly.
+pub fn import_core(krate: Option<&Path>) -> TokenStream {
+    let core = krate.map_or_else(
+        || {
+            quote! {
+                #[allow(unused_extern_crates, clippy::useless_attribute)]
+                extern crate trakt_core as _trakt_core;
+            }
+        },
+        |krate| quote! { use #krate as _trakt_core; },
+    );
+
+    quote! {
+        #core
+        #[allow(unused_imports)]
+        use _trakt_core::__private::{bytes as _bytes, http as _http, serde as _serde};
+    }
+}
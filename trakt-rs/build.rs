@@ -0,0 +1,66 @@
+//! Generates the list of endpoints executed by `trakt-cli` from the `api`
+//! module, so that new endpoints are picked up without listing them by hand.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "cli")]
+    cli::write_endpoints();
+}
+
+#[cfg(feature = "cli")]
+mod cli {
+    use std::{env, fmt::Write, fs, path::Path};
+
+    use syn::{File, Item, Visibility};
+
+    /// Writes the `METADATA` of every `Request` of the `api` module to
+    /// `$OUT_DIR/endpoints.rs`, as a slice in the order they are declared in.
+    pub fn write_endpoints() {
+        let src = Path::new(&env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("src");
+        println!("cargo:rerun-if-changed={}", src.join("api.rs").display());
+        println!("cargo:rerun-if-changed={}", src.join("api").display());
+
+        let mut endpoints = String::new();
+        for item in parse(&src.join("api.rs")).items {
+            // Modules of `api` are declared as `pub mod name;` in their own file
+            if let Item::Mod(module) = item {
+                if module.content.is_none() && matches!(module.vis, Visibility::Public(_)) {
+                    let name = module.ident.to_string();
+                    let file = parse(&src.join("api").join(format!("{name}.rs")));
+                    visit(&file.items, &format!("api::{name}"), &mut endpoints);
+                }
+            }
+        }
+
+        let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("endpoints.rs");
+        fs::write(out, format!("&[\n{endpoints}]\n")).unwrap();
+    }
+
+    fn parse(path: &Path) -> File {
+        let content = fs::read_to_string(path).unwrap();
+        syn::parse_file(&content).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+    }
+
+    /// Adds the public, non-generic `Request` structs in `items` and their
+    /// public submodules.
+    fn visit(items: &[Item], path: &str, endpoints: &mut String) {
+        for item in items {
+            match item {
+                Item::Struct(s)
+                    if s.ident == "Request"
+                        && matches!(s.vis, Visibility::Public(_))
+                        && s.generics.params.is_empty() =>
+                {
+                    writeln!(endpoints, "    {path}::Request::METADATA,").unwrap();
+                }
+                Item::Mod(module) if matches!(module.vis, Visibility::Public(_)) => {
+                    if let Some((_, items)) = &module.content {
+                        visit(items, &format!("{path}::{}", module.ident), endpoints);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
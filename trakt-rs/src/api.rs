@@ -3,17 +3,50 @@
 //! This module contains all the endpoints for the Trakt.tv API.
 //!
 //! Modules are organized by the API endpoint & category they represent.
+//! Each module can be compiled out by disabling its cargo feature, which has the same name as the
+//! module. All of them are enabled by default through the `all-apis` feature.
 //!
 //! API documentation: [https://trakt.docs.apiary.io/](https://trakt.docs.apiary.io/)
 
+#[cfg(feature = "auth")]
 pub mod auth;
+#[cfg(feature = "calendars")]
 pub mod calendars;
+#[cfg(feature = "certifications")]
 pub mod certifications;
+#[cfg(feature = "checkin")]
 pub mod checkin;
+#[cfg(feature = "comments")]
 pub mod comments;
+#[cfg(feature = "country")]
 pub mod country;
+#[cfg(feature = "episodes")]
+pub mod episodes;
+#[cfg(feature = "genres")]
 pub mod genres;
+#[cfg(feature = "languages")]
+pub mod languages;
+#[cfg(feature = "lists")]
+pub mod lists;
+#[cfg(feature = "movies")]
 pub mod movies;
+#[cfg(feature = "networks")]
+pub mod networks;
+#[cfg(feature = "notes")]
+pub mod notes;
+#[cfg(feature = "people")]
+pub mod people;
+#[cfg(feature = "recommendations")]
+pub mod recommendations;
+#[cfg(feature = "scrobble")]
 pub mod scrobble;
+#[cfg(feature = "search")]
 pub mod search;
+#[cfg(feature = "seasons")]
+pub mod seasons;
+#[cfg(feature = "shows")]
 pub mod shows;
+#[cfg(feature = "sync")]
+pub mod sync;
+#[cfg(feature = "users")]
+pub mod users;
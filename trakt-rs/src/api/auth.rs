@@ -20,11 +20,12 @@ pub mod token {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/oauth/token",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::None,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/oauth/token",
+            http::Method::POST,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -74,11 +75,12 @@ pub mod exchange {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/oauth/token",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::None,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/oauth/token",
+            http::Method::POST,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -127,11 +129,12 @@ pub mod revoke {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/oauth/revoke",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::None,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/oauth/revoke",
+            http::Method::POST,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -168,11 +171,12 @@ pub mod device_code {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/oauth/device/code",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::None,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/oauth/device/code",
+            http::Method::POST,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -216,11 +220,12 @@ pub mod poll_token {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/oauth/device/token",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::None,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/oauth/device/token",
+            http::Method::POST,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -2,6 +2,23 @@
 //!
 //! <https://trakt.docs.apiary.io/#reference/calendars>
 
+/// Implements `Request::next_days` for a calendar request.
+macro_rules! next_days {
+    () => {
+        #[cfg(feature = "clock")]
+        impl Request {
+            /// Requests `days` days of the calendar, starting today (UTC).
+            #[must_use]
+            pub fn next_days(days: u64) -> Self {
+                Self {
+                    start_date: time::OffsetDateTime::now_utc().date(),
+                    days,
+                }
+            }
+        }
+    };
+}
+
 pub mod my {
     //! My calendars
 
@@ -18,6 +35,10 @@ pub mod my {
         #[trakt(
         response = Response,
         endpoint = "/calendars/my/shows/{start_date}/{days}",
+        example(
+            request = "Request { start_date: time::macros::date!(2014-09-01), days: 7 }",
+            url = "https://api.trakt.tv/calendars/my/shows/2014-09-01/7",
+        ),
         auth = Required
         )]
         pub struct Request {
@@ -26,6 +47,8 @@ pub mod my {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<EpisodeAirEvent>);
     }
@@ -51,6 +74,8 @@ pub mod my {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<EpisodeAirEvent>);
     }
@@ -76,6 +101,8 @@ pub mod my {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<EpisodeAirEvent>);
     }
@@ -101,6 +128,8 @@ pub mod my {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<EpisodeAirEvent>);
     }
@@ -118,6 +147,10 @@ pub mod my {
         #[trakt(
         response = Response,
         endpoint = "/calendars/my/movies/{start_date}/{days}",
+        example(
+            request = "Request { start_date: time::macros::date!(2014-09-01), days: 7 }",
+            url = "https://api.trakt.tv/calendars/my/movies/2014-09-01/7",
+        ),
         auth = Required
         )]
         pub struct Request {
@@ -126,6 +159,8 @@ pub mod my {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<MovieReleaseEvent>);
     }
@@ -151,6 +186,8 @@ pub mod my {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<MovieReleaseEvent>);
     }
@@ -180,6 +217,8 @@ pub mod all {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<EpisodeAirEvent>);
     }
@@ -197,6 +236,10 @@ pub mod all {
         #[trakt(
         response = Response,
         endpoint = "/calendars/all/shows/premieres/{start_date}/{days}",
+        example(
+            request = "Request { start_date: time::macros::date!(2014-09-01), days: 30 }",
+            url = "https://api.trakt.tv/calendars/all/shows/premieres/2014-09-01/30",
+        ),
         auth = Required
         )]
         pub struct Request {
@@ -205,6 +248,8 @@ pub mod all {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<EpisodeAirEvent>);
     }
@@ -230,6 +275,8 @@ pub mod all {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<EpisodeAirEvent>);
     }
@@ -255,6 +302,8 @@ pub mod all {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<MovieReleaseEvent>);
     }
@@ -272,6 +321,10 @@ pub mod all {
         #[trakt(
         response = Response,
         endpoint = "/calendars/all/dvd/{start_date}/{days}",
+        example(
+            request = "Request { start_date: time::macros::date!(2014-09-01), days: 7 }",
+            url = "https://api.trakt.tv/calendars/all/dvd/2014-09-01/7",
+        ),
         auth = Required
         )]
         pub struct Request {
@@ -280,7 +333,29 @@ pub mod all {
             pub days: u64,
         }
 
+        next_days!();
+
         #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
         pub struct Response(pub Vec<MovieReleaseEvent>);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "clock")]
+    #[test]
+    fn next_days() {
+        use time::OffsetDateTime;
+
+        use super::*;
+
+        let today = OffsetDateTime::now_utc().date();
+        let req = my::shows::Request::next_days(7);
+        assert_eq!(req.days, 7);
+        assert!(req.start_date >= today && req.start_date <= today.next_day().unwrap());
+
+        let req = all::dvd_releases::Request::next_days(30);
+        assert_eq!(req.days, 30);
+        assert!(req.start_date >= today && req.start_date <= today.next_day().unwrap());
+    }
+}
@@ -9,18 +9,21 @@ pub mod list {
 
     use std::collections::HashMap;
 
-    use compact_str::CompactString;
     use serde::Serialize;
 
-    use crate::smo::Country;
+    use crate::smo::{Country, CountryTagged, SmallString};
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/certifications/{tp}",
+    example(
+        request = "Request { tp: Type::Movies }",
+        url = "https://api.trakt.tv/certifications/movies",
+    ),
     )]
     pub struct Request {
-        tp: Type,
+        pub tp: Type,
     }
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
@@ -30,13 +33,77 @@ pub mod list {
         Shows,
     }
 
+    /// Certifications of each country.
     #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, trakt_macros::Response)]
-    pub struct Response(pub HashMap<Country, Certification>);
+    pub struct Response(pub HashMap<Country, Vec<CertificationInfo>>);
 
+    impl Response {
+        /// Returns the certifications of a country, empty if the country has none.
+        #[must_use]
+        pub fn get(&self, country: Country) -> &[CertificationInfo] {
+            self.0.get(&country).map_or(&[], Vec::as_slice)
+        }
+
+        /// Returns the certifications of all countries, tagged with their country.
+        pub fn iter(&self) -> impl Iterator<Item = CountryTagged<&CertificationInfo>> {
+            self.0.iter().flat_map(|(country, certifications)| {
+                certifications
+                    .iter()
+                    .map(|certification| CountryTagged::new(certification, *country))
+            })
+        }
+    }
+
+    /// A certification, as returned by the certifications listing.
+    ///
+    /// The `slug` matches [`smo::Certification::certification`](crate::smo::Certification) of
+    /// movies and shows.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
-    pub struct Certification {
-        pub name: CompactString,
-        pub slug: CompactString,
-        pub description: CompactString,
+    pub struct CertificationInfo {
+        pub name: SmallString,
+        pub slug: SmallString,
+        pub description: SmallString,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{Context, Response as _};
+
+    use super::*;
+    use crate::{smo::Country, test::assert_request};
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_list() {
+        let req = list::Request {
+            tp: list::Type::Movies,
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/certifications/movies", "");
+
+        let body = json!({
+            "us": [
+                { "name": "G", "slug": "g", "description": "All Ages" },
+                { "name": "PG", "slug": "pg", "description": "Parental Guidance Suggested" }
+            ]
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = list::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.get(Country::new("us")).len(), 2);
+        assert!(response.get(Country::new("gb")).is_empty());
+
+        let tagged = response.iter().collect::<Vec<_>>();
+        assert_eq!(tagged.len(), 2);
+        assert_eq!(tagged[1].country, Some(Country::new("us")));
+        assert_eq!(tagged[1].slug, "pg");
     }
 }
@@ -10,17 +10,16 @@ pub mod checkin {
 
     use bytes::BufMut;
     use serde::Deserialize;
-    use serde_json::{json, Value};
+    use serde_json::json;
     use time::OffsetDateTime;
     use trakt_core::{error::IntoHttpError, AuthRequirement, Context, Metadata};
 
-    use crate::smo::{Episode, Id, Ids, Movie, Sharing, Show};
+    use crate::smo::{Episode, Id, Ids, Movie, PlaybackBody, Sharing, Show};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash)]
     pub struct Request<I: CheckinItem> {
         pub id: Id,
-        pub sharing: Option<Sharing>,
-        pub message: Option<String>,
+        pub extra: PlaybackBody,
         _phantom: std::marker::PhantomData<I>,
     }
 
@@ -30,8 +29,7 @@ pub mod checkin {
         pub const fn new(id: Id) -> Self {
             Self {
                 id,
-                sharing: None,
-                message: None,
+                extra: PlaybackBody::new(),
                 _phantom: std::marker::PhantomData,
             }
         }
@@ -55,11 +53,9 @@ pub mod checkin {
 
     impl<I: Clone + CheckinItem> trakt_core::Request for Request<I> {
         type Response = I::Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/checkin",
-            method: http::Method::POST,
-            auth: AuthRequirement::Required,
-        };
+        const METADATA: Metadata =
+            Metadata::new("/checkin", http::Method::POST, AuthRequirement::Required)
+                .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -68,17 +64,8 @@ pub mod checkin {
             let body = T::default();
             let mut writer = body.writer();
 
-            let json = Value::Object({
-                let mut map = serde_json::Map::new();
-                map.insert(I::KEY.to_owned(), json!({ "ids": Ids::from(self.id) }));
-                if let Some(sharing) = self.sharing {
-                    map.insert("sharing".to_owned(), json!(sharing));
-                }
-                if let Some(message) = self.message {
-                    map.insert("message".to_owned(), json!(message));
-                }
-                map
-            });
+            let mut json = serde_json::to_value(&self.extra)?;
+            json[I::KEY] = json!({ "ids": Ids::from(self.id) });
 
             serde_json::to_writer(&mut writer, &json)?;
 
@@ -118,7 +105,7 @@ pub mod checkin {
     #[trakt(expected = CREATED)]
     pub struct MovieResponse {
         pub id: u64,
-        #[serde(with = "time::serde::iso8601")]
+        #[serde(with = "crate::datetime")]
         pub watched_at: OffsetDateTime,
         pub sharing: Option<Sharing>,
         pub episode: Episode,
@@ -129,7 +116,7 @@ pub mod checkin {
     #[trakt(expected = CREATED)]
     pub struct EpisodeResponse {
         pub id: u64,
-        #[serde(with = "time::serde::iso8601")]
+        #[serde(with = "crate::datetime")]
         pub watched_at: OffsetDateTime,
         pub sharing: Option<Sharing>,
         pub episode: Episode,
@@ -142,10 +129,16 @@ pub mod delete {
     //!
     //! <https://trakt.docs.apiary.io/#reference/checkin/checkin/delete-any-active-checkins>
 
-    #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[derive(
+        Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default, trakt_macros::Request,
+    )]
     #[trakt(
     response = Response,
     endpoint = "/checkin",
+    example(
+        request = "Request",
+        url = "https://api.trakt.tv/checkin",
+    ),
     method = DELETE,
     auth = Required
     )]
@@ -192,15 +185,17 @@ mod tests {
                 "tumblr": true,
             },
             "message": "Hello, world!",
+            "app_version": "1.0",
         }))
         .unwrap();
         let mut request = checkin::Request::new_movie(Id::Trakt(1));
-        request.sharing = Some(Sharing {
+        request.extra.sharing = Some(Sharing {
             twitter: true,
             mastodon: false,
             tumblr: true,
         });
-        request.message = Some("Hello, world!".into());
+        request.extra.message = Some("Hello, world!".into());
+        request.extra.app_version = Some("1.0".into());
         assert_request(CTX, request, "https://api.trakt.tv/checkin", &expected);
     }
 
@@ -2,6 +2,170 @@
 //!
 //! <https://trakt.docs.apiary.io/#reference/comments>
 
+use serde::Serialize;
+use trakt_core::{error::IntoHttpError, Pagination};
+
+use crate::smo::{CommentItemType, CommentType, Extended};
+
+const SPOILER_OPEN: &str = "[spoiler]";
+const SPOILER_CLOSE: &str = "[/spoiler]";
+
+/// Returns whether `comment` wraps any text in `[spoiler]` tags, ignoring case.
+///
+/// Trakt marks such comments as spoilers itself, so
+/// [`post::Request::detect_spoiler`], [`update::Request::detect_spoiler`], and
+/// [`post_reply::Request::detect_spoiler`] use this to set `spoiler` up front.
+#[must_use]
+pub fn has_spoiler_tag(comment: &str) -> bool {
+    let lower = comment.to_ascii_lowercase();
+    lower
+        .find(SPOILER_OPEN)
+        .is_some_and(|start| lower[start..].contains(SPOILER_CLOSE))
+}
+
+/// Strips the markup of a comment, leaving the text as it's displayed.
+///
+/// Removes `[spoiler]` tags, heading and quote markers, `*`, `~`, and `` ` `` emphasis, and
+/// replaces `[text](url)` links with their text.
+#[must_use]
+pub fn plain_text(comment: &str) -> String {
+    let mut out = String::with_capacity(comment.len());
+    for (i, line) in comment.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let marker = line.trim_start_matches(['#', '>']);
+        let line = if marker.len() < line.len() && marker.starts_with(char::is_whitespace) {
+            marker.trim_start()
+        } else {
+            line
+        };
+        push_without_links(&mut out, &remove_spoiler_tags(line));
+    }
+    out.retain(|c| !matches!(c, '*' | '~' | '`'));
+    out
+}
+
+fn remove_spoiler_tags(text: &str) -> String {
+    // ASCII lowercasing keeps byte offsets the same as in `text`
+    let lower = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+    while let Some((start, len)) = [SPOILER_OPEN, SPOILER_CLOSE]
+        .into_iter()
+        .filter_map(|tag| lower[pos..].find(tag).map(|i| (pos + i, tag.len())))
+        .min()
+    {
+        out.push_str(&text[pos..start]);
+        pos = start + len;
+    }
+    out.push_str(&text[pos..]);
+    out
+}
+
+fn push_without_links(out: &mut String, mut text: &str) {
+    while let Some(open) = text.find('[') {
+        out.push_str(&text[..open]);
+        let after = &text[open + 1..];
+        if let Some((label, tail)) = after.split_once("](") {
+            if let Some(close) = tail.find(')') {
+                if !label.contains(['[', ']']) {
+                    out.push_str(label);
+                    text = &tail[close + 1..];
+                    continue;
+                }
+            }
+        }
+        out.push('[');
+        text = after;
+    }
+    out.push_str(text);
+}
+
+/// Path parameters of the [`trending`], [`recent`], and [`recent_updated`] listings.
+///
+/// Trakt reads the first path segment as the comment type, so it's filled in with
+/// [`CommentType::All`] when only the item type is given.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+struct ListingPath {
+    comment_type: Option<CommentType>,
+    tp: Option<CommentItemType>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+struct ListingQuery {
+    include_replies: bool,
+    extended: Extended,
+    #[serde(flatten)]
+    pagination: Pagination,
+}
+
+fn listing_params(
+    comment_type: Option<CommentType>,
+    tp: Option<CommentItemType>,
+    include_replies: bool,
+    extended: Extended,
+    pagination: Pagination,
+) -> Result<(ListingPath, ListingQuery), IntoHttpError> {
+    pagination.validate()?;
+    Ok((
+        ListingPath {
+            comment_type: comment_type.or_else(|| tp.map(|_| CommentType::All)),
+            tp,
+        },
+        ListingQuery {
+            include_replies,
+            extended,
+            pagination,
+        },
+    ))
+}
+
+/// Implements [`trakt_core::Request`] for the `Request` of a comment listing at `$endpoint`,
+/// which has the fields of the [`trending::Request`].
+macro_rules! listing_request {
+    ($endpoint:literal) => {
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: trakt_core::Metadata = trakt_core::Metadata::new(
+                $endpoint,
+                http::Method::GET,
+                trakt_core::AuthRequirement::None,
+            )
+            .with_name(trakt_core::endpoint_name!())
+            .with_optional_params(&["comment_type", "tp"]);
+
+            fn try_into_http_request<T: Default + bytes::BufMut>(
+                self,
+                ctx: trakt_core::Context,
+            ) -> Result<http::Request<T>, trakt_core::error::IntoHttpError> {
+                let (path, query) = super::listing_params(
+                    self.comment_type,
+                    self.tp,
+                    self.include_replies,
+                    self.extended,
+                    self.pagination,
+                )?;
+                trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
+            }
+
+            fn uri(
+                &self,
+                ctx: trakt_core::Context,
+            ) -> Result<http::Uri, trakt_core::error::IntoHttpError> {
+                let (path, query) = super::listing_params(
+                    self.comment_type,
+                    self.tp,
+                    self.include_replies,
+                    self.extended,
+                    self.pagination,
+                )?;
+                trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+            }
+        }
+    };
+}
+
 pub mod post {
     //! Post a comments
     //!
@@ -23,6 +187,17 @@ pub mod post {
         pub sharing: Option<Sharing>,
     }
 
+    impl Request {
+        /// Sets `spoiler` if the comment contains `[spoiler]` tags, as Trakt does.
+        ///
+        /// See [`has_spoiler_tag`](super::has_spoiler_tag).
+        #[must_use]
+        pub fn detect_spoiler(mut self) -> Self {
+            self.spoiler |= super::has_spoiler_tag(&self.comment);
+            self
+        }
+    }
+
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
     pub enum Type {
         Movie,
@@ -34,18 +209,19 @@ pub mod post {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/comments",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::Required,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/comments",
+            http::Method::POST,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
             ctx: Context,
         ) -> Result<http::Request<T>, IntoHttpError> {
-            // Check that comments have at least 5 words
-            if self.comment.unicode_words().count() < 5 {
+            // Check that comments have at least 5 words, not counting markup
+            if super::plain_text(&self.comment).unicode_words().count() < 5 {
                 return Err(IntoHttpError::Validation(
                     "Comments must be at least 5 words long".to_owned(),
                 ));
@@ -94,6 +270,10 @@ pub mod get {
     #[trakt(
     response = Response,
     endpoint = "/comments/{id}",
+    example(
+        request = "Request { id: 417 }",
+        url = "https://api.trakt.tv/comments/417",
+    ),
     )]
     pub struct Request {
         pub id: u64,
@@ -122,6 +302,17 @@ pub mod update {
         pub spoiler: bool,
     }
 
+    impl Request {
+        /// Sets `spoiler` if the comment contains `[spoiler]` tags, as Trakt does.
+        ///
+        /// See [`has_spoiler_tag`](super::has_spoiler_tag).
+        #[must_use]
+        pub fn detect_spoiler(mut self) -> Self {
+            self.spoiler |= super::has_spoiler_tag(&self.comment);
+            self
+        }
+    }
+
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
     struct RequestParams {
         id: u64,
@@ -129,11 +320,12 @@ pub mod update {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/comments/{id}",
-            method: http::Method::PUT,
-            auth: trakt_core::AuthRequirement::Required,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/comments/{id}",
+            http::Method::PUT,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -183,20 +375,28 @@ pub mod get_replies {
     //!
     //! <https://trakt.docs.apiary.io/#reference/comments/comment/get-replies-for-a-comment>
 
+    use trakt_core::{Pagination, PaginationResponse};
+
     use crate::smo::Comment;
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/comments/{id}/replies",
+    example(
+        request = "Request { id: 417, pagination: Default::default() }",
+        url = "https://api.trakt.tv/comments/417/replies?page=1&limit=10",
+    ),
     auth = Optional,
     )]
     pub struct Request {
         pub id: u64,
+        #[serde(flatten)]
+        pub pagination: Pagination,
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Vec<Comment>);
+    pub struct Response(#[trakt(pagination)] pub PaginationResponse<Comment>);
 }
 
 pub mod post_reply {
@@ -218,6 +418,17 @@ pub mod post_reply {
         pub spoiler: bool,
     }
 
+    impl Request {
+        /// Sets `spoiler` if the comment contains `[spoiler]` tags, as Trakt does.
+        ///
+        /// See [`has_spoiler_tag`](super::has_spoiler_tag).
+        #[must_use]
+        pub fn detect_spoiler(mut self) -> Self {
+            self.spoiler |= super::has_spoiler_tag(&self.comment);
+            self
+        }
+    }
+
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
     struct RequestParams {
         id: u64,
@@ -225,11 +436,12 @@ pub mod post_reply {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/comments/{id}/replies",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::Required,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/comments/{id}/replies",
+            http::Method::POST,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -265,6 +477,10 @@ pub mod item {
     #[trakt(
     response = Response,
     endpoint = "/comments/{id}/item",
+    example(
+        request = "Request { id: 417 }",
+        url = "https://api.trakt.tv/comments/417/item",
+    ),
     )]
     pub struct Request {
         pub id: u64,
@@ -301,7 +517,7 @@ pub mod likes {
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
     pub struct ResponseItem {
-        #[serde(with = "time::serde::iso8601")]
+        #[serde(with = "crate::datetime")]
         pub liked_at: OffsetDateTime,
         pub user: User,
     }
@@ -354,21 +570,23 @@ pub mod trending {
     //!
     //! <https://trakt.docs.apiary.io/#reference/comments/like/get-trending-comments>
 
-    use trakt_core::PaginationResponse;
+    use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{CommentItemType, CommentType, CommentWithItem};
+    use crate::smo::{CommentItemType, CommentType, CommentWithItem, Extended};
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
-    #[trakt(
-    response = Response,
-    endpoint = "/comments/trending/{comment_type}/{tp}",
-    )]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
     pub struct Request {
-        pub comment_type: CommentType,
-        pub tp: CommentItemType,
+        /// Type of comments, all types if `None`.
+        pub comment_type: Option<CommentType>,
+        /// Type of commented items, all types if `None`.
+        pub tp: Option<CommentItemType>,
         pub include_replies: bool,
+        pub extended: Extended,
+        pub pagination: Pagination,
     }
 
+    listing_request!("/comments/trending/{comment_type}/{tp}");
+
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response {
         #[trakt(pagination)]
@@ -381,21 +599,23 @@ pub mod recent {
     //!
     //! <https://trakt.docs.apiary.io/#reference/comments/trending/get-recently-created-comments>
 
-    use trakt_core::PaginationResponse;
+    use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{CommentItemType, CommentType, CommentWithItem};
+    use crate::smo::{CommentItemType, CommentType, CommentWithItem, Extended};
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
-    #[trakt(
-    response = Response,
-    endpoint = "/comments/recent/{comment_type}/{tp}",
-    )]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
     pub struct Request {
-        pub comment_type: CommentType,
-        pub tp: CommentItemType,
+        /// Type of comments, all types if `None`.
+        pub comment_type: Option<CommentType>,
+        /// Type of commented items, all types if `None`.
+        pub tp: Option<CommentItemType>,
         pub include_replies: bool,
+        pub extended: Extended,
+        pub pagination: Pagination,
     }
 
+    listing_request!("/comments/recent/{comment_type}/{tp}");
+
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response {
         #[trakt(pagination)]
@@ -408,21 +628,23 @@ pub mod recent_updated {
     //!
     //! <https://trakt.docs.apiary.io/#reference/comments/updates/get-recently-updated-comments>
 
-    use trakt_core::PaginationResponse;
+    use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{CommentItemType, CommentType, CommentWithItem};
+    use crate::smo::{CommentItemType, CommentType, CommentWithItem, Extended};
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
-    #[trakt(
-    response = Response,
-    endpoint = "/comments/updates/{comment_type}/{tp}",
-    )]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
     pub struct Request {
-        pub comment_type: CommentType,
-        pub tp: CommentItemType,
+        /// Type of comments, all types if `None`.
+        pub comment_type: Option<CommentType>,
+        /// Type of commented items, all types if `None`.
+        pub tp: Option<CommentItemType>,
         pub include_replies: bool,
+        pub extended: Extended,
+        pub pagination: Pagination,
     }
 
+    listing_request!("/comments/updates/{comment_type}/{tp}");
+
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response {
         #[trakt(pagination)]
@@ -488,6 +710,65 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn spoiler_detection() {
+        assert!(has_spoiler_tag(
+            "Great ending. [spoiler]He was dead all along.[/spoiler]"
+        ));
+        assert!(has_spoiler_tag("[SPOILER]It was a dream[/Spoiler]"));
+        assert!(!has_spoiler_tag("No spoilers here, just a great movie."));
+        assert!(!has_spoiler_tag("[/spoiler] backwards [spoiler]"));
+
+        let request = post::Request {
+            tp: post::Type::Movie,
+            id: Trakt(1),
+            comment: "Great ending. [spoiler]He was dead all along.[/spoiler]".to_owned(),
+            spoiler: false,
+            sharing: None,
+        };
+        assert!(request.detect_spoiler().spoiler);
+
+        let request = update::Request {
+            id: 1,
+            comment: "Great ending, no spoilers here.".to_owned(),
+            spoiler: true,
+        };
+        // An explicit spoiler flag is kept
+        assert!(request.detect_spoiler().spoiler);
+
+        let request = post_reply::Request {
+            id: 1,
+            comment: "Agreed. [spoiler]The twist was obvious.[/spoiler]".to_owned(),
+            spoiler: false,
+        };
+        assert!(request.detect_spoiler().spoiler);
+    }
+
+    #[test]
+    fn comment_plain_text() {
+        assert_eq!(
+            plain_text("# Review\n> **Loved** it, [spoiler]the `end`[/SPOILER] ~~not~~ too"),
+            "Review\nLoved it, the end not too"
+        );
+        assert_eq!(
+            plain_text("See [the trailer](https://youtu.be/abc) and #hashtags [sic]"),
+            "See the trailer and #hashtags [sic]"
+        );
+
+        // Spoiler tags don't count towards the 5 words
+        let request = post::Request {
+            tp: post::Type::Movie,
+            id: Trakt(1),
+            comment: "[spoiler]Loved it[/spoiler] a lot".to_owned(),
+            spoiler: true,
+            sharing: None,
+        };
+        assert!(matches!(
+            request.try_into_http_request::<Vec<u8>>(CTX),
+            Err(trakt_core::error::IntoHttpError::Validation(_))
+        ));
+    }
+
     #[test]
     fn post_comment_request_sharing() {
         const COMMENT: &str = "The quick brown fox jumps over the lazy dog.";
@@ -504,7 +785,7 @@ mod tests {
         });
         let request = post::Request {
             tp: post::Type::Episode,
-            id: Slug("slug".into()),
+            id: Slug("slug".parse().unwrap()),
             comment: COMMENT.to_owned(),
             spoiler: false,
             sharing: Some(Sharing {
@@ -531,4 +812,110 @@ mod tests {
         };
         assert_request(CTX, request, "https://api.trakt.tv/comments/42", &expected);
     }
+
+    #[test]
+    fn get_replies() {
+        use trakt_core::{PaginatedResponse, Pagination, Response as _};
+
+        let request = get_replies::Request {
+            id: 8,
+            pagination: Pagination::new(2, 5),
+        };
+        assert_request(
+            CTX,
+            request,
+            "https://api.trakt.tv/comments/8/replies?page=2&limit=5",
+            "",
+        );
+
+        let body = json!([
+            {
+                "id": 19,
+                "parent_id": 8,
+                "created_at": "2011-03-25T22:35:17.000Z",
+                "updated_at": "2011-03-25T22:35:17.000Z",
+                "comment": "Season 2 has really picked up the action!",
+                "spoiler": false,
+                "review": false,
+                "replies": 0,
+                "likes": 0,
+                "user_stats": { "rating": 8, "play_count": 1, "completed_count": 1 },
+                "user": trakt_fixtures::json(trakt_fixtures::USER)
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .header("X-Pagination-Page", "2")
+            .header("X-Pagination-Limit", "1")
+            .header("X-Pagination-Page-Count", "3")
+            .header("X-Pagination-Item-Count", "3")
+            .body(body.to_string())
+            .unwrap();
+        let response = get_replies::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.items().len(), 1);
+        assert_eq!(response.0.items()[0].id, 19);
+        assert_eq!(response.next_page(), Some(Pagination::new(3, 1)));
+    }
+
+    #[test]
+    fn listing_requests() {
+        use trakt_core::Pagination;
+
+        use crate::smo::{CommentItemType, CommentType, Extended};
+
+        let ctx = Context {
+            oauth_token: None,
+            ..CTX
+        };
+
+        let request = trending::Request {
+            comment_type: Some(CommentType::Reviews),
+            tp: Some(CommentItemType::Movies),
+            include_replies: true,
+            extended: Extended::FULL,
+            pagination: Pagination::new(1, 20),
+        };
+        assert_request(
+            ctx,
+            request,
+            "https://api.trakt.tv/comments/trending/reviews/movies?include_replies=true&extended=full&page=1&limit=20",
+            "",
+        );
+
+        let request = recent::Request {
+            comment_type: None,
+            tp: Some(CommentItemType::Shows),
+            include_replies: false,
+            extended: Extended::empty(),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            ctx,
+            request,
+            "https://api.trakt.tv/comments/recent/all/shows?include_replies=false&page=1&limit=10",
+            "",
+        );
+
+        let request = recent_updated::Request {
+            comment_type: Some(CommentType::Shouts),
+            tp: None,
+            include_replies: false,
+            extended: Extended::empty(),
+            pagination: Pagination::new(3, 5),
+        };
+        assert_request(
+            ctx,
+            request,
+            "https://api.trakt.tv/comments/updates/shouts?include_replies=false&page=3&limit=5",
+            "",
+        );
+
+        let request = trending::Request::default();
+        assert_request(
+            ctx,
+            request,
+            "https://api.trakt.tv/comments/trending?include_replies=false&page=1&limit=10",
+            "",
+        );
+    }
 }
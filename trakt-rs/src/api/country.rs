@@ -7,18 +7,21 @@ pub mod list {
     //!
     //! <https://trakt.docs.apiary.io/#reference/countries/list/get-countries>
 
-    use compact_str::CompactString;
     use serde::{Deserialize, Serialize};
 
-    use crate::smo::Country;
+    use crate::smo::{Country, SmallString};
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/countries/{tp}",
+    example(
+        request = "Request { tp: Type::Shows }",
+        url = "https://api.trakt.tv/countries/shows",
+    ),
     )]
     pub struct Request {
-        tp: Type,
+        pub tp: Type,
     }
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
@@ -29,11 +32,11 @@ pub mod list {
     }
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash, trakt_macros::Response)]
-    pub struct Response(Vec<ResponseItem>);
+    pub struct Response(pub Vec<ResponseItem>);
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
     pub struct ResponseItem {
-        pub name: CompactString,
+        pub name: SmallString,
         pub code: Country,
     }
 }
@@ -0,0 +1,401 @@
+//! Episodes
+//!
+//! Endpoints of a single episode, identified by the id of its show and its season and episode
+//! numbers.
+//!
+//! <https://trakt.docs.apiary.io/#reference/episodes>
+
+pub mod summary {
+    //! Get a single episode for a show
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/summary/get-a-single-episode-for-a-show>
+
+    use crate::smo::{Episode, Extended, Id};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390), season: 1, episode: 1, extended: trakt_rs::smo::Extended::FULL }",
+        url = "https://api.trakt.tv/shows/1390/seasons/1/episodes/1?extended=full",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Episode);
+}
+
+pub mod translations {
+    //! Get all episode translations
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/translations/get-all-episode-translations>
+
+    pub use crate::api::shows::translation::{Response, ResponseItem};
+    use crate::smo::{Id, Language};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}/translations/{language}",
+    example(
+        request = r#"Request { id: trakt_rs::smo::Id::Trakt(1390), season: 1, episode: 1, language: trakt_rs::smo::Language::new("es") }"#,
+        url = "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/translations/es",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+        pub language: Language,
+    }
+}
+
+pub mod comments {
+    //! Get all top level comments for an episode
+    //!
+    //! If oauth is provided, comments from blocked users will be filtered out.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/comments/get-all-episode-comments>
+
+    use trakt_core::Pagination;
+
+    pub use crate::api::shows::comments::Response;
+    use crate::smo::{Id, Sort};
+
+    /// Sort and filters left as `None` use the API's defaults.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}/comments/{sort}",
+    example(
+        request = "Request { sort: Some(trakt_rs::smo::Sort::Likes), ..Request::new(trakt_rs::smo::Id::Trakt(1390), 1, 1) }",
+        url = "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/comments/likes?page=1&limit=10",
+    ),
+    auth = Optional,
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+        /// Sort order, omitted from the path if `None`.
+        pub sort: Option<Sort>,
+        pub include_replies: Option<bool>,
+        pub spoiler: Option<bool>,
+        pub review: Option<bool>,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    impl Request {
+        /// Creates a request for the comments of an episode of the show `id`.
+        #[must_use]
+        pub fn new(id: Id, season: u16, episode: u16) -> Self {
+            Self {
+                id,
+                season,
+                episode,
+                sort: None,
+                include_replies: None,
+                spoiler: None,
+                review: None,
+                pagination: Pagination::default(),
+            }
+        }
+    }
+}
+
+pub mod lists {
+    //! Get lists containing this episode
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/lists/get-lists-containing-this-episode>
+
+    use bytes::BufMut;
+    use serde::Serialize;
+    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination};
+
+    pub use crate::api::shows::lists::{Response, Sort, Type};
+    use crate::{api::shows::lists::Segments, smo::Id};
+
+    /// A type or sort left as `None` uses the API's defaults, like [`shows::lists`]. A `sort`
+    /// given without a type is sent with [`Type::All`].
+    ///
+    /// [`shows::lists`]: crate::api::shows::lists
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+        pub tp: Option<Type>,
+        pub sort: Option<Sort>,
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct RequestPathParams {
+        id: Id,
+        season: u16,
+        episode: u16,
+        #[serde(flatten)]
+        segments: Segments,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/shows/{id}/seasons/{season}/episodes/{episode}/lists/{tp}/{sort}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!())
+        .with_optional_params(&["tp", "sort"]);
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            self.pagination.validate()?;
+            let path = RequestPathParams {
+                id: self.id,
+                season: self.season,
+                episode: self.episode,
+                segments: Segments::new(self.tp, self.sort),
+            };
+            trakt_core::construct_req(&ctx, &Self::METADATA, &path, &self.pagination, T::default())
+        }
+    }
+
+    impl Request {
+        /// Creates a request for all lists containing an episode of the show `id`.
+        #[must_use]
+        pub fn new(id: Id, season: u16, episode: u16) -> Self {
+            Self {
+                id,
+                season,
+                episode,
+                tp: None,
+                sort: None,
+                pagination: Pagination::default(),
+            }
+        }
+    }
+}
+
+pub mod people {
+    //! Get all people for an episode
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/people/get-all-people-for-an-episode>
+
+    pub use crate::api::seasons::people::{Character, Crew, CrewMember, Response};
+    use crate::smo::{Extended, Id};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}/people",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+        /// Use [`Extended::GUEST_STARS`] to include the guest stars.
+        pub extended: Extended,
+    }
+}
+
+pub mod ratings {
+    //! Get episode ratings
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/ratings/get-episode-ratings>
+
+    pub use crate::api::shows::ratings::Response;
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}/ratings",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390), season: 1, episode: 1 }",
+        url = "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/ratings",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+    }
+}
+
+pub mod stats {
+    //! Get episode stats
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/stats/get-episode-stats>
+
+    pub use crate::api::seasons::stats::Response;
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}/stats",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+    }
+}
+
+pub mod watching {
+    //! Get users watching an episode right now
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/episodes/watching/get-users-watching-right-now>
+
+    pub use crate::api::shows::watching::Response;
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/episodes/{episode}/watching",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub episode: u16,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{Context, Pagination, Request as _, Response as _};
+
+    use super::*;
+    use crate::{
+        smo::{Extended, Id, Language},
+        test::assert_request,
+    };
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_summary() {
+        let req = summary::Request {
+            id: Id::Slug("game-of-thrones".parse().unwrap()),
+            season: 1,
+            episode: 1,
+            extended: Extended::FULL,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/game-of-thrones/seasons/1/episodes/1?extended=full",
+            "",
+        );
+        assert_eq!(summary::Request::METADATA.name(), "episodes.summary");
+
+        let response = http::Response::builder()
+            .status(200)
+            .body(trakt_fixtures::EPISODE)
+            .unwrap();
+        let response = summary::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.title, "Pilot");
+        assert_eq!(response.0.ids.trakt, Some(16));
+    }
+
+    #[test]
+    fn test_children() {
+        let req = translations::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            episode: 1,
+            language: Language::new("es"),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/translations/es",
+            "",
+        );
+
+        let req = lists::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            episode: 1,
+            tp: Some(lists::Type::Personal),
+            sort: Some(lists::Sort::Added),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/lists/personal/added?page=1&limit=10",
+            "",
+        );
+
+        let mut req = lists::Request::new(Id::Trakt(1390), 1, 1);
+        req.sort = Some(lists::Sort::Likes);
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/lists/all/likes?page=1&limit=10",
+            "",
+        );
+
+        let req = people::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            episode: 1,
+            extended: Extended::empty(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/people",
+            "",
+        );
+
+        let req = stats::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            episode: 1,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/stats",
+            "",
+        );
+
+        let req = watching::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            episode: 1,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/1/watching",
+            "",
+        );
+
+        let response = http::Response::builder()
+            .status(200)
+            .body(json!([trakt_fixtures::json(trakt_fixtures::USER)]).to_string())
+            .unwrap();
+        let response = watching::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 1);
+    }
+}
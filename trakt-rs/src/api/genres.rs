@@ -7,13 +7,18 @@ pub mod list {
     //!
     //! <https://trakt.docs.apiary.io/#reference/genres/list/get-genres>
 
-    use compact_str::CompactString;
     use serde::{Deserialize, Serialize};
 
+    use crate::smo::SmallString;
+
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/genres/{tp}",
+    example(
+        request = "Request { tp: Type::Movies }",
+        url = "https://api.trakt.tv/genres/movies",
+    ),
     )]
     pub struct Request {
         pub tp: Type,
@@ -27,11 +32,11 @@ pub mod list {
     }
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash, trakt_macros::Response)]
-    pub struct Response(Vec<ResponseItem>);
+    pub struct Response(pub Vec<ResponseItem>);
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
     pub struct ResponseItem {
-        pub name: CompactString,
-        pub slug: CompactString,
+        pub name: SmallString,
+        pub slug: SmallString,
     }
 }
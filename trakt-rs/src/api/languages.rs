@@ -0,0 +1,78 @@
+//! Language related endpoints
+//!
+//! <https://trakt.docs.apiary.io/#reference/languages>
+
+pub mod list {
+    //! Get languages
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/languages/list/get-languages>
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::smo::{Language, SmallString};
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/languages/{tp}",
+    example(
+        request = "Request { tp: Type::Shows }",
+        url = "https://api.trakt.tv/languages/shows",
+    ),
+    )]
+    pub struct Request {
+        pub tp: Type,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Type {
+        Movies,
+        Shows,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<ResponseItem>);
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+    pub struct ResponseItem {
+        pub name: SmallString,
+        pub code: Language,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{Context, Response as _};
+
+    use super::*;
+    use crate::{smo::Language, test::assert_request};
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_list() {
+        let req = list::Request {
+            tp: list::Type::Movies,
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/languages/movies", "");
+
+        let body = json!([
+            { "name": "Arabic", "code": "ar" },
+            { "name": "English", "code": "en" }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = list::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 2);
+        assert_eq!(response.0[1].name, "English");
+        assert_eq!(response.0[1].code, Language::new("en"));
+    }
+}
@@ -0,0 +1,398 @@
+//! List endpoints
+//!
+//! <https://trakt.docs.apiary.io/#reference/lists>
+
+use serde::Serializer;
+
+bitflags::bitflags! {
+    /// Types of items to return from a list.
+    ///
+    /// An empty set returns items of all types.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+    pub struct ListItemType: u8 {
+        const MOVIE = 0b0000_0001;
+        const SHOW = 0b0000_0010;
+        const SEASON = 0b0000_0100;
+        const EPISODE = 0b0000_1000;
+        const PERSON = 0b0001_0000;
+    }
+}
+
+impl serde::Serialize for ListItemType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Serialize as a comma-separated list of the flag names, dropping unknown bits
+        let joined = self
+            .iter_names()
+            .map(|(name, _)| name.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        if joined.is_empty() {
+            serializer.serialize_none()
+        } else {
+            serializer.serialize_str(&joined)
+        }
+    }
+}
+
+pub mod trending {
+    //! Get trending lists
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/lists/trending/get-trending-lists>
+
+    use serde::Deserialize;
+    use trakt_core::{Pagination, PaginationResponse};
+
+    use crate::smo::List;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/lists/trending",
+    example(
+        request = "Request::default()",
+        url = "https://api.trakt.tv/lists/trending?page=1&limit=10",
+    ),
+    )]
+    pub struct Request {
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response {
+        #[trakt(pagination)]
+        pub items: PaginationResponse<ResponseItem>,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct ResponseItem {
+        pub like_count: u64,
+        pub comment_count: u64,
+        pub list: List,
+    }
+}
+
+pub mod popular {
+    //! Get popular lists
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/lists/popular/get-popular-lists>
+
+    use trakt_core::Pagination;
+
+    pub use super::trending::{Response, ResponseItem};
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/lists/popular",
+    )]
+    pub struct Request {
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+}
+
+pub mod summary {
+    //! Get a list
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/lists/list/get-list>
+
+    use crate::smo::{Id, List};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/lists/{id}",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1337) }",
+        url = "https://api.trakt.tv/lists/1337",
+    ),
+    )]
+    pub struct Request {
+        /// Trakt ID or slug of the list.
+        pub id: Id,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub List);
+}
+
+pub mod likes {
+    //! Get all users who liked a list
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/lists/list-likes/get-all-users-who-liked-a-list>
+
+    use trakt_core::Pagination;
+
+    pub use crate::api::comments::likes::{Response, ResponseItem};
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/lists/{id}/likes",
+    )]
+    pub struct Request {
+        /// Trakt ID or slug of the list.
+        pub id: Id,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+}
+
+pub mod like {
+    //! Like a list
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/lists/list-like/like-a-list>
+
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/lists/{id}/like",
+    method = POST,
+    auth = Required,
+    )]
+    pub struct Request {
+        /// Trakt ID or slug of the list.
+        pub id: Id,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = NO_CONTENT)]
+    pub struct Response;
+}
+
+pub mod remove_like {
+    //! Remove like on a list
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/lists/list-like/remove-like-on-a-list>
+
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/lists/{id}/like",
+    method = DELETE,
+    auth = Required,
+    )]
+    pub struct Request {
+        /// Trakt ID or slug of the list.
+        pub id: Id,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = NO_CONTENT)]
+    pub struct Response;
+}
+
+pub mod items {
+    //! Get all items on a list
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/lists/list-items/get-items-on-a-list>
+
+    use trakt_core::{Pagination, PaginationResponse};
+
+    use super::ListItemType;
+    use crate::smo::{Extended, Id, ListItem};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/lists/{id}/items/{tp}",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1337), tp: trakt_rs::api::lists::ListItemType::MOVIE, extended: trakt_rs::smo::Extended::empty(), pagination: Default::default() }",
+        url = "https://api.trakt.tv/lists/1337/items/movie?page=1&limit=10",
+    ),
+    )]
+    pub struct Request {
+        /// Trakt ID or slug of the list.
+        pub id: Id,
+        /// Types of items to return, all types if empty.
+        #[trakt(optional)]
+        pub tp: ListItemType,
+        pub extended: Extended,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response {
+        #[trakt(pagination)]
+        pub items: PaginationResponse<ListItem>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{
+        error::{IntoHttpError, PaginationError},
+        Context, Pagination, Request, Response as _,
+    };
+
+    use super::*;
+    use crate::{
+        smo::{Extended, Id},
+        test::assert_request,
+    };
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "client_id",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_trending() {
+        assert_request(
+            CTX,
+            trending::Request::default(),
+            "https://api.trakt.tv/lists/trending?page=1&limit=10",
+            "",
+        );
+        assert_request(
+            CTX,
+            popular::Request {
+                pagination: Pagination::new(2, 5),
+            },
+            "https://api.trakt.tv/lists/popular?page=2&limit=5",
+            "",
+        );
+
+        let body = json!([{
+            "like_count": 5,
+            "comment_count": 0,
+            "list": trakt_fixtures::json(trakt_fixtures::LIST),
+        }]);
+        let response = trakt_fixtures::paginated(body.to_string(), 1, 10, 1, 1);
+        let response = popular::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.items.items()[0].like_count, 5);
+        assert_eq!(response.items.items()[0].list.item_count, 5);
+    }
+
+    #[test]
+    fn test_summary() {
+        let req = summary::Request {
+            id: Id::Slug("star-wars-in-machete-order".parse().unwrap()),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/lists/star-wars-in-machete-order",
+            "",
+        );
+
+        let response = http::Response::builder()
+            .status(200)
+            .body(trakt_fixtures::LIST)
+            .unwrap();
+        let list = summary::Response::try_from_http_response(response)
+            .unwrap()
+            .0;
+        assert_eq!(list.ids.trakt, Some(55));
+        assert_eq!(list.user.username, "sean");
+
+        assert_request(
+            CTX,
+            likes::Request {
+                id: Id::Trakt(55),
+                pagination: Pagination::default(),
+            },
+            "https://api.trakt.tv/lists/55/likes?page=1&limit=10",
+            "",
+        );
+    }
+
+    #[test]
+    fn test_like() {
+        let ctx = Context {
+            oauth_token: Some("token"),
+            ..CTX
+        };
+        assert_request(
+            ctx,
+            like::Request { id: Id::Trakt(55) },
+            "https://api.trakt.tv/lists/55/like",
+            "",
+        );
+        assert_request(
+            ctx,
+            remove_like::Request { id: Id::Trakt(55) },
+            "https://api.trakt.tv/lists/55/like",
+            "",
+        );
+    }
+
+    #[test]
+    fn test_items_request() {
+        let req = items::Request {
+            id: Id::Trakt(123),
+            tp: ListItemType::MOVIE,
+            extended: Extended::empty(),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/lists/123/items/movie?page=1&limit=10",
+            "",
+        );
+
+        let req = items::Request {
+            id: Id::Trakt(123),
+            tp: ListItemType::from_bits_retain(0b1000_0001),
+            extended: Extended::empty(),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/lists/123/items/movie?page=1&limit=10",
+            "",
+        );
+
+        let req = items::Request {
+            id: Id::Slug("star-wars-in-machete-order".parse().unwrap()),
+            tp: ListItemType::SHOW | ListItemType::EPISODE | ListItemType::PERSON,
+            extended: Extended::FULL,
+            pagination: Pagination::new(2, 20),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/lists/star-wars-in-machete-order/items/show,episode,person?extended=full&page=2&limit=20",
+            "",
+        );
+
+        let req = items::Request {
+            id: Id::Trakt(123),
+            tp: ListItemType::empty(),
+            extended: Extended::empty(),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/lists/123/items?page=1&limit=10",
+            "",
+        );
+        assert_eq!(items::Request::METADATA.optional_params(), ["tp"]);
+
+        let req = items::Request {
+            id: Id::Trakt(123),
+            tp: ListItemType::MOVIE,
+            extended: Extended::empty(),
+            pagination: Pagination::new(1, 101),
+        };
+        assert!(matches!(
+            req.uri(CTX),
+            Err(IntoHttpError::Pagination(PaginationError::LimitTooLarge(
+                101
+            )))
+        ));
+    }
+}
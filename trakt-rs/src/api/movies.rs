@@ -12,13 +12,14 @@ pub mod favorited {
 
     use crate::smo::{Movie, Period};
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/movies/favorited/{period}",
     )]
     pub struct Request {
-        pub period: Period,
+        /// Time period to get favorites for. Trakt defaults to [`Period::Weekly`] if `None`.
+        pub period: Option<Period>,
         #[serde(flatten)]
         pub pagination: Pagination,
     }
@@ -67,10 +68,7 @@ pub mod trending {
     //! <https://trakt.docs.apiary.io/#reference/movies/trending/get-trending-movies>
     use http::StatusCode;
     use serde::Deserialize;
-    use trakt_core::{
-        error::FromHttpError, handle_response_body, parse_from_header, Pagination,
-        PaginationResponse,
-    };
+    use trakt_core::{error::FromHttpError, handle_response, Pagination, PaginationResponse};
 
     use crate::smo::Movie;
 
@@ -78,6 +76,10 @@ pub mod trending {
     #[trakt(
     response = Response,
     endpoint = "/movies/trending",
+    example(
+        request = "Request::default()",
+        url = "https://api.trakt.tv/movies/trending?page=1&limit=10",
+    ),
     )]
     pub struct Request {
         #[serde(flatten)]
@@ -101,19 +103,15 @@ pub mod trending {
         fn try_from_http_response<T: AsRef<[u8]>>(
             response: http::Response<T>,
         ) -> Result<Self, FromHttpError> {
-            let body: Vec<ResponseItem> = handle_response_body(&response, StatusCode::OK)?;
-
-            let items = PaginationResponse::from_headers(body, response.headers())?;
-
+            let (body, ctx) = handle_response(&response, StatusCode::OK)?;
             Ok(Self {
-                items,
-                trending_user_count: parse_from_header(
-                    response.headers(),
-                    "X-Trending-User-Count",
-                )?,
+                items: ctx.paginated(body)?,
+                trending_user_count: ctx.header("X-Trending-User-Count")?,
             })
         }
     }
+
+    trakt_core::impl_try_from_response!(Response);
 }
 
 pub mod played {
@@ -122,12 +120,16 @@ pub mod played {
     //! <https://trakt.docs.apiary.io/#reference/movies/played/get-the-most-played-movies>
     use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{Movie, Period};
+    use crate::smo::{Movie, Period, PlayStats};
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/movies/played/{period}",
+    example(
+        request = "Request { period: trakt_rs::smo::Period::All, pagination: Default::default() }",
+        url = "https://api.trakt.tv/movies/played/all?page=1&limit=10",
+    ),
     )]
     pub struct Request {
         pub period: Period,
@@ -141,13 +143,7 @@ pub mod played {
         pub items: PaginationResponse<ResponseItem>,
     }
 
-    #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub watcher_count: usize,
-        pub play_count: usize,
-        pub collected_count: usize,
-        pub movie: Movie,
-    }
+    pub type ResponseItem = PlayStats<Movie>;
 }
 
 pub mod watched {
@@ -156,9 +152,9 @@ pub mod watched {
     //! <https://trakt.docs.apiary.io/#reference/movies/watched/get-the-most-watched-movies>
     use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{Movie, Period};
+    use crate::smo::{Movie, Period, PlayStats};
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/movies/watched/{period}",
@@ -175,13 +171,7 @@ pub mod watched {
         pub items: PaginationResponse<ResponseItem>,
     }
 
-    #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub watcher_count: usize,
-        pub play_count: usize,
-        pub collected_count: usize,
-        pub movie: Movie,
-    }
+    pub type ResponseItem = PlayStats<Movie>;
 }
 
 pub mod collected {
@@ -190,9 +180,9 @@ pub mod collected {
     //! <https://trakt.docs.apiary.io/#reference/movies/collected/get-the-most-collected-movies>
     use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{Movie, Period};
+    use crate::smo::{Movie, Period, PlayStats};
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/movies/collected/{period}",
@@ -209,13 +199,7 @@ pub mod collected {
         pub items: PaginationResponse<ResponseItem>,
     }
 
-    #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub watcher_count: usize,
-        pub play_count: usize,
-        pub collected_count: usize,
-        pub movie: Movie,
-    }
+    pub type ResponseItem = PlayStats<Movie>;
 }
 
 pub mod anticipated {
@@ -226,7 +210,7 @@ pub mod anticipated {
 
     use crate::smo::Movie;
 
-    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/movies/anticipated",
@@ -260,6 +244,10 @@ pub mod boxoffice {
     #[trakt(
     response = Response,
     endpoint = "/movies/boxoffice",
+    example(
+        request = "Request",
+        url = "https://api.trakt.tv/movies/boxoffice",
+    ),
     )]
     pub struct Request;
 
@@ -288,7 +276,7 @@ pub mod updates {
     endpoint = "/movies/updates/{start_date}",
     )]
     pub struct Request {
-        #[serde(with = "time::serde::iso8601")]
+        #[serde(with = "crate::datetime")]
         pub start_date: OffsetDateTime,
         #[serde(flatten)]
         pub pagination: Pagination,
@@ -298,7 +286,14 @@ pub mod updates {
     #[trakt(expected = OK)]
     pub struct Response {
         #[trakt(pagination)]
-        pub items: PaginationResponse<Movie>,
+        pub items: PaginationResponse<ResponseItem>,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
+    pub struct ResponseItem {
+        #[serde(with = "crate::datetime")]
+        pub updated_at: OffsetDateTime,
+        pub movie: Movie,
     }
 }
 
@@ -316,7 +311,7 @@ pub mod updates_id {
     endpoint = "/movies/updates/id/{start_date}",
     )]
     pub struct Request {
-        #[serde(with = "time::serde::iso8601")]
+        #[serde(with = "crate::datetime")]
         pub start_date: OffsetDateTime,
         #[serde(flatten)]
         pub pagination: Pagination,
@@ -326,7 +321,7 @@ pub mod updates_id {
     #[trakt(expected = OK)]
     pub struct Response {
         #[trakt(pagination)]
-        pub items: PaginationResponse<u32>,
+        pub items: PaginationResponse<u64>,
     }
 }
 
@@ -335,16 +330,36 @@ pub mod summary {
     //!
     //! <https://trakt.docs.apiary.io/#reference/movies/summary/get-a-movie>
 
-    use crate::smo::{Id, Movie};
+    use crate::smo::{Id, Language, Movie, Translated};
 
     #[derive(Debug, Clone, Eq, PartialEq, trakt_macros::Request)]
-    #[trakt(response = Response, endpoint = "/movies/{id}")]
+    #[trakt(
+    response = Response,
+    endpoint = "/movies/{id}",
+    example(
+        request = r#"Request::new(trakt_rs::smo::Id::Slug("tron-legacy-2010".parse().unwrap()))"#,
+        url = "https://api.trakt.tv/movies/tron-legacy-2010",
+    ),
+    )]
     pub struct Request {
         pub id: Id,
+        /// Include the translations in this language in the response.
+        pub translations: Option<Language>,
+    }
+
+    impl Request {
+        /// Creates a request for the movie `id` without translations.
+        #[must_use]
+        pub const fn new(id: Id) -> Self {
+            Self {
+                id,
+                translations: None,
+            }
+        }
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Movie);
+    pub struct Response(pub Translated<Movie>);
 }
 
 pub mod aliases {
@@ -352,9 +367,7 @@ pub mod aliases {
     //!
     //! <https://trakt.docs.apiary.io/#reference/movies/aliases/get-all-movie-aliases>
 
-    use serde::Deserialize;
-
-    use crate::smo::Id;
+    use crate::smo::{Alias, Id};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
@@ -366,13 +379,7 @@ pub mod aliases {
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Vec<ResponseItem>);
-
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-    pub struct ResponseItem {
-        pub title: String,
-        pub country: String,
-    }
+    pub struct Response(pub Vec<Alias>);
 }
 
 pub mod releases {
@@ -380,42 +387,80 @@ pub mod releases {
     //!
     //! <https://trakt.docs.apiary.io/#reference/movies/releases/get-all-movie-releases>
 
+    use std::collections::HashMap;
+
     use serde::Deserialize;
+    use time::Date;
 
-    use crate::smo::{Country, Id};
+    use crate::smo::{Certification, Country, CountryTagged, Id};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/movies/{id}/releases/{country}",
+    example(
+        request = r#"Request { id: trakt_rs::smo::Id::Trakt(1), country: Some(trakt_rs::smo::Country::new("us")) }"#,
+        url = "https://api.trakt.tv/movies/1/releases/us",
+    ),
     )]
     pub struct Request {
         pub id: Id,
-        pub country: Country,
+        /// Country to get releases for. All releases are returned if `None`.
+        pub country: Option<Country>,
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response(pub Vec<ResponseItem>);
 
+    impl Response {
+        /// Groups the releases by country, under `None` for releases without a country.
+        ///
+        /// Releases within a country keep the order they were returned in.
+        #[must_use]
+        pub fn by_country(&self) -> HashMap<Option<Country>, Vec<&ResponseItem>> {
+            let mut map: HashMap<_, Vec<_>> = HashMap::new();
+            for item in &self.0 {
+                map.entry(item.country).or_default().push(item);
+            }
+            map
+        }
+
+        /// Returns the earliest release of the given type across all countries.
+        #[must_use]
+        pub fn earliest(&self, release_type: ReleaseType) -> Option<&ResponseItem> {
+            self.0
+                .iter()
+                .filter(|item| item.release_type == release_type)
+                .min_by_key(|item| item.release_date)
+        }
+    }
+
+    /// A release in a country, derefs to the [`Release`].
+    pub type ResponseItem = CountryTagged<Release>;
+
     #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-    pub struct ResponseItem {
-        pub country: Country,
-        pub certification: String,
-        pub release_date: String,
+    pub struct Release {
+        #[serde(flatten)]
+        pub certification: Certification,
+        #[serde(with = "crate::iso8601_date")]
+        pub release_date: Date,
         pub release_type: ReleaseType,
         pub note: Option<String>,
     }
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
     #[serde(rename_all = "lowercase")]
+    #[non_exhaustive]
     pub enum ReleaseType {
-        Unknown,
         Premiere,
         Limited,
         Theatrical,
         Digital,
         Physical,
         TV,
+        /// Value added by Trakt after this version.
+        #[serde(other)]
+        Unknown,
     }
 }
 
@@ -424,9 +469,7 @@ pub mod translations {
     //!
     //! <https://trakt.docs.apiary.io/#reference/movies/translations/get-all-movie-translations>
 
-    use serde::Deserialize;
-
-    use crate::smo::{Country, Id, Language};
+    use crate::smo::{CountryTranslation, Id, Language};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
@@ -441,14 +484,7 @@ pub mod translations {
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response(pub Vec<ResponseItem>);
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-    pub struct ResponseItem {
-        pub title: String,
-        pub overview: String,
-        pub tagline: String,
-        pub language: Language,
-        pub country: Country,
-    }
+    pub type ResponseItem = CountryTranslation;
 }
 
 pub mod comments {
@@ -461,19 +497,43 @@ pub mod comments {
 
     use crate::smo::{Comment, Sort};
 
+    /// Sort and filters left as `None` use the API's defaults.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/movies/{id}/comments/{sort}",
+    example(
+        request = r#"Request::new("tron-legacy-2010".into())"#,
+        url = "https://api.trakt.tv/movies/tron-legacy-2010/comments?page=1&limit=10",
+    ),
     auth = Optional,
     )]
     pub struct Request {
         pub id: String,
-        pub sort: Sort,
+        /// Sort order, omitted from the path if `None`.
+        pub sort: Option<Sort>,
+        pub include_replies: Option<bool>,
+        pub spoiler: Option<bool>,
+        pub review: Option<bool>,
         #[serde(flatten)]
         pub pagination: Pagination,
     }
 
+    impl Request {
+        /// Creates a request for the comments of the movie `id`.
+        #[must_use]
+        pub fn new(id: String) -> Self {
+            Self {
+                id,
+                sort: None,
+                include_replies: None,
+                spoiler: None,
+                review: None,
+                pagination: Pagination::default(),
+            }
+        }
+    }
+
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     #[trakt(expected = OK)]
     pub struct Response {
@@ -527,6 +587,8 @@ pub mod people {
     //!
     //! <https://trakt.docs.apiary.io/#reference/movies/lists/get-all-people-for-a-movie>
 
+    use std::ops::Deref;
+
     use serde::Deserialize;
 
     use crate::smo::{Id, Person};
@@ -535,6 +597,10 @@ pub mod people {
     #[trakt(
     response = Response,
     endpoint = "/movies/{id}/people",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1) }",
+        url = "https://api.trakt.tv/movies/1/people",
+    ),
     )]
     pub struct Request {
         pub id: Id,
@@ -546,33 +612,135 @@ pub mod people {
         pub crew: Crew,
     }
 
+    /// A cast member, and the characters they play.
+    ///
+    /// `T` holds the rest of the credit, e.g. the [`CreditedPerson`] of [`Response`], or the movie
+    /// or show of a person's credits in [`api::people`](crate::api::people). It's flattened into
+    /// the credit and can be accessed directly.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-    pub struct Character {
+    #[serde(from = "RawCharacter<T>", bound = "T: Deserialize<'de>")]
+    pub struct Character<T = CreditedPerson> {
         pub characters: Vec<String>,
+        pub item: T,
+    }
+
+    /// Accepts the single `character` of older payloads, as well as payloads with both keys.
+    #[derive(Deserialize)]
+    struct RawCharacter<T> {
+        #[serde(default)]
+        characters: Option<Vec<String>>,
+        #[serde(default)]
+        character: Option<String>,
+        #[serde(flatten)]
+        item: T,
+    }
+
+    impl<T> From<RawCharacter<T>> for Character<T> {
+        fn from(raw: RawCharacter<T>) -> Self {
+            Self {
+                characters: merge(raw.characters, raw.character),
+                item: raw.item,
+            }
+        }
+    }
+
+    impl<T> Deref for Character<T> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            &self.item
+        }
+    }
+
+    /// Person of a movie, season, or episode credit.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct CreditedPerson {
         pub person: Person,
     }
 
+    /// The plural values if present, otherwise the singular one of older payloads.
+    fn merge(many: Option<Vec<String>>, one: Option<String>) -> Vec<String> {
+        many.unwrap_or_else(|| one.into_iter().collect())
+    }
+
+    /// Crew members by department.
+    ///
+    /// Departments without crew members may be left out, in which case they're empty.
+    ///
+    /// `M` is the type of the crew members, which is also used for the credits of a person in
+    /// [`api::people`](crate::api::people).
     #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-    pub struct Crew {
-        pub production: Vec<CrewMember>,
-        pub art: Vec<CrewMember>,
-        pub crew: Vec<CrewMember>,
+    #[serde(default, bound = "M: Deserialize<'de>")]
+    pub struct Crew<M = CrewMember> {
+        pub production: Vec<M>,
+        pub art: Vec<M>,
+        pub crew: Vec<M>,
         #[serde(rename = "costume & make-up")]
-        pub costume_and_make_up: Vec<CrewMember>,
-        pub directing: Vec<CrewMember>,
-        pub writing: Vec<CrewMember>,
-        pub sound: Vec<CrewMember>,
-        pub camera: Vec<CrewMember>,
+        pub costume_and_make_up: Vec<M>,
+        pub directing: Vec<M>,
+        pub writing: Vec<M>,
+        pub sound: Vec<M>,
+        pub camera: Vec<M>,
         #[serde(rename = "visual effects")]
-        pub visual_effects: Vec<CrewMember>,
-        pub lighting: Vec<CrewMember>,
-        pub editing: Vec<CrewMember>,
+        pub visual_effects: Vec<M>,
+        pub lighting: Vec<M>,
+        pub editing: Vec<M>,
     }
 
+    impl<M> Default for Crew<M> {
+        fn default() -> Self {
+            Self {
+                production: Vec::new(),
+                art: Vec::new(),
+                crew: Vec::new(),
+                costume_and_make_up: Vec::new(),
+                directing: Vec::new(),
+                writing: Vec::new(),
+                sound: Vec::new(),
+                camera: Vec::new(),
+                visual_effects: Vec::new(),
+                lighting: Vec::new(),
+                editing: Vec::new(),
+            }
+        }
+    }
+
+    /// A crew member, and the jobs they did.
+    ///
+    /// `T` holds the rest of the credit, like for [`Character`].
     #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-    pub struct CrewMember {
+    #[serde(from = "RawCrewMember<T>", bound = "T: Deserialize<'de>")]
+    pub struct CrewMember<T = CreditedPerson> {
         pub jobs: Vec<String>,
-        pub person: Person,
+        pub item: T,
+    }
+
+    /// Accepts the single `job` of older payloads, as well as payloads with both keys.
+    #[derive(Deserialize)]
+    struct RawCrewMember<T> {
+        #[serde(default)]
+        jobs: Option<Vec<String>>,
+        #[serde(default)]
+        job: Option<String>,
+        #[serde(flatten)]
+        item: T,
+    }
+
+    impl<T> From<RawCrewMember<T>> for CrewMember<T> {
+        fn from(raw: RawCrewMember<T>) -> Self {
+            Self {
+                jobs: merge(raw.jobs, raw.job),
+                item: raw.item,
+            }
+        }
+    }
+
+    impl<T> Deref for CrewMember<T> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            &self.item
+        }
     }
 }
 
@@ -651,7 +819,7 @@ pub mod stats {
     }
 }
 
-pub mod studio {
+pub mod studios {
     //! Get movie studios
     //!
     //! <https://trakt.docs.apiary.io/#reference/movies/studios/get-movie-studios>
@@ -671,6 +839,12 @@ pub mod studio {
     pub struct Response(pub Vec<Studio>);
 }
 
+/// Renamed to [`studios`] to match the endpoint.
+#[deprecated(note = "use `studios` instead")]
+pub mod studio {
+    pub use super::studios::*;
+}
+
 pub mod watching {
     //! Get users currently watching a movie.
     //!
@@ -690,13 +864,37 @@ pub mod watching {
     pub struct Response(pub Vec<User>);
 }
 
+pub mod videos {
+    //! Get all videos for a movie
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/movies/videos/get-all-videos>
+
+    use crate::smo::{Id, Video};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/movies/{id}/videos",
+    )]
+    pub struct Request {
+        pub id: Id,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<Video>);
+}
+
 #[cfg(test)]
 mod tests {
     use httpmock::prelude::*;
     use serde_json::json;
-    use trakt_core::{Context, PaginatedResponse, Request};
+    use trakt_core::{Context, PaginatedResponse, Pagination, Request};
 
     use super::*;
+    use crate::{
+        smo::{Alias, AliasTitle, Country, Id, Period, StudioIds},
+        test::assert_request,
+    };
 
     #[test]
     pub fn test_popular() {
@@ -777,4 +975,409 @@ mod tests {
 
         popular_mock.assert();
     }
+
+    #[test]
+    fn test_trending_try_from() {
+        let body = json!([
+            {
+                "watchers": 21,
+                "movie": {
+                    "title": "TRON: Legacy",
+                    "year": 2010,
+                    "ids": {
+                        "trakt": 1,
+                        "slug": "tron-legacy-2010"
+                    }
+                }
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .header("X-Pagination-Page", "1")
+            .header("X-Pagination-Limit", "10")
+            .header("X-Pagination-Page-Count", "1")
+            .header("X-Pagination-Item-Count", "1")
+            .header("X-Trending-User-Count", "40")
+            .body(body.to_string())
+            .unwrap();
+        let response = trending::Response::try_from(response).unwrap();
+
+        assert_eq!(response.trending_user_count, 40);
+        assert_eq!(response.items().len(), 1);
+        assert_eq!(response.items()[0].watchers, 21);
+        assert_eq!(response.items()[0].movie.title, "TRON: Legacy");
+    }
+
+    #[test]
+    fn test_aliases() {
+        use trakt_core::Response as _;
+
+        let body = json!([
+            { "title": "Batman 1 - The Dark Knight", "country": "de" },
+            { "title": "The Dark Knight", "country": "us" }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = aliases::Response::try_from_http_response(response).unwrap();
+        assert_eq!(
+            response.0,
+            [
+                Alias::new(
+                    AliasTitle {
+                        title: "Batman 1 - The Dark Knight".into(),
+                    },
+                    Country::new("de"),
+                ),
+                Alias::new(
+                    AliasTitle {
+                        title: "The Dark Knight".into(),
+                    },
+                    Country::new("us"),
+                ),
+            ]
+        );
+
+        let body = json!([{ "title": "Batman 1 - The Dark Knight", "country": "usa" }]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        assert!(aliases::Response::try_from_http_response(response).is_err());
+    }
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_favorited_request() {
+        let req = favorited::Request::default();
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/movies/favorited?page=1&limit=10",
+            "",
+        );
+
+        let req = favorited::Request {
+            period: Some(Period::Monthly),
+            pagination: Pagination::new(2, 20),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/movies/favorited/monthly?page=2&limit=20",
+            "",
+        );
+    }
+
+    #[test]
+    fn test_updates() {
+        use time::macros::datetime;
+        use trakt_core::Response as _;
+
+        let body = json!([
+            {
+                "updated_at": "2014-09-22T21:56:03.000Z",
+                "movie": {
+                    "title": "The Dark Knight",
+                    "year": 2008,
+                    "ids": { "trakt": 16, "slug": "the-dark-knight-2008" }
+                }
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .header("X-Pagination-Page", "1")
+            .header("X-Pagination-Limit", "10")
+            .header("X-Pagination-Page-Count", "1")
+            .header("X-Pagination-Item-Count", "1")
+            .body(body.to_string())
+            .unwrap();
+        let response = updates::Response::try_from_http_response(response).unwrap();
+        let item = &response.items.items()[0];
+        assert_eq!(item.updated_at, datetime!(2014-09-22 21:56:03 UTC));
+        assert_eq!(item.movie.title, "The Dark Knight");
+
+        let response = http::Response::builder()
+            .status(200)
+            .header("X-Pagination-Page", "1")
+            .header("X-Pagination-Limit", "10")
+            .header("X-Pagination-Page-Count", "1")
+            .header("X-Pagination-Item-Count", "2")
+            .body(json!([1, 5_000_000_000_u64]).to_string())
+            .unwrap();
+        let response = updates_id::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.items.items(), [1, 5_000_000_000]);
+    }
+
+    #[test]
+    fn test_releases_request() {
+        let req = releases::Request {
+            id: Id::Trakt(1),
+            country: Some(Country::new("us")),
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/movies/1/releases/us", "");
+
+        let req = releases::Request {
+            id: Id::Trakt(1),
+            country: None,
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/movies/1/releases", "");
+    }
+
+    #[test]
+    fn test_releases_helpers() {
+        use time::{Date, Month};
+        use trakt_core::Response as _;
+
+        let body = json!([
+            {
+                "country": "us",
+                "certification": "PG",
+                "release_date": "2010-12-17",
+                "release_type": "theatrical",
+                "note": null
+            },
+            {
+                "country": "gb",
+                "certification": "PG",
+                "release_date": "2010-12-16",
+                "release_type": "theatrical",
+                "note": null
+            },
+            {
+                "country": "us",
+                "certification": "PG",
+                "release_date": "2011-04-05",
+                "release_type": "digital",
+                "note": "iTunes"
+            },
+            {
+                "certification": "",
+                "release_date": "2011-06-01",
+                "release_type": "physical",
+                "note": null
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = releases::Response::try_from_http_response(response).unwrap();
+
+        let by_country = response.by_country();
+        assert_eq!(by_country.len(), 3);
+        assert_eq!(by_country[&Some(Country::new("us"))].len(), 2);
+        assert_eq!(by_country[&Some(Country::new("gb"))].len(), 1);
+        assert_eq!(by_country[&None].len(), 1);
+
+        let theatrical = response
+            .earliest(releases::ReleaseType::Theatrical)
+            .unwrap();
+        assert_eq!(theatrical.country, Some(Country::new("gb")));
+        assert_eq!(theatrical.certification.certification, "PG");
+        assert_eq!(
+            theatrical.release_date,
+            Date::from_calendar_date(2010, Month::December, 16).unwrap()
+        );
+
+        let digital = response.earliest(releases::ReleaseType::Digital).unwrap();
+        assert_eq!(digital.note.as_deref(), Some("iTunes"));
+
+        assert!(response.earliest(releases::ReleaseType::TV).is_none());
+
+        let tp: releases::ReleaseType = serde_json::from_str(r#""hologram""#).unwrap();
+        assert_eq!(tp, releases::ReleaseType::Unknown);
+    }
+
+    #[test]
+    fn test_summary_translations() {
+        use trakt_core::Response as _;
+
+        use crate::smo::Language;
+
+        let req = summary::Request::new(Id::Trakt(1));
+        assert_request(CTX, req, "https://api.trakt.tv/movies/1", "");
+
+        let req = summary::Request {
+            id: Id::Trakt(1),
+            translations: Some(Language::new("es")),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/movies/1?translations=es",
+            "",
+        );
+
+        let body = json!({
+            "title": "TRON: Legacy",
+            "year": 2010,
+            "ids": { "trakt": 1, "slug": "tron-legacy-2010" },
+            "translations": [
+                {
+                    "title": "TRON: El legado",
+                    "overview": "Sam Flynn, un experto en tecnología...",
+                    "tagline": null,
+                    "language": "es",
+                    "country": "es"
+                }
+            ]
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = summary::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.title, "TRON: Legacy");
+        assert_eq!(response.0.translations.len(), 1);
+        assert_eq!(
+            response.0.translations[0].title.as_deref(),
+            Some("TRON: El legado")
+        );
+        assert_eq!(response.0.translations[0].country, Some(Country::new("es")));
+
+        let body = json!({
+            "title": "TRON: Legacy",
+            "year": 2010,
+            "ids": { "trakt": 1 }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = summary::Response::try_from_http_response(response).unwrap();
+        assert!(response.0.translations.is_empty());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_studios() {
+        let req = studios::Request { id: Id::Trakt(1) };
+        assert_request(CTX, req, "https://api.trakt.tv/movies/1/studios", "");
+        assert_eq!(studios::Request::METADATA.name(), "movies.studios");
+
+        // The deprecated path refers to the same types
+        let req: studio::Request = studios::Request { id: Id::Trakt(1) };
+        assert_request(CTX, req, "https://api.trakt.tv/movies/1/studios", "");
+
+        let response = http::Response::builder()
+            .status(200)
+            .body(trakt_fixtures::STUDIOS)
+            .unwrap();
+        let response =
+            <studios::Response as trakt_core::Response>::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 2);
+        assert_eq!(response.0[0].name, "Walt Disney Pictures");
+        assert_eq!(
+            response.0[0].ids,
+            StudioIds {
+                trakt: Some(2),
+                slug: Some("walt-disney-pictures".parse().unwrap()),
+                tmdb: Some(2),
+            }
+        );
+    }
+
+    #[test]
+    fn test_people() {
+        use trakt_core::Response as _;
+
+        let req = people::Request { id: Id::Trakt(1) };
+        assert_request(CTX, req, "https://api.trakt.tv/movies/1/people", "");
+
+        let person =
+            json!({ "name": "Jeff Bridges", "ids": { "trakt": 1, "slug": "jeff-bridges" } });
+        let body = json!({
+            "cast": [
+                { "characters": ["Kevin Flynn", "Clu"], "person": person },
+                { "character": "Sam Flynn", "person": person },
+                { "character": "Sam", "characters": ["Sam Flynn"], "person": person }
+            ],
+            "crew": {
+                "production": [{ "job": "Producer", "person": person }],
+                "art": [],
+                "crew": [],
+                "costume & make-up": [],
+                "directing": [{ "job": "Director", "jobs": ["Director", "Producer"], "person": person }],
+                "writing": [],
+                "sound": [],
+                "camera": [],
+                "visual effects": [],
+                "lighting": [],
+                "editing": []
+            }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = people::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.cast[0].characters, ["Kevin Flynn", "Clu"]);
+        assert_eq!(response.cast[1].characters, ["Sam Flynn"]);
+        // Transitional payloads send both keys, the plural one wins
+        assert_eq!(response.cast[2].characters, ["Sam Flynn"]);
+        assert_eq!(response.cast[2].person.name, "Jeff Bridges");
+        assert_eq!(response.crew.production[0].jobs, ["Producer"]);
+        assert_eq!(response.crew.directing[0].jobs, ["Director", "Producer"]);
+    }
+
+    #[test]
+    fn test_videos() {
+        use trakt_core::Response as _;
+
+        use crate::smo::{Language, VideoSite, VideoType};
+
+        let req = videos::Request { id: Id::Trakt(1) };
+        assert_request(CTX, req, "https://api.trakt.tv/movies/1/videos", "");
+
+        let body = json!([
+            {
+                "title": "TRON: Legacy - Official Trailer",
+                "url": "https://youtube.com/watch?v=L9szn1QQfas",
+                "site": "youtube",
+                "type": "trailer",
+                "size": 1080,
+                "official": true,
+                "published_at": "2010-07-22T00:00:00.000Z",
+                "country": "us",
+                "language": "en"
+            },
+            {
+                "title": "Behind the grid",
+                "url": "https://vimeo.com/123456",
+                "site": "vimeo",
+                "type": "featurette",
+                "size": null,
+                "official": false,
+                "published_at": "2011-01-01T00:00:00.000Z",
+                "country": null,
+                "language": null
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = videos::Response::try_from_http_response(response).unwrap();
+
+        assert_eq!(response.0.len(), 2);
+        let trailer = &response.0[0];
+        assert_eq!(trailer.site, VideoSite::Youtube);
+        assert_eq!(trailer.tp, VideoType::Trailer);
+        assert_eq!(trailer.size, Some(1080));
+        assert_eq!(trailer.country, Some(Country::new("us")));
+        assert_eq!(trailer.language, Some(Language::new("en")));
+
+        let featurette = &response.0[1];
+        assert_eq!(featurette.site, VideoSite::Vimeo);
+        assert_eq!(featurette.tp, VideoType::Featurette);
+        assert_eq!(featurette.size, None);
+        assert_eq!(featurette.country, None);
+    }
 }
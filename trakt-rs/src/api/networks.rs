@@ -0,0 +1,81 @@
+//! Network related endpoints
+//!
+//! <https://trakt.docs.apiary.io/#reference/networks>
+
+pub mod list {
+    //! Get networks
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/networks/list/get-networks>
+
+    use serde::Deserialize;
+
+    use crate::smo::{Country, SmallString};
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/networks",
+    example(
+        request = "Request",
+        url = "https://api.trakt.tv/networks",
+    ),
+    )]
+    pub struct Request;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<ResponseItem>);
+
+    /// A TV network.
+    ///
+    /// The `name` matches [`smo::Show::network`](crate::smo::Show) of shows.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+    pub struct ResponseItem {
+        pub name: SmallString,
+        /// Country the network is based in, if known.
+        #[serde(default)]
+        pub country: Option<Country>,
+        #[serde(default)]
+        pub ids: NetworkIds,
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+    pub struct NetworkIds {
+        pub trakt: Option<u64>,
+        pub tmdb: Option<u64>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{Context, Response as _};
+
+    use super::*;
+    use crate::{smo::Country, test::assert_request};
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_list() {
+        assert_request(CTX, list::Request, "https://api.trakt.tv/networks", "");
+
+        let body = json!([
+            { "name": "A&E", "country": "us", "ids": { "trakt": 1, "tmdb": 129 } },
+            { "name": "ABC Kids" }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = list::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 2);
+        assert_eq!(response.0[0].country, Some(Country::new("us")));
+        assert_eq!(response.0[0].ids.tmdb, Some(129));
+        assert_eq!(response.0[1].country, None);
+        assert_eq!(response.0[1].ids, list::NetworkIds::default());
+    }
+}
@@ -0,0 +1,419 @@
+//! Notes
+//!
+//! Private notes attached to media and history items. Notes are only available to VIP users.
+//!
+//! <https://trakt.docs.apiary.io/#reference/notes>
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use trakt_core::error::IntoHttpError;
+
+use crate::smo::{Id, Item, SmallString, User};
+
+/// Maximum length of a note, in characters.
+pub const MAX_LENGTH: usize = 500;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Note {
+    pub id: u64,
+    pub notes: String,
+    pub privacy: Privacy,
+    pub spoiler: bool,
+    #[serde(with = "crate::datetime")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "crate::datetime")]
+    pub updated_at: OffsetDateTime,
+    pub user: User,
+}
+
+/// Who can see a note.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Privacy {
+    #[default]
+    Private,
+    Friends,
+    Public,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+/// Item a note is added to.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Target {
+    Movie(Id),
+    Show(Id),
+    Season(Id),
+    Episode(Id),
+    Person(Id),
+    /// A play in the user's watch history, by its history id.
+    History(u64),
+}
+
+/// Body of the [`add`] and [`update`] requests.
+#[derive(Debug, Serialize)]
+struct NoteBody<'a> {
+    notes: &'a str,
+    spoiler: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy: Option<Privacy>,
+}
+
+impl<'a> NoteBody<'a> {
+    fn new(notes: &'a str, spoiler: bool, privacy: Option<Privacy>) -> Result<Self, IntoHttpError> {
+        if notes.trim().is_empty() {
+            return Err(IntoHttpError::Validation(
+                "Notes must not be empty".to_owned(),
+            ));
+        }
+        if notes.chars().count() > MAX_LENGTH {
+            return Err(IntoHttpError::Validation(format!(
+                "Notes must be at most {MAX_LENGTH} characters long"
+            )));
+        }
+        Ok(Self {
+            notes,
+            spoiler,
+            privacy,
+        })
+    }
+}
+
+pub mod add {
+    //! Add notes
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/notes/notes/add-notes>
+
+    use bytes::BufMut;
+    use serde_json::json;
+    use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+    use super::{Note, NoteBody, Privacy, Target};
+    use crate::smo::Ids;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct Request {
+        pub item: Target,
+        pub notes: String,
+        pub spoiler: bool,
+        /// Privacy of the note, the user's default if `None`.
+        pub privacy: Option<Privacy>,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/notes",
+            http::Method::POST,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            let body = NoteBody::new(&self.notes, self.spoiler, self.privacy)?;
+            let mut json = serde_json::to_value(body)?;
+
+            let (key, item) = match self.item {
+                Target::Movie(id) => ("movie", json!({ "ids": Ids::from(id) })),
+                Target::Show(id) => ("show", json!({ "ids": Ids::from(id) })),
+                Target::Season(id) => ("season", json!({ "ids": Ids::from(id) })),
+                Target::Episode(id) => ("episode", json!({ "ids": Ids::from(id) })),
+                Target::Person(id) => ("person", json!({ "ids": Ids::from(id) })),
+                Target::History(id) => ("history", json!({ "id": id })),
+            };
+            json[key] = item;
+
+            let mut writer = T::default().writer();
+            serde_json::to_writer(&mut writer, &json)?;
+
+            trakt_core::construct_req(&ctx, &Self::METADATA, &(), &(), writer.into_inner())
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = CREATED)]
+    pub struct Response(pub Note);
+}
+
+pub mod get {
+    //! Get a note
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/notes/note/get-a-note>
+
+    use super::Note;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/notes/{id}",
+    auth = Required,
+    )]
+    pub struct Request {
+        pub id: u64,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Note);
+}
+
+pub mod update {
+    //! Update a note
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/notes/note/update-a-note>
+
+    use bytes::BufMut;
+    use serde::Serialize;
+    use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+    use super::{Note, NoteBody, Privacy};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct Request {
+        pub id: u64,
+        pub notes: String,
+        pub spoiler: bool,
+        /// New privacy of the note, unchanged if `None`.
+        pub privacy: Option<Privacy>,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+    struct RequestParams {
+        id: u64,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/notes/{id}",
+            http::Method::PUT,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            let body = NoteBody::new(&self.notes, self.spoiler, self.privacy)?;
+
+            let mut writer = T::default().writer();
+            serde_json::to_writer(&mut writer, &body)?;
+
+            let params = RequestParams { id: self.id };
+            trakt_core::construct_req(&ctx, &Self::METADATA, &params, &(), writer.into_inner())
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Note);
+}
+
+pub mod delete {
+    //! Delete a note
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/notes/note/delete-a-note>
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/notes/{id}",
+    method = DELETE,
+    auth = Required,
+    )]
+    pub struct Request {
+        pub id: u64,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = NO_CONTENT)]
+    pub struct Response;
+}
+
+pub mod item {
+    //! Get the attached item of a note
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/notes/item/get-the-attached-item>
+
+    use serde::Deserialize;
+
+    use super::Item;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/notes/{id}/item",
+    example(
+        request = "Request { id: 1 }",
+        url = "https://api.trakt.tv/notes/1/item",
+    ),
+    auth = Required,
+    )]
+    pub struct Request {
+        pub id: u64,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, trakt_macros::Response)]
+    pub struct Response {
+        pub attached_to: AttachedTo,
+        /// The media item, which for history notes is the item that was watched.
+        #[serde(flatten)]
+        pub item: Item,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct AttachedTo {
+        #[serde(rename = "type")]
+        pub tp: AttachedType,
+        /// History id of the play, only present for history notes.
+        #[serde(default)]
+        pub id: Option<u64>,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    #[non_exhaustive]
+    pub enum AttachedType {
+        Movie,
+        Show,
+        Season,
+        Episode,
+        Person,
+        History,
+        /// Value added by Trakt after this version.
+        #[serde(other)]
+        Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{error::IntoHttpError, Context, Request as _, Response as _};
+
+    use super::*;
+    use crate::test::assert_request;
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "client_id",
+        oauth_token: Some("token"),
+    };
+
+    fn response(status: u16, body: &serde_json::Value) -> http::Response<Vec<u8>> {
+        http::Response::builder()
+            .status(status)
+            .body(body.to_string().into_bytes())
+            .unwrap()
+    }
+
+    #[test]
+    fn privacy_unknown() {
+        let privacy: Privacy = serde_json::from_value(json!("vip")).unwrap();
+        assert_eq!(privacy, Privacy::Unknown("vip".into()));
+        assert_eq!(serde_json::to_value(&privacy).unwrap(), json!("vip"));
+    }
+
+    #[test]
+    fn test_add() {
+        let req = add::Request {
+            item: Target::Movie(Id::Trakt(1)),
+            notes: "Watched with friends.".to_owned(),
+            spoiler: false,
+            privacy: Some(Privacy::Friends),
+        };
+        let exp = json!({
+            "movie": { "ids": { "trakt": 1 } },
+            "notes": "Watched with friends.",
+            "spoiler": false,
+            "privacy": "friends",
+        });
+        assert_request(CTX, req, "https://api.trakt.tv/notes", &exp);
+
+        let req = add::Request {
+            item: Target::History(1_982_346),
+            notes: "Rewatched on the plane.".to_owned(),
+            spoiler: true,
+            privacy: None,
+        };
+        let exp = json!({
+            "history": { "id": 1_982_346 },
+            "notes": "Rewatched on the plane.",
+            "spoiler": true,
+        });
+        assert_request(CTX, req, "https://api.trakt.tv/notes", &exp);
+
+        for notes in [String::new(), " ".to_owned(), "a".repeat(MAX_LENGTH + 1)] {
+            let req = add::Request {
+                item: Target::Person(Id::Trakt(1)),
+                notes,
+                spoiler: false,
+                privacy: None,
+            };
+            assert!(matches!(
+                req.try_into_http_request::<Vec<u8>>(CTX),
+                Err(IntoHttpError::Validation(_))
+            ));
+        }
+
+        let body = json!({
+            "id": 1,
+            "notes": "Watched with friends.",
+            "privacy": "friends",
+            "spoiler": false,
+            "created_at": "2014-09-01T09:10:11.000Z",
+            "updated_at": "2014-09-01T09:10:11.000Z",
+            "user": trakt_fixtures::json(trakt_fixtures::USER),
+        });
+        let note = add::Response::try_from_http_response(response(201, &body))
+            .unwrap()
+            .0;
+        assert_eq!(note.id, 1);
+        assert_eq!(note.privacy, Privacy::Friends);
+    }
+
+    #[test]
+    fn test_note() {
+        assert_request(
+            CTX,
+            get::Request { id: 1 },
+            "https://api.trakt.tv/notes/1",
+            "",
+        );
+
+        let req = update::Request {
+            id: 1,
+            notes: "Watched with family.".to_owned(),
+            spoiler: false,
+            privacy: None,
+        };
+        let exp = json!({ "notes": "Watched with family.", "spoiler": false });
+        assert_request(CTX, req, "https://api.trakt.tv/notes/1", &exp);
+
+        let req = delete::Request { id: 1 };
+        let http_req: http::Request<Vec<u8>> = req.try_into_http_request(CTX).unwrap();
+        assert_eq!(http_req.method(), http::Method::DELETE);
+        assert_eq!(http_req.uri(), "https://api.trakt.tv/notes/1");
+
+        assert_request(
+            CTX,
+            item::Request { id: 1 },
+            "https://api.trakt.tv/notes/1/item",
+            "",
+        );
+        let body = json!({
+            "attached_to": { "type": "history", "id": 1_982_346 },
+            "type": "movie",
+            "movie": trakt_fixtures::json(trakt_fixtures::MOVIE),
+        });
+        let res = item::Response::try_from_http_response(response(200, &body)).unwrap();
+        assert_eq!(res.attached_to.tp, item::AttachedType::History);
+        assert_eq!(res.attached_to.id, Some(1_982_346));
+        assert_eq!(res.item.title(), Some("TRON: Legacy"));
+    }
+}
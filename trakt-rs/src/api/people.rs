@@ -0,0 +1,328 @@
+//! People
+//!
+//! Endpoints of a single person, and the movies and shows they're credited in.
+//!
+//! <https://trakt.docs.apiary.io/#reference/people>
+
+pub mod summary {
+    //! Get a single person
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/people/summary/get-a-single-person>
+
+    use crate::smo::{Extended, Id, Person};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/people/{id}",
+    example(
+        request = r#"Request { id: trakt_rs::smo::Id::Slug("bryan-cranston".parse().unwrap()), extended: trakt_rs::smo::Extended::FULL }"#,
+        url = "https://api.trakt.tv/people/bryan-cranston?extended=full",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Person);
+}
+
+pub mod movies {
+    //! Get movie credits
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/people/movies/get-movie-credits>
+
+    use serde::Deserialize;
+
+    pub use crate::api::movies::people::Crew;
+    use crate::{
+        api::movies::people,
+        smo::{Extended, Id, Movie},
+    };
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/people/{id}/movies",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, trakt_macros::Response)]
+    pub struct Response {
+        #[serde(default)]
+        pub cast: Vec<Character>,
+        #[serde(default)]
+        pub crew: Crew<CrewMember>,
+    }
+
+    pub type Character = people::Character<CreditedMovie>;
+    pub type CrewMember = people::CrewMember<CreditedMovie>;
+
+    /// Movie of a person's credit.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct CreditedMovie {
+        pub movie: Movie,
+    }
+}
+
+pub mod shows {
+    //! Get show credits
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/people/shows/get-show-credits>
+
+    use serde::Deserialize;
+
+    pub use crate::api::movies::people::Crew;
+    use crate::{
+        api::movies::people,
+        smo::{Extended, Id, Show},
+    };
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/people/{id}/shows",
+    example(
+        request = r#"Request { id: trakt_rs::smo::Id::Slug("bryan-cranston".parse().unwrap()), extended: trakt_rs::smo::Extended::empty() }"#,
+        url = "https://api.trakt.tv/people/bryan-cranston/shows",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, trakt_macros::Response)]
+    pub struct Response {
+        #[serde(default)]
+        pub cast: Vec<Character>,
+        #[serde(default)]
+        pub crew: Crew<CrewMember>,
+    }
+
+    pub type Character = people::Character<CastShow>;
+    pub type CrewMember = people::CrewMember<CrewShow>;
+
+    /// Show of a person's cast credit.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct CastShow {
+        /// Number of episodes the person appears in, if known.
+        #[serde(default)]
+        pub episode_count: Option<u32>,
+        /// Whether the person is a series regular, if known.
+        #[serde(default)]
+        pub series_regular: Option<bool>,
+        pub show: Show,
+    }
+
+    /// Show of a person's crew credit.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct CrewShow {
+        /// Number of episodes the person worked on, if known.
+        #[serde(default)]
+        pub episode_count: Option<u32>,
+        pub show: Show,
+    }
+}
+
+pub mod lists {
+    //! Get lists containing this person
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/people/lists/get-lists-containing-this-person>
+
+    use bytes::BufMut;
+    use serde::Serialize;
+    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination};
+
+    pub use crate::api::shows::lists::{Response, Sort, Type};
+    use crate::{api::shows::lists::Segments, smo::Id};
+
+    /// A type or sort left as `None` uses the API's defaults, like [`shows::lists`]. A `sort`
+    /// given without a type is sent with [`Type::All`].
+    ///
+    /// [`shows::lists`]: crate::api::shows::lists
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct Request {
+        pub id: Id,
+        pub tp: Option<Type>,
+        pub sort: Option<Sort>,
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct RequestPathParams {
+        id: Id,
+        #[serde(flatten)]
+        segments: Segments,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/people/{id}/lists/{tp}/{sort}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!())
+        .with_optional_params(&["tp", "sort"]);
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            self.pagination.validate()?;
+            let path = RequestPathParams {
+                id: self.id,
+                segments: Segments::new(self.tp, self.sort),
+            };
+            trakt_core::construct_req(&ctx, &Self::METADATA, &path, &self.pagination, T::default())
+        }
+    }
+
+    impl Request {
+        /// Creates a request for all lists containing the person `id`.
+        #[must_use]
+        pub fn new(id: Id) -> Self {
+            Self {
+                id,
+                tp: None,
+                sort: None,
+                pagination: Pagination::default(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::{Date, Month};
+    use trakt_core::{Context, Pagination, Response as _};
+
+    use super::*;
+    use crate::{
+        smo::{Extended, Id},
+        test::assert_request,
+    };
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    fn response(body: &serde_json::Value) -> http::Response<Vec<u8>> {
+        http::Response::builder()
+            .status(200)
+            .body(body.to_string().into_bytes())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_summary() {
+        let req = summary::Request {
+            id: Id::Slug("bryan-cranston".parse().unwrap()),
+            extended: Extended::FULL,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/people/bryan-cranston?extended=full",
+            "",
+        );
+
+        let body = json!({
+            "name": "Bryan Cranston",
+            "ids": {"trakt": 297_737, "slug": "bryan-cranston", "imdb": "nm0186505", "tmdb": 17419},
+            "biography": "Bryan Lee Cranston is an American actor.",
+            "birthday": "1956-03-07",
+            "death": null,
+            "birthplace": "San Fernando Valley, California, USA",
+            "homepage": "http://www.bryancranston.com/",
+        });
+        let person = summary::Response::try_from_http_response(response(&body))
+            .unwrap()
+            .0;
+        assert_eq!(person.name, "Bryan Cranston");
+        assert_eq!(
+            person.birthday,
+            Some(Date::from_calendar_date(1956, Month::March, 7).unwrap())
+        );
+        assert_eq!(person.death, None);
+        assert_eq!(
+            person.birthplace.as_deref(),
+            Some("San Fernando Valley, California, USA")
+        );
+    }
+
+    #[test]
+    fn test_credits() {
+        let req = movies::Request {
+            id: Id::Trakt(1),
+            extended: Extended::empty(),
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/people/1/movies", "");
+
+        let req = shows::Request {
+            id: Id::Trakt(1),
+            extended: Extended::empty(),
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/people/1/shows", "");
+
+        let movie = trakt_fixtures::json(trakt_fixtures::MOVIE);
+        let body = json!({
+            "cast": [{"character": "Sam", "characters": ["Sam Flynn"], "movie": movie}],
+            "crew": {"production": [{"job": "Producer", "jobs": ["Producer"], "movie": movie}]},
+        });
+        let res = movies::Response::try_from_http_response(response(&body)).unwrap();
+        assert_eq!(res.cast[0].characters, ["Sam Flynn"]);
+        assert_eq!(res.crew.production[0].jobs, ["Producer"]);
+        assert_eq!(res.crew.production[0].movie.title, "TRON: Legacy");
+        assert!(res.crew.directing.is_empty());
+
+        let show = trakt_fixtures::json(trakt_fixtures::SHOW);
+        let body = json!({
+            "cast": [{
+                "character": "Walter White",
+                "episode_count": 62,
+                "series_regular": true,
+                "show": show,
+            }],
+        });
+        let res = shows::Response::try_from_http_response(response(&body)).unwrap();
+        assert_eq!(res.cast[0].characters, ["Walter White"]);
+        assert_eq!(res.cast[0].episode_count, Some(62));
+        assert_eq!(res.cast[0].series_regular, Some(true));
+        assert_eq!(res.crew, shows::Crew::default());
+    }
+
+    #[test]
+    fn test_lists() {
+        let req = lists::Request {
+            id: Id::Slug("bryan-cranston".parse().unwrap()),
+            tp: Some(lists::Type::Official),
+            sort: Some(lists::Sort::Added),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/people/bryan-cranston/lists/official/added?page=1&limit=10",
+            "",
+        );
+
+        let mut req = lists::Request::new(Id::Slug("bryan-cranston".parse().unwrap()));
+        req.sort = Some(lists::Sort::Likes);
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/people/bryan-cranston/lists/all/likes?page=1&limit=10",
+            "",
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Recommendations
+//!
+//! Personalized recommendations of the authenticated user, based on their watch history,
+//! ratings, and favorites.
+//!
+//! <https://trakt.docs.apiary.io/#reference/recommendations>
+
+pub mod movies {
+    //! Get movie recommendations
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/recommendations/movies/get-movie-recommendations>
+
+    use crate::smo::{Extended, Movie};
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/recommendations/movies",
+    example(
+        request = "Request { ignore_collected: true, limit: Some(25), ..Default::default() }",
+        url = "https://api.trakt.tv/recommendations/movies?ignore_collected=true&ignore_watchlisted=false&limit=25",
+    ),
+    auth = Required,
+    )]
+    pub struct Request {
+        /// Leaves out movies the user has already collected.
+        pub ignore_collected: bool,
+        /// Leaves out movies already on the user's watchlist.
+        pub ignore_watchlisted: bool,
+        /// Number of recommendations to return, up to [`trakt_core::Pagination::MAX_LIMIT`].
+        /// Defaults to 10.
+        #[trakt(limit)]
+        pub limit: Option<usize>,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<Movie>);
+}
+
+pub mod hide_movie {
+    //! Hide a movie recommendation
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/recommendations/hide-movie/hide-a-movie-recommendation>
+
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/recommendations/movies/{id}",
+    method = DELETE,
+    auth = Required,
+    )]
+    pub struct Request {
+        pub id: Id,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = NO_CONTENT)]
+    pub struct Response;
+}
+
+pub mod shows {
+    //! Get show recommendations
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/recommendations/shows/get-show-recommendations>
+
+    use crate::smo::{Extended, Show};
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/recommendations/shows",
+    auth = Required,
+    )]
+    pub struct Request {
+        /// Leaves out shows the user has already collected.
+        pub ignore_collected: bool,
+        /// Leaves out shows already on the user's watchlist.
+        pub ignore_watchlisted: bool,
+        /// Number of recommendations to return, up to [`trakt_core::Pagination::MAX_LIMIT`].
+        /// Defaults to 10.
+        #[trakt(limit)]
+        pub limit: Option<usize>,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<Show>);
+}
+
+pub mod hide_show {
+    //! Hide a show recommendation
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/recommendations/hide-show/hide-a-show-recommendation>
+
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/recommendations/shows/{id}",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390) }",
+        url = "https://api.trakt.tv/recommendations/shows/1390",
+    ),
+    method = DELETE,
+    auth = Required,
+    )]
+    pub struct Request {
+        pub id: Id,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = NO_CONTENT)]
+    pub struct Response;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{
+        error::{IntoHttpError, PaginationError},
+        Context, Request as _, Response as _,
+    };
+
+    use super::*;
+    use crate::{
+        smo::{Extended, Id},
+        test::assert_request,
+    };
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: Some("token"),
+    };
+
+    #[test]
+    fn test_recommendations() {
+        let req = movies::Request {
+            ignore_collected: true,
+            ignore_watchlisted: false,
+            limit: Some(25),
+            extended: Extended::FULL,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/recommendations/movies?ignore_collected=true&ignore_watchlisted=false&limit=25&extended=full",
+            "",
+        );
+
+        let req = shows::Request::default();
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/recommendations/shows?ignore_collected=false&ignore_watchlisted=false",
+            "",
+        );
+
+        let req = shows::Request {
+            limit: Some(101),
+            ..Default::default()
+        };
+        assert!(matches!(
+            req.try_into_http_request::<Vec<u8>>(CTX),
+            Err(IntoHttpError::Pagination(PaginationError::LimitTooLarge(
+                101
+            )))
+        ));
+        let req = movies::Request {
+            limit: Some(0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            req.uri(CTX),
+            Err(IntoHttpError::Pagination(PaginationError::ZeroLimit))
+        ));
+
+        let body = json!([trakt_fixtures::json(trakt_fixtures::MOVIE)]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = movies::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0[0].title, "TRON: Legacy");
+    }
+
+    #[test]
+    fn test_hide() {
+        let req = hide_movie::Request { id: Id::Trakt(1) };
+        let http_req: http::Request<Vec<u8>> = req.clone().try_into_http_request(CTX).unwrap();
+        assert_eq!(http_req.method(), http::Method::DELETE);
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/recommendations/movies/1",
+            "",
+        );
+
+        let req = hide_show::Request {
+            id: Id::Slug("breaking-bad".parse().unwrap()),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/recommendations/shows/breaking-bad",
+            "",
+        );
+
+        let response = http::Response::builder()
+            .status(204)
+            .body(Vec::<u8>::new())
+            .unwrap();
+        assert!(hide_show::Response::try_from_http_response(response).is_ok());
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! <https://trakt.docs.apiary.io/#reference/scrobble>
 
+use bytes::BufMut;
 use serde::Deserialize;
+use serde_json::json;
+use trakt_core::{error::IntoHttpError, Context, Metadata};
 
-use crate::smo::{Episode, Movie, Sharing, Show};
+use crate::smo::{Episode, Id, Ids, Movie, Sharing, Show, WatchAction};
 
 mod _private {
     use crate::smo::{Episode, Movie};
@@ -38,9 +41,9 @@ impl ScrobbleItem for Episode {
 #[trakt(expected = CREATED)]
 pub struct MovieResponse {
     pub id: u64,
-    pub action: Action,
+    pub action: WatchAction,
     pub progress: f64,
-    pub sharing: Sharing,
+    pub sharing: Option<Sharing>,
     pub movie: Movie,
 }
 
@@ -48,19 +51,34 @@ pub struct MovieResponse {
 #[trakt(expected = CREATED)]
 pub struct EpisodeResponse {
     pub id: u64,
-    pub action: Action,
+    pub action: WatchAction,
     pub progress: f64,
-    pub sharing: Sharing,
+    pub sharing: Option<Sharing>,
     pub episode: Episode,
     pub show: Show,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Action {
-    Start,
-    Pause,
-    Scrobble,
+#[deprecated(note = "use `smo::WatchAction` instead")]
+pub type Action = WatchAction;
+
+pub use crate::smo::PlaybackBody;
+
+/// Constructs a scrobble request of the item `I` with the given progress and optional fields.
+fn construct_playback_req<I: ScrobbleItem, T: Default + BufMut>(
+    ctx: &Context,
+    md: &Metadata,
+    id: Id,
+    progress: f64,
+    extra: &PlaybackBody,
+) -> Result<http::Request<T>, IntoHttpError> {
+    let mut json = serde_json::to_value(extra)?;
+    json[I::KEY] = json!({ "ids": Ids::from(id) });
+    json["progress"] = json!(progress);
+
+    let mut writer = T::default().writer();
+    serde_json::to_writer(&mut writer, &json)?;
+
+    trakt_core::construct_req(ctx, md, &(), &(), writer.into_inner())
 }
 
 pub mod start {
@@ -69,16 +87,16 @@ pub mod start {
     //! <https://trakt.docs.apiary.io/#reference/scrobble/start/start-watching-in-a-media-center>
 
     use bytes::BufMut;
-    use serde_json::{json, Value};
     use trakt_core::{error::IntoHttpError, Context, Metadata};
 
-    use super::ScrobbleItem;
-    use crate::smo::{Episode, Id, Ids, Movie};
+    use super::{construct_playback_req, PlaybackBody, ScrobbleItem};
+    use crate::smo::{Episode, Id, Movie};
 
     #[derive(Debug, Clone, PartialEq)]
     pub struct Request<I: ScrobbleItem> {
         pub id: Id,
         pub progress: f64,
+        pub extra: PlaybackBody,
         _phantom: std::marker::PhantomData<I>,
     }
 
@@ -89,6 +107,7 @@ pub mod start {
             Self {
                 id,
                 progress,
+                extra: PlaybackBody::new(),
                 _phantom: std::marker::PhantomData,
             }
         }
@@ -112,29 +131,24 @@ pub mod start {
 
     impl<I: ScrobbleItem> trakt_core::Request for Request<I> {
         type Response = I::Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/scrobble/start",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::Required,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/scrobble/start",
+            http::Method::POST,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
             ctx: Context,
         ) -> Result<http::Request<T>, IntoHttpError> {
-            let body = T::default();
-            let mut writer = body.writer();
-
-            let json = Value::Object({
-                let mut map = serde_json::Map::new();
-                map.insert(I::KEY.to_owned(), json!({ "ids": Ids::from(self.id) }));
-                map.insert("progress".to_owned(), json!(self.progress));
-                map
-            });
-
-            serde_json::to_writer(&mut writer, &json)?;
-
-            trakt_core::construct_req(&ctx, &Self::METADATA, &(), &(), writer.into_inner())
+            construct_playback_req::<I, T>(
+                &ctx,
+                &Self::METADATA,
+                self.id,
+                self.progress,
+                &self.extra,
+            )
         }
     }
 }
@@ -145,18 +159,16 @@ pub mod pause {
     //! <https://trakt.docs.apiary.io/#reference/scrobble/pause/pause-watching-in-a-media-center>
 
     use bytes::BufMut;
-    use serde_json::{json, Value};
     use trakt_core::{error::IntoHttpError, Context, Metadata};
 
-    use crate::{
-        api::scrobble::ScrobbleItem,
-        smo::{Episode, Id, Ids, Movie},
-    };
+    use super::{construct_playback_req, PlaybackBody, ScrobbleItem};
+    use crate::smo::{Episode, Id, Movie};
 
     #[derive(Debug, Clone, PartialEq)]
     pub struct Request<I: ScrobbleItem> {
         pub id: Id,
         pub progress: f64,
+        pub extra: PlaybackBody,
         _phantom: std::marker::PhantomData<I>,
     }
 
@@ -167,6 +179,7 @@ pub mod pause {
             Self {
                 id,
                 progress,
+                extra: PlaybackBody::new(),
                 _phantom: std::marker::PhantomData,
             }
         }
@@ -190,29 +203,24 @@ pub mod pause {
 
     impl<I: ScrobbleItem> trakt_core::Request for Request<I> {
         type Response = I::Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/scrobble/pause",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::Required,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/scrobble/pause",
+            http::Method::POST,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
             ctx: Context,
         ) -> Result<http::Request<T>, IntoHttpError> {
-            let body = T::default();
-            let mut writer = body.writer();
-
-            let json = Value::Object({
-                let mut map = serde_json::Map::new();
-                map.insert(I::KEY.to_owned(), json!({ "ids": Ids::from(self.id) }));
-                map.insert("progress".to_owned(), json!(self.progress));
-                map
-            });
-
-            serde_json::to_writer(&mut writer, &json)?;
-
-            trakt_core::construct_req(&ctx, &Self::METADATA, &(), &(), writer.into_inner())
+            construct_playback_req::<I, T>(
+                &ctx,
+                &Self::METADATA,
+                self.id,
+                self.progress,
+                &self.extra,
+            )
         }
     }
 }
@@ -223,18 +231,16 @@ pub mod stop {
     //! <https://trakt.docs.apiary.io/#reference/scrobble/stop/stop-or-finish-watching-in-a-media-center>
 
     use bytes::BufMut;
-    use serde_json::{json, Value};
     use trakt_core::{error::IntoHttpError, Context, Metadata};
 
-    use crate::{
-        api::scrobble::ScrobbleItem,
-        smo::{Episode, Id, Ids, Movie},
-    };
+    use super::{construct_playback_req, PlaybackBody, ScrobbleItem};
+    use crate::smo::{Episode, Id, Movie};
 
     #[derive(Debug, Clone, PartialEq)]
     pub struct Request<I: ScrobbleItem> {
         pub id: Id,
         pub progress: f64,
+        pub extra: PlaybackBody,
         _phantom: std::marker::PhantomData<I>,
     }
 
@@ -245,6 +251,7 @@ pub mod stop {
             Self {
                 id,
                 progress,
+                extra: PlaybackBody::new(),
                 _phantom: std::marker::PhantomData,
             }
         }
@@ -268,29 +275,24 @@ pub mod stop {
 
     impl<I: ScrobbleItem> trakt_core::Request for Request<I> {
         type Response = I::Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/scrobble/stop",
-            method: http::Method::POST,
-            auth: trakt_core::AuthRequirement::Required,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/scrobble/stop",
+            http::Method::POST,
+            trakt_core::AuthRequirement::Required,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
             ctx: Context,
         ) -> Result<http::Request<T>, IntoHttpError> {
-            let body = T::default();
-            let mut writer = body.writer();
-
-            let json = Value::Object({
-                let mut map = serde_json::Map::new();
-                map.insert(I::KEY.to_owned(), json!({ "ids": Ids::from(self.id) }));
-                map.insert("progress".to_owned(), json!(self.progress));
-                map
-            });
-
-            serde_json::to_writer(&mut writer, &json)?;
-
-            trakt_core::construct_req(&ctx, &Self::METADATA, &(), &(), writer.into_inner())
+            construct_playback_req::<I, T>(
+                &ctx,
+                &Self::METADATA,
+                self.id,
+                self.progress,
+                &self.extra,
+            )
         }
     }
 }
@@ -322,7 +324,7 @@ pub mod tests {
             "episode": { "ids": { "slug": "abc" } },
             "progress": 5.0
         });
-        let req = start::Request::new_episode(Id::Slug("abc".into()), 5.0);
+        let req = start::Request::new_episode(Id::Slug("abc".parse().unwrap()), 5.0);
         assert_request(CTX, req, "https://api.trakt.tv/scrobble/start", &exp);
     }
 
@@ -356,7 +358,107 @@ pub mod tests {
             "episode": { "ids": { "slug": "abc" } },
             "progress": 50.0
         });
-        let req = stop::Request::new_episode(Id::Slug("abc".into()), 50.0);
+        let req = stop::Request::new_episode(Id::Slug("abc".parse().unwrap()), 50.0);
+        assert_request(CTX, req, "https://api.trakt.tv/scrobble/stop", &exp);
+    }
+
+    #[test]
+    fn test_playback_body() {
+        use time::{Date, Month};
+
+        let mut req = stop::Request::new_movie(Id::Trakt(1), 99.9);
+        req.extra = PlaybackBody {
+            message: Some("Watched it all".to_owned()),
+            sharing: Some(Sharing {
+                twitter: true,
+                mastodon: false,
+                tumblr: false,
+            }),
+            app_version: Some("1.0".to_owned()),
+            app_date: Some(Date::from_calendar_date(2014, Month::September, 22).unwrap()),
+        };
+        let exp = json!({
+            "movie": { "ids": { "trakt": 1 } },
+            "progress": 99.9,
+            "message": "Watched it all",
+            "sharing": { "twitter": true, "mastodon": false, "tumblr": false },
+            "app_version": "1.0",
+            "app_date": "2014-09-22"
+        });
         assert_request(CTX, req, "https://api.trakt.tv/scrobble/stop", &exp);
+
+        let mut req = start::Request::new_episode(Id::Trakt(2), 1.5);
+        req.extra.app_version = Some("2.0".to_owned());
+        let exp = json!({
+            "episode": { "ids": { "trakt": 2 } },
+            "progress": 1.5,
+            "app_version": "2.0"
+        });
+        assert_request(CTX, req, "https://api.trakt.tv/scrobble/start", &exp);
+    }
+
+    #[test]
+    fn test_stop_response() {
+        use trakt_core::Response as _;
+
+        let body = json!({
+            "id": 3_373_536_622_u64,
+            "action": "scrobble",
+            "progress": 99.9,
+            "sharing": { "twitter": true, "mastodon": false, "tumblr": false },
+            "movie": {
+                "title": "Guardians of the Galaxy",
+                "year": 2014,
+                "ids": { "trakt": 28, "slug": "guardians-of-the-galaxy-2014" }
+            }
+        });
+        let response = http::Response::builder()
+            .status(201)
+            .body(body.to_string())
+            .unwrap();
+        let response = MovieResponse::try_from_http_response(response).unwrap();
+        assert_eq!(response.action, WatchAction::Scrobble);
+        assert!(response.sharing.unwrap().twitter);
+
+        // Sharing can be missing
+        let body = json!({
+            "id": 3_373_536_623_u64,
+            "action": "scrobble",
+            "progress": 85.9,
+            "episode": {
+                "season": 1,
+                "number": 1,
+                "title": "Pilot",
+                "ids": { "trakt": 16 }
+            },
+            "show": {
+                "title": "Breaking Bad",
+                "year": 2008,
+                "ids": { "trakt": 1 }
+            }
+        });
+        let response = http::Response::builder()
+            .status(201)
+            .body(body.to_string())
+            .unwrap();
+        let response = EpisodeResponse::try_from_http_response(response).unwrap();
+        assert_eq!(response.action, WatchAction::Scrobble);
+        assert_eq!(response.sharing, None);
+
+        // Or null
+        let body = json!({
+            "id": 3_373_536_620_u64,
+            "action": "pause",
+            "progress": 75.0,
+            "sharing": null,
+            "movie": { "title": "Guardians of the Galaxy", "year": 2014, "ids": { "trakt": 28 } }
+        });
+        let response = http::Response::builder()
+            .status(201)
+            .body(body.to_string())
+            .unwrap();
+        let response = MovieResponse::try_from_http_response(response).unwrap();
+        assert_eq!(response.action, WatchAction::Pause);
+        assert_eq!(response.sharing, None);
     }
 }
@@ -2,6 +2,11 @@
 //!
 //! <https://trakt.docs.apiary.io/#reference/search>
 
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
 use serde::Serializer;
 
 use crate::smo::Item;
@@ -19,39 +24,85 @@ bitflags::bitflags! {
 
 impl serde::Serialize for SearchType {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        const FLAGS: [&str; 5] = ["movie", "show", "episode", "person", "list"];
-
-        if self.is_empty() {
+        // Serialize as a comma-separated list of the flag names, dropping unknown bits.
+        // We can't serialize as a sequence b/c serde_urlencoded doesn't support it
+        let joined = self
+            .iter_names()
+            .map(|(name, _)| name.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        if joined.is_empty() {
             serializer.serialize_none()
-        } else if self.bits().count_ones() == 1 {
-            // Serialize as a single value
-
-            // Get name of the flag
-            let idx = self.bits().trailing_zeros() as usize;
-            serializer.serialize_str(FLAGS[idx])
         } else {
-            // Serialize as a comma-separated list
-            // We can't serialize as a sequence b/c serde_urlencoded doesn't support it
+            serializer.serialize_str(&joined)
+        }
+    }
+}
 
-            // Get names of the flags
-            let iter = self.iter().map(|flag| {
-                let idx = flag.bits().trailing_zeros() as usize;
-                FLAGS[idx]
-            });
+/// Relevance score of a [`SearchResult`].
+///
+/// Totally ordered, using [`f64::total_cmp`], so that results can be sorted and compared by score.
+#[derive(Debug, Copy, Clone, Default, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Score(pub f64);
+
+impl Score {
+    #[must_use]
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+}
 
-            // Join the names
-            let joined = iter.collect::<Vec<_>>().join(",");
+impl PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
 
-            serializer.serialize_str(&joined)
-        }
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for Score {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+impl From<f64> for Score {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
 pub struct SearchResult {
     #[serde(flatten)]
     pub item: Item,
-    pub score: Option<f64>,
+    pub score: Option<Score>,
+}
+
+impl SearchResult {
+    /// Whether the title of the result is `title`, ignoring case and surrounding whitespace.
+    ///
+    /// Useful to pick the exact match out of fuzzy text query results.
+    #[must_use]
+    pub fn is_exact_title_match(&self, title: &str) -> bool {
+        self.item
+            .title()
+            .is_some_and(|t| t.trim().to_lowercase() == title.trim().to_lowercase())
+    }
 }
 
 pub mod text_query {
@@ -66,7 +117,11 @@ pub mod text_query {
     #[derive(Debug, Clone, PartialEq, Eq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
-    endpoint = "/search/{tp}"
+    endpoint = "/search/{tp}",
+    example(
+        request = r#"Request { tp: trakt_rs::api::search::SearchType::MOVIE, query: "tron".into(), pagination: Default::default() }"#,
+        url = "https://api.trakt.tv/search/movie?query=tron&page=1&limit=10",
+    ),
     )]
     pub struct Request {
         pub tp: SearchType,
@@ -75,7 +130,7 @@ pub mod text_query {
         pub pagination: Pagination,
     }
 
-    #[derive(Debug, Clone, PartialEq, trakt_macros::Response)]
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response {
         #[trakt(pagination)]
         pub items: PaginationResponse<SearchResult>,
@@ -89,7 +144,10 @@ pub mod id_lookup {
 
     use bytes::BufMut;
     use serde::Serialize;
-    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
+    use trakt_core::{
+        error::{FromHttpError, IntoHttpError},
+        Context, Metadata, Pagination, PaginationResponse,
+    };
 
     use super::{SearchResult, SearchType};
     use crate::smo::Id;
@@ -119,6 +177,7 @@ pub mod id_lookup {
         type Error = IntoHttpError;
 
         fn try_from(value: Request) -> Result<Self, Self::Error> {
+            value.pagination.validate()?;
             Ok((
                 RequestPathParams {
                     id_type: match &value.id {
@@ -144,11 +203,12 @@ pub mod id_lookup {
 
     impl trakt_core::Request for Request {
         type Response = Response;
-        const METADATA: Metadata = Metadata {
-            endpoint: "/search/{id_type}/{id}",
-            method: http::Method::GET,
-            auth: trakt_core::AuthRequirement::None,
-        };
+        const METADATA: Metadata = Metadata::new(
+            "/search/{id_type}/{id}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!());
 
         fn try_into_http_request<T: Default + BufMut>(
             self,
@@ -157,13 +217,64 @@ pub mod id_lookup {
             let (path, query) = self.try_into()?;
             trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
         }
+
+        fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+            let (path, query) = self.clone().try_into()?;
+            trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+        }
     }
 
-    #[derive(Debug, Clone, PartialEq, trakt_macros::Response)]
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response {
         #[trakt(pagination)]
         pub items: PaginationResponse<SearchResult>,
     }
+
+    /// Prepares an id lookup request for each of the given ids.
+    ///
+    /// All requests share the same `tp` and `pagination`. The returned requests are in the same
+    /// order as `ids`, so they can be executed concurrently and matched back up with [`collate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the requests fail to be constructed, e.g. if one of the ids is
+    /// a [`Id::Slug`].
+    pub fn batch<T, I>(
+        ctx: Context,
+        ids: I,
+        tp: SearchType,
+        pagination: Pagination,
+    ) -> Result<Vec<http::Request<T>>, IntoHttpError>
+    where
+        T: Default + BufMut,
+        I: IntoIterator<Item = Id>,
+    {
+        use trakt_core::Request as _;
+
+        ids.into_iter()
+            .map(|id| Request { id, tp, pagination }.try_into_http_request(ctx))
+            .collect()
+    }
+
+    /// Parses the responses of requests prepared by [`batch`].
+    ///
+    /// The returned responses are in the same order as `responses`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the responses fail to be parsed.
+    pub fn collate<T, I>(responses: I) -> Result<Vec<Response>, FromHttpError>
+    where
+        T: AsRef<[u8]>,
+        I: IntoIterator<Item = http::Response<T>>,
+    {
+        use trakt_core::Response as _;
+
+        responses
+            .into_iter()
+            .map(Response::try_from_http_response)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -214,7 +325,51 @@ mod tests {
             tp: SearchType::empty(),
         };
         let url = construct_url("", "/search/{tp}", &test, &()).unwrap();
-        assert_eq!(url, "/search/");
+        assert_eq!(url, "/search");
+    }
+
+    #[test]
+    fn test_text_query_multiple_types() {
+        use serde_json::json;
+        use trakt_core::{PaginatedResponse, Response};
+
+        let req = text_query::Request {
+            tp: SearchType::MOVIE | SearchType::PERSON,
+            query: "tron".into(),
+            pagination: Pagination::new(2, 2),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/search/movie,person?query=tron&page=2&limit=2",
+            "",
+        );
+
+        let body = json!([
+            {
+                "type": "movie",
+                "score": 26.019_377,
+                "movie": trakt_fixtures::json(trakt_fixtures::MOVIE)
+            },
+            {
+                "type": "person",
+                "score": 8.5,
+                "person": { "name": "Tron Guy", "ids": { "trakt": 1, "slug": "tron-guy" } }
+            }
+        ]);
+        let response = trakt_fixtures::paginated(body.to_string(), 2, 2, 3, 6);
+        let response = text_query::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.next_page(), Some(Pagination::new(3, 2)));
+
+        let results = response.items.items();
+        assert!(results[0].is_exact_title_match(" tron: legacy"));
+        assert!(!results[0].is_exact_title_match("tron"));
+        assert!(matches!(&results[1].item, Item::Person { person } if person.name == "Tron Guy"));
+        assert!(results[0].score > results[1].score);
+        assert_eq!(
+            results.iter().max_by_key(|r| r.score).unwrap().score,
+            Some(Score(26.019_377))
+        );
     }
 
     #[test]
@@ -256,13 +411,73 @@ mod tests {
         );
 
         let req = id_lookup::Request {
-            id: Id::Slug("slug".into()),
+            id: Id::Tmdb(2),
+            tp: SearchType::MOVIE,
+            pagination: Pagination::default(),
+        };
+        assert_eq!(
+            req.uri(CTX).unwrap(),
+            "https://api.trakt.tv/search/tmdb/2?type=movie&page=1&limit=10"
+        );
+
+        let req = id_lookup::Request {
+            id: Id::Slug("slug".parse().unwrap()),
             tp: SearchType::PERSON,
             pagination: Pagination::default(),
         };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
         assert!(matches!(
             req.try_into_http_request::<Vec<u8>>(CTX),
             Err(IntoHttpError::Validation(_))
         ));
+
+        let req = id_lookup::Request {
+            id: Id::Trakt(1),
+            tp: SearchType::MOVIE,
+            pagination: Pagination::new(1, 0),
+        };
+        assert!(matches!(
+            req.try_into_http_request::<Vec<u8>>(CTX),
+            Err(IntoHttpError::Pagination(_))
+        ));
+    }
+
+    #[test]
+    fn test_id_lookup_batch() {
+        let ids = [Id::Trakt(1), Id::Tmdb(2), Id::Imdb("tt0000003".into())];
+        let reqs =
+            id_lookup::batch::<Vec<u8>, _>(CTX, ids, SearchType::MOVIE, Pagination::new(1, 5))
+                .unwrap();
+
+        let urls = reqs.iter().map(|r| r.uri().to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            [
+                "https://api.trakt.tv/search/trakt/1?type=movie&page=1&limit=5",
+                "https://api.trakt.tv/search/tmdb/2?type=movie&page=1&limit=5",
+                "https://api.trakt.tv/search/imdb/tt0000003?type=movie&page=1&limit=5",
+            ]
+        );
+
+        let ids = [Id::Trakt(1), Id::Slug("slug".parse().unwrap())];
+        assert!(matches!(
+            id_lookup::batch::<Vec<u8>, _>(CTX, ids, SearchType::MOVIE, Pagination::default()),
+            Err(IntoHttpError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_id_lookup_collate() {
+        use trakt_fixtures::{paginated, SEARCH_MOVIE};
+
+        let response = |body: &'static str| paginated(body, 1, 10, 1, 1);
+
+        let responses = id_lookup::collate([response(SEARCH_MOVIE), response("[]")]).unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].items.items().len(), 1);
+        assert!(responses[1].items.items().is_empty());
+
+        let bad = http::Response::builder().status(404).body("").unwrap();
+        assert!(id_lookup::collate([response(SEARCH_MOVIE), bad]).is_err());
     }
 }
@@ -0,0 +1,614 @@
+//! Seasons
+//!
+//! Endpoints of a show's seasons. Seasons are identified by the id of their show and their number,
+//! where `0` is the specials season.
+//!
+//! <https://trakt.docs.apiary.io/#reference/seasons>
+
+pub mod summary {
+    //! Get all seasons for a show
+    //!
+    //! Use [`Extended::EPISODES`] to include the episodes of each season.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/summary/get-all-seasons-for-a-show>
+
+    use crate::smo::{Extended, Id, Season};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390), extended: trakt_rs::smo::Extended::EPISODES }",
+        url = "https://api.trakt.tv/shows/1390/seasons?extended=episodes",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<Season>);
+}
+
+pub mod info {
+    //! Get a single season for a show
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/season/get-single-season-for-a-show>
+
+    use crate::smo::{Extended, Id, Season};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/info",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Season);
+}
+
+pub mod episodes {
+    //! Get all episodes for a single season
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/episodes/get-all-episodes-for-a-single-season>
+
+    use crate::smo::{Episode, Extended, Id, Language, Translated};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}",
+    example(
+        request = r#"Request { id: trakt_rs::smo::Id::Trakt(1390), season: 1, translations: Some(trakt_rs::smo::Language::new("es")), extended: trakt_rs::smo::Extended::empty() }"#,
+        url = "https://api.trakt.tv/shows/1390/seasons/1?translations=es",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        /// Include the translations in this language with each episode.
+        pub translations: Option<Language>,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<Translated<Episode>>);
+}
+
+pub mod translations {
+    //! Get all season translations
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/translations/get-all-season-translations>
+
+    pub use crate::api::shows::translation::{Response, ResponseItem};
+    use crate::smo::{Id, Language};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/translations/{language}",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub language: Language,
+    }
+}
+
+pub mod comments {
+    //! Get all top level comments for a season
+    //!
+    //! If oauth is provided, comments from blocked users will be filtered out.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/comments/get-all-season-comments>
+
+    use trakt_core::Pagination;
+
+    pub use crate::api::shows::comments::Response;
+    use crate::smo::{Id, Sort};
+
+    /// Sort and filters left as `None` use the API's defaults.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/comments/{sort}",
+    example(
+        request = "Request::new(trakt_rs::smo::Id::Trakt(1390), 1)",
+        url = "https://api.trakt.tv/shows/1390/seasons/1/comments?page=1&limit=10",
+    ),
+    auth = Optional,
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        /// Sort order, omitted from the path if `None`.
+        pub sort: Option<Sort>,
+        pub include_replies: Option<bool>,
+        pub spoiler: Option<bool>,
+        pub review: Option<bool>,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    impl Request {
+        /// Creates a request for the comments of a season of the show `id`.
+        #[must_use]
+        pub fn new(id: Id, season: u16) -> Self {
+            Self {
+                id,
+                season,
+                sort: None,
+                include_replies: None,
+                spoiler: None,
+                review: None,
+                pagination: Pagination::default(),
+            }
+        }
+    }
+}
+
+pub mod lists {
+    //! Get lists containing this season
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/lists/get-lists-containing-this-season>
+
+    use bytes::BufMut;
+    use serde::Serialize;
+    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination};
+
+    pub use crate::api::shows::lists::{Response, Sort, Type};
+    use crate::{api::shows::lists::Segments, smo::Id};
+
+    /// A type or sort left as `None` uses the API's defaults, like [`shows::lists`]. A `sort`
+    /// given without a type is sent with [`Type::All`].
+    ///
+    /// [`shows::lists`]: crate::api::shows::lists
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        pub tp: Option<Type>,
+        pub sort: Option<Sort>,
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct RequestPathParams {
+        id: Id,
+        season: u16,
+        #[serde(flatten)]
+        segments: Segments,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/shows/{id}/seasons/{season}/lists/{tp}/{sort}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!())
+        .with_optional_params(&["tp", "sort"]);
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            self.pagination.validate()?;
+            let path = RequestPathParams {
+                id: self.id,
+                season: self.season,
+                segments: Segments::new(self.tp, self.sort),
+            };
+            trakt_core::construct_req(&ctx, &Self::METADATA, &path, &self.pagination, T::default())
+        }
+    }
+
+    impl Request {
+        /// Creates a request for all lists containing a season of the show `id`.
+        #[must_use]
+        pub fn new(id: Id, season: u16) -> Self {
+            Self {
+                id,
+                season,
+                tp: None,
+                sort: None,
+                pagination: Pagination::default(),
+            }
+        }
+    }
+}
+
+pub mod people {
+    //! Get all people for a season
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/people/get-all-people-for-a-season>
+
+    use serde::Deserialize;
+
+    pub use crate::api::movies::people::{Character, Crew, CrewMember};
+    use crate::smo::{Extended, Id};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/people",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390), season: 1, extended: trakt_rs::smo::Extended::empty() }",
+        url = "https://api.trakt.tv/shows/1390/seasons/1/people",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+        /// Use [`Extended::GUEST_STARS`] to include the guest stars.
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, trakt_macros::Response)]
+    pub struct Response {
+        pub cast: Vec<Character>,
+        #[serde(default)]
+        pub crew: Crew,
+        /// Only present with [`Extended::GUEST_STARS`].
+        #[serde(default)]
+        pub guest_stars: Vec<Character>,
+    }
+}
+
+pub mod ratings {
+    //! Get season ratings
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/ratings/get-season-ratings>
+
+    pub use crate::api::shows::ratings::Response;
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/ratings",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390), season: 1 }",
+        url = "https://api.trakt.tv/shows/1390/seasons/1/ratings",
+    ),
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+    }
+}
+
+pub mod stats {
+    //! Get season stats
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/stats/get-season-stats>
+
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/stats",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize, trakt_macros::Response)]
+    pub struct Response {
+        pub watchers: u64,
+        pub plays: u64,
+        pub collectors: u64,
+        pub collected_episodes: u64,
+        pub comments: u64,
+        pub lists: u64,
+        pub votes: u64,
+    }
+}
+
+pub mod watching {
+    //! Get users watching a season right now
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/watching/get-users-watching-right-now>
+
+    pub use crate::api::shows::watching::Response;
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/watching",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+    }
+}
+
+pub mod videos {
+    //! Get all videos for a season
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/seasons/videos/get-all-videos>
+
+    pub use crate::api::shows::videos::Response;
+    use crate::smo::Id;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/seasons/{season}/videos",
+    )]
+    pub struct Request {
+        pub id: Id,
+        pub season: u16,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{Context, Pagination, Request as _, Response as _};
+
+    use super::*;
+    use crate::{
+        smo::{Extended, Id, Language},
+        test::assert_request,
+    };
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_seasons_summary() {
+        use trakt_core::Response as _;
+
+        let req = summary::Request {
+            id: Id::Trakt(1),
+            extended: Extended::empty(),
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/shows/1/seasons", "");
+
+        let req = summary::Request {
+            id: Id::Trakt(1),
+            extended: Extended::EPISODES,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1/seasons?extended=episodes",
+            "",
+        );
+
+        let req = summary::Request {
+            id: Id::Trakt(1),
+            extended: Extended::FULL | Extended::EPISODES,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1/seasons?extended=full%2Cepisodes",
+            "",
+        );
+
+        let body = json!([
+            {
+                "number": 0,
+                "ids": { "trakt": 1, "tvdb": 137_481, "tmdb": 3627 }
+            },
+            {
+                "number": 1,
+                "ids": { "trakt": 2, "tvdb": 364_731, "tmdb": 3624 },
+                "title": "Season 1",
+                "episode_count": 10,
+                "aired_episodes": 10,
+                "first_aired": "2011-04-18T01:00:00Z",
+                "network": "HBO",
+                "episodes": [
+                    {
+                        "season": 1,
+                        "number": 1,
+                        "title": "Winter Is Coming",
+                        "ids": {
+                            "trakt": 73640,
+                            "tvdb": 3_254_641,
+                            "imdb": "tt1480055",
+                            "tmdb": 63056
+                        }
+                    }
+                ]
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = summary::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 2);
+        assert!(response.0[0].episodes.is_none());
+        assert!(response.0[0].is_special());
+        assert!(!response.0[1].is_special());
+        assert_eq!(response.0[1].episode_count, Some(10));
+        assert_eq!(
+            response.0[1].first_aired,
+            Some(time::macros::datetime!(2011-04-18 01:00 UTC))
+        );
+        let episodes = response.0[1].episodes.as_ref().unwrap();
+        assert_eq!(episodes.len(), 1);
+        assert_eq!(episodes[0].title, "Winter Is Coming");
+    }
+
+    #[test]
+    fn test_season_translations_videos() {
+        use crate::smo::Language;
+
+        let req = translations::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            language: Language::new("es"),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/translations/es",
+            "",
+        );
+
+        let req = videos::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/videos",
+            "",
+        );
+    }
+
+    #[test]
+    fn test_season_info_episodes() {
+        let req = info::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            extended: Extended::FULL,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/info?extended=full",
+            "",
+        );
+
+        let req = episodes::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            translations: Some(Language::new("es")),
+            extended: Extended::empty(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1?translations=es",
+            "",
+        );
+
+        let mut episode = trakt_fixtures::json(trakt_fixtures::EPISODE);
+        episode["translations"] = json!([
+            { "title": "Piloto", "overview": "Walter White...", "language": "es" }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(json!([episode]).to_string())
+            .unwrap();
+        let response = episodes::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0[0].title, "Pilot");
+        assert_eq!(response.0[0].translations.len(), 1);
+    }
+
+    #[test]
+    fn test_season_lists_watching() {
+        let req = lists::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            tp: Some(lists::Type::Official),
+            sort: None,
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/lists/official?page=1&limit=10",
+            "",
+        );
+
+        let mut req = lists::Request::new(Id::Trakt(1390), 1);
+        req.sort = Some(lists::Sort::Likes);
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/lists/all/likes?page=1&limit=10",
+            "",
+        );
+        assert_eq!(lists::Request::METADATA.name(), "seasons.lists");
+
+        let req = watching::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/watching",
+            "",
+        );
+    }
+
+    #[test]
+    fn test_season_people_stats() {
+        let req = people::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            extended: Extended::GUEST_STARS,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/people?extended=guest_stars",
+            "",
+        );
+
+        let person = json!({ "name": "Sean Bean", "ids": { "trakt": 1, "slug": "sean-bean" } });
+        let body = json!({
+            "cast": [{ "characters": ["Eddard Stark"], "episode_count": 9, "person": person }],
+            "crew": { "directing": [{ "jobs": ["Director"], "episode_count": 2, "person": person }] },
+            "guest_stars": [{ "character": "Jon Arryn", "person": person }]
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = people::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.cast[0].characters, ["Eddard Stark"]);
+        assert_eq!(response.crew.directing.len(), 1);
+        assert!(response.crew.writing.is_empty());
+        assert_eq!(response.guest_stars[0].characters, ["Jon Arryn"]);
+
+        let req = stats::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/stats",
+            "",
+        );
+        let body = json!({
+            "watchers": 30521,
+            "plays": 37986,
+            "collectors": 11_683,
+            "collected_episodes": 10,
+            "comments": 115,
+            "lists": 221,
+            "votes": 3640
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = stats::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.collected_episodes, 10);
+    }
+}
@@ -2,16 +2,27 @@
 //!
 //! <https://trakt.docs.apiary.io/#reference/shows>
 
+use serde::Serialize;
+
+/// Activity used to determine the last episode of a show's progress.
+///
+/// [`collection_progress`] supports [`LastActivity::Aired`] and [`LastActivity::Collected`], while
+/// [`watched_progress`] supports [`LastActivity::Aired`] and [`LastActivity::Watched`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LastActivity {
+    Aired,
+    Collected,
+    Watched,
+}
+
 pub mod trending {
     //! Get trending shows
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/trending/get-trending-shows>
     use http::StatusCode;
     use serde::Deserialize;
-    use trakt_core::{
-        error::FromHttpError, handle_response_body, parse_from_header, Pagination,
-        PaginationResponse,
-    };
+    use trakt_core::{error::FromHttpError, handle_response, Pagination, PaginationResponse};
 
     use crate::smo::Show;
 
@@ -19,6 +30,10 @@ pub mod trending {
     #[trakt(
     response = Response,
     endpoint = "/shows/trending",
+    example(
+        request = "Request::default()",
+        url = "https://api.trakt.tv/shows/trending?page=1&limit=10",
+    ),
     )]
     pub struct Request {
         #[serde(flatten)]
@@ -42,17 +57,15 @@ pub mod trending {
         fn try_from_http_response<T: AsRef<[u8]>>(
             response: http::Response<T>,
         ) -> Result<Self, FromHttpError> {
-            let body = handle_response_body(&response, StatusCode::OK)?;
-            let items = PaginationResponse::from_headers(body, response.headers())?;
+            let (body, ctx) = handle_response(&response, StatusCode::OK)?;
             Ok(Self {
-                items,
-                trending_user_count: parse_from_header(
-                    response.headers(),
-                    "X-Trending-User-Count",
-                )?,
+                items: ctx.paginated(body)?,
+                trending_user_count: ctx.header("X-Trending-User-Count")?,
             })
         }
     }
+
+    trakt_core::impl_try_from_response!(Response);
 }
 
 pub mod popular {
@@ -95,14 +108,20 @@ pub mod favorited {
 
     use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::Show;
+    use crate::smo::{Period, Show};
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
-    endpoint = "/shows/favorited",
+    endpoint = "/shows/favorited/{period}",
+    example(
+        request = "Request { period: Some(trakt_rs::smo::Period::Monthly), ..Default::default() }",
+        url = "https://api.trakt.tv/shows/favorited/monthly?page=1&limit=10",
+    ),
     )]
     pub struct Request {
+        /// Time period to get favorites for. Trakt defaults to [`Period::Weekly`] if `None`.
+        pub period: Option<Period>,
         #[serde(flatten)]
         pub pagination: Pagination,
     }
@@ -128,12 +147,16 @@ pub mod played {
 
     use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{Period, Show};
+    use crate::smo::{Period, PlayStats, Show};
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/shows/played/{period}",
+    example(
+        request = "Request::default()",
+        url = "https://api.trakt.tv/shows/played/weekly?page=1&limit=10",
+    ),
     )]
     pub struct Request {
         pub period: Period,
@@ -148,14 +171,7 @@ pub mod played {
         pub items: PaginationResponse<ResponseItem>,
     }
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub watcher_count: u64,
-        pub play_count: u64,
-        pub collected_count: u64,
-        pub collector_count: u64,
-        pub show: Show,
-    }
+    pub type ResponseItem = PlayStats<Show>;
 }
 
 pub mod watched {
@@ -164,7 +180,7 @@ pub mod watched {
     //! <https://trakt.docs.apiary.io/#reference/shows/watched/get-the-most-watched-shows>
     use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{Period, Show};
+    use crate::smo::{Period, PlayStats, Show};
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
@@ -183,14 +199,7 @@ pub mod watched {
         pub items: PaginationResponse<ResponseItem>,
     }
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub watcher_count: u64,
-        pub play_count: u64,
-        pub collected_count: u64,
-        pub collector_count: u64,
-        pub show: Show,
-    }
+    pub type ResponseItem = PlayStats<Show>;
 }
 
 pub mod collected {
@@ -199,12 +208,12 @@ pub mod collected {
     //! <https://trakt.docs.apiary.io/#reference/shows/watched/get-the-most-collected-shows>
     use trakt_core::{Pagination, PaginationResponse};
 
-    use crate::smo::{Period, Show};
+    use crate::smo::{Period, PlayStats, Show};
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
     #[trakt(
     response = Response,
-    endpoint = "/shows/collected",
+    endpoint = "/shows/collected/{period}",
     )]
     pub struct Request {
         pub period: Period,
@@ -218,14 +227,7 @@ pub mod collected {
         pub items: PaginationResponse<ResponseItem>,
     }
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub watcher_count: u64,
-        pub play_count: u64,
-        pub collector_count: u64,
-        pub collected_count: u64,
-        pub show: Show,
-    }
+    pub type ResponseItem = PlayStats<Show>;
 }
 
 pub mod anticipated {
@@ -273,9 +275,13 @@ pub mod updates {
     #[trakt(
     response = Response,
     endpoint = "/shows/updates/{start_date}",
+    example(
+        request = "Request { start_date: time::macros::datetime!(2014-09-01 00:00 UTC), pagination: Default::default() }",
+        url = "https://api.trakt.tv/shows/updates/2014-09-01T00:00:00.000Z?page=1&limit=10",
+    ),
     )]
     pub struct Request {
-        #[serde(with = "time::serde::iso8601")]
+        #[serde(with = "crate::datetime")]
         pub start_date: OffsetDateTime,
         #[serde(flatten)]
         pub pagination: Pagination,
@@ -289,6 +295,7 @@ pub mod updates {
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
     pub struct ResponseItem {
+        #[serde(with = "crate::datetime")]
         pub updated_at: OffsetDateTime,
         pub show: Show,
     }
@@ -300,7 +307,7 @@ pub mod updates_id {
     //! <https://trakt.docs.apiary.io/#reference/shows/updates/get-recently-updated-show-trakt-ids>
 
     use time::OffsetDateTime;
-    use trakt_core::Pagination;
+    use trakt_core::{Pagination, PaginationResponse};
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
@@ -308,14 +315,14 @@ pub mod updates_id {
     endpoint = "/shows/updates/id/{start_date}",
     )]
     pub struct Request {
-        #[serde(with = "time::serde::iso8601")]
+        #[serde(with = "crate::datetime")]
         pub start_date: OffsetDateTime,
         #[serde(flatten)]
         pub pagination: Pagination,
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Vec<u64>);
+    pub struct Response(#[trakt(pagination)] pub PaginationResponse<u64>);
 }
 
 pub mod summary {
@@ -323,19 +330,36 @@ pub mod summary {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/summary/get-a-single-show>
 
-    use crate::smo::{Id, Show};
+    use crate::smo::{Id, Language, Show, Translated};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/shows/{id}",
+    example(
+        request = r#"Request::new(trakt_rs::smo::Id::Slug("breaking-bad".parse().unwrap()))"#,
+        url = "https://api.trakt.tv/shows/breaking-bad",
+    ),
     )]
     pub struct Request {
         pub id: Id,
+        /// Include the translations in this language in the response.
+        pub translations: Option<Language>,
+    }
+
+    impl Request {
+        /// Creates a request for the show `id` without translations.
+        #[must_use]
+        pub const fn new(id: Id) -> Self {
+            Self {
+                id,
+                translations: None,
+            }
+        }
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Show);
+    pub struct Response(pub Translated<Show>);
 }
 
 pub mod aliases {
@@ -343,7 +367,7 @@ pub mod aliases {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/aliases/get-all-show-aliases>
 
-    use crate::smo::{Country, Id};
+    use crate::smo::{Alias, Id};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
@@ -355,13 +379,7 @@ pub mod aliases {
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Vec<ResponseItem>);
-
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub title: String,
-        pub country: Country,
-    }
+    pub struct Response(pub Vec<Alias>);
 }
 
 pub mod certifications {
@@ -369,7 +387,7 @@ pub mod certifications {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/certifications/get-all-show-certifications>
 
-    use crate::smo::{Country, Id};
+    use crate::smo::{CountryCertification, Id};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
@@ -383,11 +401,7 @@ pub mod certifications {
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response(pub Vec<ResponseItem>);
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub certification: String,
-        pub country: Country,
-    }
+    pub type ResponseItem = CountryCertification;
 }
 
 pub mod translation {
@@ -395,12 +409,16 @@ pub mod translation {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/certifications/get-all-show-translations>
 
-    use crate::smo::{Country, Id, Language};
+    use crate::smo::{CountryTranslation, Id, Language};
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/shows/{id}/translations/{language}",
+    example(
+        request = r#"Request { id: trakt_rs::smo::Id::Trakt(1390), language: trakt_rs::smo::Language::new("es") }"#,
+        url = "https://api.trakt.tv/shows/1390/translations/es",
+    ),
     )]
     pub struct Request {
         pub id: Id,
@@ -410,14 +428,7 @@ pub mod translation {
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
     pub struct Response(pub Vec<ResponseItem>);
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
-    pub struct ResponseItem {
-        pub title: String,
-        pub overview: String,
-        pub tagline: Option<String>,
-        pub language: Language,
-        pub country: Country,
-    }
+    pub type ResponseItem = CountryTranslation;
 }
 
 pub mod comments {
@@ -427,19 +438,45 @@ pub mod comments {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/translations/get-all-show-comments>
 
-    use trakt_core::PaginationResponse;
+    use trakt_core::{Pagination, PaginationResponse};
 
     use crate::smo::{Comment, Id, Sort};
 
+    /// Sort and filters left as `None` use the API's defaults.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/shows/{id}/comments/{sort}",
+    example(
+        request = "Request { sort: Some(trakt_rs::smo::Sort::Newest), ..Request::new(trakt_rs::smo::Id::Trakt(1390)) }",
+        url = "https://api.trakt.tv/shows/1390/comments/newest?page=1&limit=10",
+    ),
     auth = Optional,
     )]
     pub struct Request {
-        id: Id,
-        sort: Sort,
+        pub id: Id,
+        /// Sort order, omitted from the path if `None`.
+        pub sort: Option<Sort>,
+        pub include_replies: Option<bool>,
+        pub spoiler: Option<bool>,
+        pub review: Option<bool>,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    impl Request {
+        /// Creates a request for the comments of the show `id`.
+        #[must_use]
+        pub fn new(id: Id) -> Self {
+            Self {
+                id,
+                sort: None,
+                include_replies: None,
+                spoiler: None,
+                review: None,
+                pagination: Pagination::default(),
+            }
+        }
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
@@ -454,25 +491,87 @@ pub mod lists {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/lists/get-lists-containing-this-show>
 
+    use bytes::BufMut;
     use serde::Serialize;
-    use trakt_core::{Pagination, PaginationResponse};
+    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
 
     use crate::smo::{Id, List};
 
-    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
-    #[trakt(
-    response = Response,
-    endpoint = "/shows/{id}/lists/{tp}/{sort}"
-    )]
+    /// A type or sort left as `None` uses the API's defaults, [`Type::All`] and
+    /// [`Sort::Popular`]. A `sort` given without a type is sent with [`Type::All`].
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
     pub struct Request {
         pub id: Id,
         pub tp: Option<Type>,
         pub sort: Option<Sort>,
-        #[serde(flatten)]
         pub pagination: Pagination,
     }
 
+    /// `{tp}/{sort}` path segments of the lists containing an item, also used by the lists of
+    /// seasons, episodes and people.
+    ///
+    /// The `{tp}` segment can't be omitted before `{sort}`, so [`Type::All`] is filled in for a
+    /// `sort` given without a type.
+    #[derive(Debug, Serialize)]
+    pub(crate) struct Segments {
+        tp: Option<Type>,
+        sort: Option<Sort>,
+    }
+
+    impl Segments {
+        pub(crate) fn new(tp: Option<Type>, sort: Option<Sort>) -> Self {
+            Self {
+                tp: tp.or_else(|| sort.map(|_| Type::All)),
+                sort,
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize)]
+    struct RequestPathParams {
+        id: Id,
+        #[serde(flatten)]
+        segments: Segments,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/shows/{id}/lists/{tp}/{sort}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::None,
+        )
+        .with_name(trakt_core::endpoint_name!())
+        .with_optional_params(&["tp", "sort"]);
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            self.pagination.validate()?;
+            let path = RequestPathParams {
+                id: self.id,
+                segments: Segments::new(self.tp, self.sort),
+            };
+            trakt_core::construct_req(&ctx, &Self::METADATA, &path, &self.pagination, T::default())
+        }
+    }
+
+    impl Request {
+        /// Creates a request for all lists containing the show `id`.
+        #[must_use]
+        pub fn new(id: Id) -> Self {
+            Self {
+                id,
+                tp: None,
+                sort: None,
+                pagination: Pagination::default(),
+            }
+        }
+    }
+
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+    #[serde(rename_all = "lowercase")]
     pub enum Type {
         #[default]
         All,
@@ -506,8 +605,11 @@ pub mod collection_progress {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/collection-progress/get-show-collection-progress>
 
+    use super::LastActivity;
     use crate::smo::{Episode, Id, Season};
 
+    /// [`Request::new`] uses the API's defaults: hidden seasons and specials are left out,
+    /// but specials count towards the progress.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
@@ -519,13 +621,28 @@ pub mod collection_progress {
         pub hidden: bool,
         pub specials: bool,
         pub count_specials: bool,
+        pub last_activity: Option<LastActivity>,
+    }
+
+    impl Request {
+        /// Creates a request for the collection progress of the show `id`.
+        #[must_use]
+        pub const fn new(id: Id) -> Self {
+            Self {
+                id,
+                hidden: false,
+                specials: false,
+                count_specials: true,
+                last_activity: None,
+            }
+        }
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize, trakt_macros::Response)]
     pub struct Response {
         pub aired: u64,
         pub completed: u64,
-        #[serde(with = "time::serde::iso8601::option")]
+        #[serde(with = "crate::datetime::option")]
         pub last_collected_at: Option<time::OffsetDateTime>,
         pub seasons: Vec<SeasonCollection>,
         pub hidden_seasons: Vec<Season>,
@@ -546,7 +663,7 @@ pub mod collection_progress {
     pub struct EpisodeCollection {
         pub number: u64,
         pub completed: bool,
-        #[serde(with = "time::serde::iso8601::option")]
+        #[serde(with = "crate::datetime::option")]
         pub collected_at: Option<time::OffsetDateTime>,
     }
 }
@@ -556,12 +673,19 @@ pub mod watched_progress {
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/collection-progress/get-show-watched-progress>
 
+    use super::LastActivity;
     use crate::smo::{Episode, Id, Season};
 
+    /// [`Request::new`] uses the API's defaults: hidden seasons and specials are left out,
+    /// but specials count towards the progress.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
     #[trakt(
     response = Response,
     endpoint = "/shows/{id}/progress/watched",
+    example(
+        request = "Request::new(trakt_rs::smo::Id::Trakt(1390))",
+        url = "https://api.trakt.tv/shows/1390/progress/watched?hidden=false&specials=false&count_specials=true",
+    ),
     auth = Required,
     )]
     pub struct Request {
@@ -569,13 +693,28 @@ pub mod watched_progress {
         pub hidden: bool,
         pub specials: bool,
         pub count_specials: bool,
+        pub last_activity: Option<LastActivity>,
+    }
+
+    impl Request {
+        /// Creates a request for the watched progress of the show `id`.
+        #[must_use]
+        pub const fn new(id: Id) -> Self {
+            Self {
+                id,
+                hidden: false,
+                specials: false,
+                count_specials: true,
+                last_activity: None,
+            }
+        }
     }
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize, trakt_macros::Response)]
     pub struct Response {
         pub aired: u64,
         pub completed: u64,
-        #[serde(with = "time::serde::iso8601::option")]
+        #[serde(with = "crate::datetime::option")]
         pub last_watched_at: Option<time::OffsetDateTime>,
         pub seasons: Vec<SeasonWatched>,
         pub hidden_seasons: Vec<Season>,
@@ -596,7 +735,7 @@ pub mod watched_progress {
     pub struct EpisodeWatched {
         pub number: u64,
         pub completed: bool,
-        #[serde(with = "time::serde::iso8601::option")]
+        #[serde(with = "crate::datetime::option")]
         pub last_watched_at: Option<time::OffsetDateTime>,
     }
 }
@@ -629,7 +768,7 @@ pub mod reset {
             Debug, Copy, Clone, Eq, PartialEq, Hash, serde::Deserialize, trakt_macros::Response,
         )]
         pub struct Response {
-            #[serde(with = "time::serde::iso8601")]
+            #[serde(with = "crate::datetime")]
             pub reset_at: time::OffsetDateTime,
         }
     }
@@ -697,9 +836,14 @@ pub mod related {
     #[trakt(
     response = Response,
     endpoint = "/shows/{id}/related",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390), pagination: Default::default() }",
+        url = "https://api.trakt.tv/shows/1390/related?page=1&limit=10",
+    ),
     )]
     pub struct Request {
         pub id: Id,
+        #[serde(flatten)]
         pub pagination: Pagination,
     }
 
@@ -739,7 +883,7 @@ pub mod stats {
     }
 }
 
-pub mod studio {
+pub mod studios {
     //! Get show studios
     //!
     //! <https://trakt.docs.apiary.io/#reference/shows/stats/get-show-studios>
@@ -759,6 +903,12 @@ pub mod studio {
     pub struct Response(pub Vec<Studio>);
 }
 
+/// Renamed to [`studios`] to match the endpoint.
+#[deprecated(note = "use `studios` instead")]
+pub mod studio {
+    pub use super::studios::*;
+}
+
 pub mod watching {
     //! Get users watching a show right now
     //!
@@ -779,6 +929,26 @@ pub mod watching {
     pub struct Response(pub Vec<User>);
 }
 
+pub mod videos {
+    //! Get all videos for a show
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/shows/videos/get-all-videos>
+
+    use crate::smo::{Id, Video};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/shows/{id}/videos",
+    )]
+    pub struct Request {
+        pub id: Id,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<Video>);
+}
+
 pub mod next_episode {
     //! Get next scheduled to air episode
     //!
@@ -790,13 +960,19 @@ pub mod next_episode {
     #[trakt(
     response = Response,
     endpoint = "/shows/{id}/next_episode",
+    example(
+        request = "Request { id: trakt_rs::smo::Id::Trakt(1390) }",
+        url = "https://api.trakt.tv/shows/1390/next_episode",
+    ),
     )]
     pub struct Request {
         pub id: Id,
     }
 
+    /// The next episode, or `None` if there is no scheduled episode.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Episode);
+    #[trakt(empty = NO_CONTENT)]
+    pub struct Response(pub Option<Episode>);
 }
 
 pub mod last_episode {
@@ -815,17 +991,30 @@ pub mod last_episode {
         pub id: Id,
     }
 
+    /// The last episode, or `None` if no episode has aired yet.
     #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
-    pub struct Response(pub Episode);
+    #[trakt(empty = NO_CONTENT)]
+    pub struct Response(pub Option<Episode>);
 }
 
+/// Moved to [`api::episodes`](super::episodes), which covers all episode endpoints.
+#[cfg(feature = "episodes")]
+pub use super::episodes;
+/// Moved to [`api::seasons`](super::seasons), which covers all season endpoints.
+#[cfg(feature = "seasons")]
+pub use super::seasons;
+
 #[cfg(test)]
 mod tests {
     use httpmock::prelude::*;
     use serde_json::json;
-    use trakt_core::{Context, PaginatedResponse, Request};
+    use trakt_core::{Context, PaginatedResponse, Pagination, Request};
 
     use super::*;
+    use crate::{
+        smo::{Alias, AliasTitle, Country, Id, Period},
+        test::assert_request,
+    };
 
     #[test]
     fn test_trending() {
@@ -898,4 +1087,404 @@ mod tests {
 
         trending_mock.assert();
     }
+
+    #[test]
+    fn test_next_episode() {
+        use trakt_core::Response as _;
+
+        let response = http::Response::builder()
+            .status(200)
+            .body(
+                json!({
+                    "season": 7,
+                    "number": 4,
+                    "title": "The Bear and the Maiden Fair",
+                    "ids": {
+                        "trakt": 36440,
+                        "tvdb": 4_517_466,
+                        "imdb": "tt2178800",
+                        "tmdb": 63061
+                    }
+                })
+                .to_string(),
+            )
+            .unwrap();
+        let response = next_episode::Response::try_from_http_response(response).unwrap();
+        let episode = response.0.unwrap();
+        assert_eq!(episode.season, 7);
+        assert_eq!(episode.number, 4);
+        assert_eq!(episode.ids.trakt, Some(36440));
+
+        let response = http::Response::builder().status(204).body("").unwrap();
+        let response = next_episode::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0, None);
+
+        let response = http::Response::builder().status(404).body("").unwrap();
+        assert!(next_episode::Response::try_from_http_response(response).is_err());
+    }
+
+    #[test]
+    fn test_last_episode() {
+        use trakt_core::Response as _;
+
+        let response = http::Response::builder()
+            .status(200)
+            .body(
+                json!({
+                    "season": 1,
+                    "number": 10,
+                    "title": "Fire and Blood",
+                    "ids": {
+                        "trakt": 73649,
+                        "tvdb": 4_077_554,
+                        "imdb": "tt1851397",
+                        "tmdb": 63065
+                    }
+                })
+                .to_string(),
+            )
+            .unwrap();
+        let response = last_episode::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.unwrap().title, "Fire and Blood");
+
+        let response = http::Response::builder().status(204).body("").unwrap();
+        let response = last_episode::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0, None);
+    }
+
+    #[test]
+    fn test_aliases() {
+        use trakt_core::Response as _;
+
+        let body = json!([
+            { "title": "Breaking Bad", "country": "us" },
+            { "title": "Reazione a catena", "country": "it" }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = aliases::Response::try_from_http_response(response).unwrap();
+        assert_eq!(
+            response.0,
+            [
+                Alias::new(
+                    AliasTitle {
+                        title: "Breaking Bad".into(),
+                    },
+                    Country::new("us"),
+                ),
+                Alias::new(
+                    AliasTitle {
+                        title: "Reazione a catena".into(),
+                    },
+                    Country::new("it"),
+                ),
+            ]
+        );
+
+        let body = json!([{ "title": "Breaking Bad", "country": "usa" }]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        assert!(aliases::Response::try_from_http_response(response).is_err());
+    }
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "abc",
+        oauth_token: None,
+    };
+
+    #[test]
+    fn test_favorited_request() {
+        let req = favorited::Request::default();
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/favorited?page=1&limit=10",
+            "",
+        );
+
+        let req = favorited::Request {
+            period: Some(Period::All),
+            pagination: Pagination::new(2, 20),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/favorited/all?page=2&limit=20",
+            "",
+        );
+    }
+
+    #[test]
+    fn test_updates() {
+        use time::macros::datetime;
+        use trakt_core::Response as _;
+
+        let body = json!([
+            {
+                "updated_at": "2014-09-22T21:56:03.000Z",
+                "show": {
+                    "title": "Breaking Bad",
+                    "year": 2008,
+                    "ids": { "trakt": 1, "slug": "breaking-bad" }
+                }
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .header("X-Pagination-Page", "1")
+            .header("X-Pagination-Limit", "10")
+            .header("X-Pagination-Page-Count", "1")
+            .header("X-Pagination-Item-Count", "1")
+            .body(body.to_string())
+            .unwrap();
+        let response = updates::Response::try_from_http_response(response).unwrap();
+        let item = &response.items.items()[0];
+        assert_eq!(item.updated_at, datetime!(2014-09-22 21:56:03 UTC));
+        assert_eq!(item.show.title, "Breaking Bad");
+    }
+
+    #[test]
+    fn test_updates_id() {
+        use trakt_core::Response as _;
+
+        let response = http::Response::builder()
+            .status(200)
+            .header("X-Pagination-Page", "1")
+            .header("X-Pagination-Limit", "2")
+            .header("X-Pagination-Page-Count", "2")
+            .header("X-Pagination-Item-Count", "4")
+            .body(json!([1, 20]).to_string())
+            .unwrap();
+        let response = updates_id::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.items(), [1, 20]);
+        assert_eq!(response.0.total_items(), 4);
+        assert_eq!(response.next_page(), Some(Pagination::new(2, 2)));
+    }
+
+    #[test]
+    fn test_country_tagged() {
+        use trakt_core::Response as _;
+
+        let body = json!([
+            { "certification": "TV-MA", "country": "us" },
+            { "certification": "15", "country": "gb" }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = certifications::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0[0].certification, "TV-MA");
+        assert_eq!(response.0[1].country, Some(Country::new("gb")));
+
+        let body = json!([
+            {
+                "title": "Breaking Bad",
+                "overview": "Walter White, ein Chemielehrer...",
+                "tagline": null,
+                "language": "de",
+                "country": "de"
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = translation::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0[0].title.as_deref(), Some("Breaking Bad"));
+        assert_eq!(response.0[0].tagline, None);
+        assert_eq!(response.0[0].language, crate::smo::Language::new("de"));
+        assert_eq!(response.0[0].country, Some(Country::new("de")));
+    }
+
+    #[test]
+    fn test_progress_last_activity() {
+        let ctx = Context {
+            oauth_token: Some("token"),
+            ..CTX
+        };
+
+        let req = collection_progress::Request::new(Id::Trakt(1));
+        assert_request(
+            ctx,
+            req,
+            "https://api.trakt.tv/shows/1/progress/collection?hidden=false&specials=false&count_specials=true",
+            "",
+        );
+
+        let req = collection_progress::Request {
+            id: Id::Trakt(1),
+            hidden: false,
+            specials: false,
+            count_specials: true,
+            last_activity: Some(LastActivity::Collected),
+        };
+        assert_request(
+            ctx,
+            req,
+            "https://api.trakt.tv/shows/1/progress/collection?hidden=false&specials=false&count_specials=true&last_activity=collected",
+            "",
+        );
+
+        let req = watched_progress::Request {
+            id: Id::Trakt(1),
+            hidden: true,
+            specials: false,
+            count_specials: false,
+            last_activity: Some(LastActivity::Aired),
+        };
+        assert_request(
+            ctx,
+            req,
+            "https://api.trakt.tv/shows/1/progress/watched?hidden=true&specials=false&count_specials=false&last_activity=aired",
+            "",
+        );
+    }
+
+    #[test]
+    fn test_path_enums() {
+        let req = lists::Request {
+            id: Id::Trakt(1390),
+            tp: Some(lists::Type::Personal),
+            sort: Some(lists::Sort::Likes),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/lists/personal/likes?page=1&limit=10",
+            "",
+        );
+
+        let mut req = lists::Request::new(Id::Trakt(1390));
+        assert_request(
+            CTX,
+            req.clone(),
+            "https://api.trakt.tv/shows/1390/lists?page=1&limit=10",
+            "",
+        );
+        req.sort = Some(lists::Sort::Likes);
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/lists/all/likes?page=1&limit=10",
+            "",
+        );
+
+        let req = collected::Request {
+            period: Period::Monthly,
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/collected/monthly?page=1&limit=10",
+            "",
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "seasons", feature = "episodes"))]
+    fn test_comments() {
+        use crate::smo::Sort;
+
+        let req = comments::Request::new(Id::Trakt(1390));
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/comments?page=1&limit=10",
+            "",
+        );
+
+        let req = seasons::comments::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            sort: Some(Sort::Likes),
+            include_replies: Some(true),
+            spoiler: Some(false),
+            review: None,
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/comments/likes?include_replies=true&spoiler=false&page=1&limit=10",
+            "",
+        );
+
+        let req = episodes::comments::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            episode: 2,
+            sort: Some(Sort::Newest),
+            include_replies: None,
+            spoiler: None,
+            review: Some(true),
+            pagination: Pagination::new(2, 5),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/2/comments/newest?review=true&page=2&limit=5",
+            "",
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "seasons", feature = "episodes"))]
+    fn test_season_episode_ratings() {
+        use trakt_core::Response as _;
+
+        use crate::smo::Distribution;
+
+        let req = seasons::ratings::Request {
+            id: Id::Slug("game-of-thrones".parse().unwrap()),
+            season: 1,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/game-of-thrones/seasons/1/ratings",
+            "",
+        );
+
+        let req = episodes::ratings::Request {
+            id: Id::Trakt(1390),
+            season: 1,
+            episode: 2,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/shows/1390/seasons/1/episodes/2/ratings",
+            "",
+        );
+
+        let body = json!({
+            "rating": 9.0,
+            "votes": 3,
+            "distribution": { "1": 0, "2": 0, "3": 0, "4": 0, "5": 0, "6": 0, "7": 0, "8": 1, "9": 1, "10": 1 }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = episodes::ratings::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.votes, 3);
+        assert_eq!(response.0.distribution.0, [0, 0, 0, 0, 0, 0, 0, 1, 1, 1]);
+
+        // Items without votes may not have a distribution
+        let body = json!({ "rating": 0.0, "votes": 0 });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = seasons::ratings::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.distribution, Distribution::default());
+    }
 }
@@ -0,0 +1,2767 @@
+//! Sync endpoints
+//!
+//! <https://trakt.docs.apiary.io/#reference/sync>
+
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+use crate::smo::{Ids, MediaMetadata};
+
+/// Type of media in a `/sync/*/{type}` path.
+///
+/// Endpoints that also accept no type at all take an `Option<MediaType>`, where `None` requests
+/// items of every type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaType {
+    #[default]
+    Movies,
+    Shows,
+    Seasons,
+    Episodes,
+}
+
+/// Type of media in the `/{type}` path of sync endpoints that only list movies and shows, e.g.
+/// the collection.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemType {
+    #[default]
+    Movies,
+    Shows,
+}
+
+/// Items to add to or remove from a user's collection, history, watchlist, etc.
+///
+/// `E` contains the extra fields sent along with each item, e.g. `notes` or `watched_at`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct ItemsBody<E = ()> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub movies: Vec<BodyItem<E>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shows: Vec<ShowItem<E>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub seasons: Vec<BodyItem<E>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub episodes: Vec<BodyItem<E>>,
+}
+
+impl<E> Default for ItemsBody<E> {
+    fn default() -> Self {
+        Self {
+            movies: Vec::new(),
+            shows: Vec::new(),
+            seasons: Vec::new(),
+            episodes: Vec::new(),
+        }
+    }
+}
+
+impl<E> ItemsBody<E> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.movies.is_empty()
+            && self.shows.is_empty()
+            && self.seasons.is_empty()
+            && self.episodes.is_empty()
+    }
+
+    #[must_use]
+    pub fn movie(self, ids: impl Into<Ids>) -> Self
+    where
+        E: Default,
+    {
+        self.movie_with(ids, E::default())
+    }
+
+    #[must_use]
+    pub fn movie_with(mut self, ids: impl Into<Ids>, extra: E) -> Self {
+        self.movies.push(BodyItem::new(ids, extra));
+        self
+    }
+
+    #[must_use]
+    pub fn show(self, ids: impl Into<Ids>) -> Self
+    where
+        E: Default,
+    {
+        self.show_with(ids, E::default())
+    }
+
+    #[must_use]
+    pub fn show_with(mut self, ids: impl Into<Ids>, extra: E) -> Self {
+        self.shows.push(ShowItem::new(ids, extra));
+        self
+    }
+
+    #[must_use]
+    pub fn season(self, ids: impl Into<Ids>) -> Self
+    where
+        E: Default,
+    {
+        self.season_with(ids, E::default())
+    }
+
+    #[must_use]
+    pub fn season_with(mut self, ids: impl Into<Ids>, extra: E) -> Self {
+        self.seasons.push(BodyItem::new(ids, extra));
+        self
+    }
+
+    #[must_use]
+    pub fn episode(self, ids: impl Into<Ids>) -> Self
+    where
+        E: Default,
+    {
+        self.episode_with(ids, E::default())
+    }
+
+    #[must_use]
+    pub fn episode_with(mut self, ids: impl Into<Ids>, extra: E) -> Self {
+        self.episodes.push(BodyItem::new(ids, extra));
+        self
+    }
+
+    /// Adds an episode of the show with `show_ids` by its absolute number.
+    ///
+    /// Episodes of the same show added one after another are grouped under the show.
+    #[must_use]
+    pub fn absolute_episode(self, show_ids: impl Into<Ids>, number_abs: u16) -> Self
+    where
+        E: Default,
+    {
+        self.absolute_episode_with(show_ids, number_abs, E::default())
+    }
+
+    #[must_use]
+    pub fn absolute_episode_with(
+        mut self,
+        show_ids: impl Into<Ids>,
+        number_abs: u16,
+        extra: E,
+    ) -> Self
+    where
+        E: Default,
+    {
+        let ids = show_ids.into();
+        let episode = AbsoluteEpisode { number_abs, extra };
+        match self.shows.last_mut() {
+            Some(show) if show.ids == ids => show.episodes.push(episode),
+            _ => {
+                let mut show = ShowItem::new(ids, E::default());
+                show.episodes.push(episode);
+                self.shows.push(show);
+            }
+        }
+        self
+    }
+}
+
+/// A single item in an [`ItemsBody`].
+///
+/// Items can be matched by several ids at once by passing a full [`Ids`], or by a single
+/// [`Id`](crate::smo::Id).
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct BodyItem<E = ()> {
+    pub ids: Ids,
+    #[serde(flatten)]
+    pub extra: E,
+}
+
+impl<E> BodyItem<E> {
+    #[must_use]
+    pub fn new(ids: impl Into<Ids>, extra: E) -> Self {
+        Self {
+            ids: ids.into(),
+            extra,
+        }
+    }
+}
+
+/// A show in an [`ItemsBody`], along with episodes of it addressed by their absolute number.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct ShowItem<E = ()> {
+    pub ids: Ids,
+    #[serde(flatten)]
+    pub extra: E,
+    /// Episodes of the show by their absolute number, see [`ItemsBody::absolute_episode`].
+    ///
+    /// The whole show is sent if this is empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub episodes: Vec<AbsoluteEpisode<E>>,
+}
+
+impl<E> ShowItem<E> {
+    #[must_use]
+    pub fn new(ids: impl Into<Ids>, extra: E) -> Self {
+        Self {
+            ids: ids.into(),
+            extra,
+            episodes: Vec::new(),
+        }
+    }
+}
+
+impl<E> From<BodyItem<E>> for ShowItem<E> {
+    fn from(item: BodyItem<E>) -> Self {
+        Self {
+            ids: item.ids,
+            extra: item.extra,
+            episodes: Vec::new(),
+        }
+    }
+}
+
+/// An episode of a show in an [`ItemsBody`], addressed by its absolute number.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct AbsoluteEpisode<E = ()> {
+    pub number_abs: u16,
+    #[serde(flatten)]
+    pub extra: E,
+}
+
+/// Optional notes attached to an item.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+pub struct Notes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// Optional collection date and media info of a collected item.
+///
+/// Trakt uses the current time if `collected_at` is `None`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+pub struct Collected {
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "crate::datetime::option"
+    )]
+    pub collected_at: Option<OffsetDateTime>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<MediaMetadata>,
+}
+
+/// Optional time at which an item was watched.
+///
+/// Trakt uses the current time if `watched_at` is `None`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+pub struct Watched {
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "crate::datetime::option"
+    )]
+    pub watched_at: Option<OffsetDateTime>,
+}
+
+/// Rating of an item, from 1 to 10, and the optional time it was rated at.
+///
+/// Trakt uses the current time if `rated_at` is `None`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct Rated {
+    pub rating: u8,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "crate::datetime::option"
+    )]
+    pub rated_at: Option<OffsetDateTime>,
+}
+
+impl Rated {
+    #[must_use]
+    pub const fn new(rating: u8) -> Self {
+        Self {
+            rating,
+            rated_at: None,
+        }
+    }
+}
+
+/// Number of items affected by a sync request, by type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct Counts {
+    pub movies: u64,
+    pub shows: u64,
+    pub seasons: u64,
+    pub episodes: u64,
+}
+
+/// Items of a sync request that couldn't be found.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct NotFound {
+    pub movies: Vec<NotFoundItem>,
+    pub shows: Vec<NotFoundItem>,
+    pub seasons: Vec<NotFoundItem>,
+    pub episodes: Vec<NotFoundItem>,
+    /// History ids that couldn't be found.
+    pub ids: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+pub struct NotFoundItem {
+    pub ids: Ids,
+}
+
+/// Current state of the list modified by a sync request.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+pub struct ListInfo {
+    #[serde(with = "crate::datetime")]
+    pub updated_at: OffsetDateTime,
+    pub item_count: u64,
+}
+
+/// Summary of a request adding items.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+pub struct AddSummary {
+    pub added: Counts,
+    #[serde(default)]
+    pub updated: Counts,
+    #[serde(default)]
+    pub existing: Counts,
+    pub not_found: NotFound,
+    pub list: Option<ListInfo>,
+}
+
+/// Summary of a request removing items.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+pub struct RemoveSummary {
+    pub deleted: Counts,
+    pub not_found: NotFound,
+    pub list: Option<ListInfo>,
+}
+
+/// Summary of a request reordering items.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+pub struct ReorderSummary {
+    pub updated: u64,
+    pub skipped_ids: Vec<u64>,
+    pub list: Option<ListInfo>,
+}
+
+/// A sync GET request that needs to be re-run, as determined by a [`Watermark`].
+///
+/// More variants are added as more sync endpoints are tracked by [`Watermark::refresh`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Refresh {
+    Collection(collection::get::Request),
+    Favorites(favorites::get::Request),
+    History(history::get::Request),
+    Ratings(ratings::get::Request),
+    Watchlist(watchlist::get::Request),
+    Playback(playback::get::Request),
+}
+
+/// Stores the [`last_activities`] of the previous sync to determine which data changed since.
+///
+/// # Example
+///
+/// ```
+/// use trakt_rs::api::sync::{last_activities::LastActivities, Refresh, Watermark};
+///
+/// fn sync(watermark: &mut Watermark, activities: LastActivities) {
+///     for refresh in watermark.refresh(&activities) {
+///         match refresh {
+///             Refresh::Collection(req) => { /* fetch the collection */ }
+///             Refresh::Favorites(req) => { /* fetch the favorites */ }
+///             Refresh::History(req) => { /* fetch the watched history */ }
+///             Refresh::Ratings(req) => { /* fetch the ratings */ }
+///             Refresh::Watchlist(req) => { /* fetch the watchlist */ }
+///             Refresh::Playback(req) => { /* fetch the playback progress */ }
+///             _ => { /* requests added in later versions */ }
+///         }
+///     }
+///     watermark.update(activities);
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Watermark {
+    last: Option<last_activities::LastActivities>,
+}
+
+impl Watermark {
+    /// Creates an empty watermark, for which everything needs refreshing.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Returns the time of the last activity as of the previous sync.
+    #[must_use]
+    pub fn last_synced(&self) -> Option<OffsetDateTime> {
+        self.last.as_ref().map(|last| last.all)
+    }
+
+    /// Returns the requests that need to be re-run because their data changed since the previous
+    /// sync.
+    ///
+    /// Every request is returned if there was no previous sync.
+    #[must_use]
+    pub fn refresh(&self, current: &last_activities::LastActivities) -> Vec<Refresh> {
+        type Activity = fn(&last_activities::LastActivities) -> Option<OffsetDateTime>;
+
+        if self
+            .last
+            .as_ref()
+            .is_some_and(|last| current.all <= last.all)
+        {
+            return Vec::new();
+        }
+
+        let history = |tp| {
+            Refresh::History(history::get::Request {
+                tp: Some(tp),
+                ..Default::default()
+            })
+        };
+        let ratings = |tp| {
+            Refresh::Ratings(ratings::get::Request {
+                tp: Some(tp),
+                ..Default::default()
+            })
+        };
+        let watchlist = |tp| {
+            Refresh::Watchlist(watchlist::get::Request {
+                tp: Some(tp),
+                ..Default::default()
+            })
+        };
+        let playback = |tp| {
+            Refresh::Playback(playback::get::Request {
+                tp: Some(tp),
+                ..Default::default()
+            })
+        };
+        let requests: [(Activity, Refresh); 16] = [
+            (
+                |a| a.movies.collected_at,
+                Refresh::Collection(collection::get::Request {
+                    tp: ItemType::Movies,
+                    ..Default::default()
+                }),
+            ),
+            (
+                |a| a.episodes.collected_at,
+                Refresh::Collection(collection::get::Request {
+                    tp: ItemType::Shows,
+                    ..Default::default()
+                }),
+            ),
+            (
+                |a| a.movies.favorited_at,
+                Refresh::Favorites(favorites::get::Request {
+                    tp: Some(ItemType::Movies),
+                    ..Default::default()
+                }),
+            ),
+            (
+                |a| a.shows.favorited_at,
+                Refresh::Favorites(favorites::get::Request {
+                    tp: Some(ItemType::Shows),
+                    ..Default::default()
+                }),
+            ),
+            (|a| a.movies.watched_at, history(MediaType::Movies)),
+            (|a| a.episodes.watched_at, history(MediaType::Episodes)),
+            (|a| a.movies.rated_at, ratings(MediaType::Movies)),
+            (|a| a.shows.rated_at, ratings(MediaType::Shows)),
+            (|a| a.seasons.rated_at, ratings(MediaType::Seasons)),
+            (|a| a.episodes.rated_at, ratings(MediaType::Episodes)),
+            (|a| a.movies.watchlisted_at, watchlist(MediaType::Movies)),
+            (|a| a.shows.watchlisted_at, watchlist(MediaType::Shows)),
+            (|a| a.seasons.watchlisted_at, watchlist(MediaType::Seasons)),
+            (
+                |a| a.episodes.watchlisted_at,
+                watchlist(MediaType::Episodes),
+            ),
+            (
+                |a| a.movies.paused_at,
+                playback(playback::get::Type::Movies),
+            ),
+            (
+                |a| a.episodes.paused_at,
+                playback(playback::get::Type::Episodes),
+            ),
+        ];
+
+        requests
+            .into_iter()
+            .filter(|(activity, _)| {
+                let Some(last) = &self.last else {
+                    return true;
+                };
+                match (activity(last), activity(current)) {
+                    (Some(prev), Some(cur)) => cur > prev,
+                    (None, Some(_)) => true,
+                    (_, None) => false,
+                }
+            })
+            .map(|(_, request)| request)
+            .collect()
+    }
+
+    /// Records `current` as the state of the latest sync.
+    pub const fn update(&mut self, current: last_activities::LastActivities) {
+        self.last = Some(current);
+    }
+}
+
+impl From<last_activities::LastActivities> for Watermark {
+    fn from(value: last_activities::LastActivities) -> Self {
+        Self { last: Some(value) }
+    }
+}
+
+/// Constructs a request with a JSON body.
+pub(crate) fn construct_body_req<T: Default + BufMut>(
+    ctx: &Context,
+    md: &Metadata,
+    path: &impl Serialize,
+    body: &impl Serialize,
+) -> Result<http::Request<T>, IntoHttpError> {
+    let mut writer = T::default().writer();
+    serde_json::to_writer(&mut writer, body)?;
+    trakt_core::construct_req(ctx, md, path, &(), writer.into_inner())
+}
+
+pub mod collection {
+    //! Manage a user's collection
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/sync/get-collection>
+
+    pub mod get {
+        //! Get a user's collection
+        //!
+        //! Use [`Extended::METADATA`] to include the media info of each collected item.
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/get-collection/get-collection>
+
+        use serde::Deserialize;
+        use time::OffsetDateTime;
+
+        use crate::{
+            api::sync::ItemType,
+            smo::{Extended, MediaMetadata, Movie, Show},
+        };
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/sync/collection/{tp}",
+        example(
+            request = "Request { tp: trakt_rs::api::sync::ItemType::Shows, extended: trakt_rs::smo::Extended::empty() }",
+            url = "https://api.trakt.tv/sync/collection/shows",
+        ),
+        auth = Required,
+        )]
+        pub struct Request {
+            pub tp: ItemType,
+            pub extended: Extended,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub Vec<ResponseItem>);
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+        #[serde(untagged)]
+        #[non_exhaustive]
+        pub enum ResponseItem {
+            Movie(CollectedMovie),
+            Show(CollectedShow),
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+        pub struct CollectedMovie {
+            #[serde(with = "crate::datetime")]
+            pub collected_at: OffsetDateTime,
+            #[serde(with = "crate::datetime")]
+            pub updated_at: OffsetDateTime,
+            pub movie: Movie,
+            pub metadata: Option<MediaMetadata>,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+        pub struct CollectedShow {
+            #[serde(with = "crate::datetime")]
+            pub last_collected_at: OffsetDateTime,
+            #[serde(with = "crate::datetime")]
+            pub last_updated_at: OffsetDateTime,
+            pub show: Show,
+            pub seasons: Vec<CollectedSeason>,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+        pub struct CollectedSeason {
+            pub number: u16,
+            pub episodes: Vec<CollectedEpisode>,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+        pub struct CollectedEpisode {
+            pub number: u16,
+            #[serde(with = "crate::datetime")]
+            pub collected_at: OffsetDateTime,
+            pub metadata: Option<MediaMetadata>,
+        }
+    }
+
+    pub mod add {
+        //! Add items to a user's collection
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/add-to-collection/add-items-to-collection>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, AddSummary, Collected, ItemsBody};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody<Collected>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/collection",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = CREATED)]
+        pub struct Response(pub AddSummary);
+    }
+
+    pub mod remove {
+        //! Remove items from a user's collection
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/remove-from-collection/remove-items-from-collection>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, ItemsBody, RemoveSummary};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/collection/remove",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub RemoveSummary);
+    }
+}
+
+pub mod favorites {
+    //! Manage a user's favorites
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/sync/favorites>
+
+    pub mod get {
+        //! Get a user's favorites
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/get-favorites/get-favorites>
+
+        use bytes::BufMut;
+        use serde::Serialize;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::{api::sync::ItemType, smo::ListItem};
+
+        /// Request for a user's favorites.
+        ///
+        /// Favorites of every type are returned if `tp` is `None`. The request fails to convert
+        /// if `sort` is given without a type.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub tp: Option<ItemType>,
+            /// Sort order, [`Sort::Rank`] if `None`.
+            pub sort: Option<Sort>,
+        }
+
+        #[derive(Debug, Serialize)]
+        struct RequestPathParams {
+            tp: Option<ItemType>,
+            sort: Option<Sort>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/favorites/{tp}/{sort}",
+                http::Method::GET,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!())
+            .with_optional_params(&["tp", "sort"]);
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                trakt_core::construct_req(&ctx, &Self::METADATA, &self.path()?, &(), T::default())
+            }
+
+            fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+                trakt_core::construct_uri(&ctx, &Self::METADATA, &self.path()?, &())
+            }
+        }
+
+        impl Request {
+            fn path(self) -> Result<RequestPathParams, IntoHttpError> {
+                if self.tp.is_none() && self.sort.is_some() {
+                    return Err(IntoHttpError::Validation(String::from(
+                        "A sort order can only be sent along with an item type",
+                    )));
+                }
+                Ok(RequestPathParams {
+                    tp: self.tp,
+                    sort: self.sort,
+                })
+            }
+        }
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Sort {
+            Rank,
+            Added,
+            Released,
+            Title,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub Vec<ListItem>);
+    }
+
+    pub mod add {
+        //! Add items to a user's favorites
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/add-to-favorites/add-items-to-favorites>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, AddSummary, ItemsBody, Notes};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody<Notes>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/favorites",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = CREATED)]
+        pub struct Response(pub AddSummary);
+    }
+
+    pub mod remove {
+        //! Remove items from a user's favorites
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/remove-from-favorites/remove-items-from-favorites>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, ItemsBody, RemoveSummary};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/favorites/remove",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub RemoveSummary);
+    }
+
+    pub mod reorder {
+        //! Reorder a user's favorites
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/reorder-favorites/reorder-favorited-items>
+
+        use bytes::BufMut;
+        use serde_json::json;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, ReorderSummary};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            /// List item ids in their new order.
+            pub rank: Vec<u64>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/favorites/reorder",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let body = json!({ "rank": self.rank });
+                construct_body_req(&ctx, &Self::METADATA, &(), &body)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub ReorderSummary);
+    }
+}
+
+pub mod ratings {
+    //! Manage a user's ratings
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/sync/get-ratings>
+
+    use trakt_core::error::IntoHttpError;
+
+    pub mod get {
+        //! Get a user's ratings
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/get-ratings/get-ratings>
+
+        use bytes::BufMut;
+        use serde::Serialize;
+        use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
+
+        use crate::{
+            api::sync::MediaType,
+            smo::{Extended, RatedItem},
+        };
+
+        /// Request for a user's ratings.
+        ///
+        /// Ratings of every type are returned if `tp` is `None`. `rating` only returns items with
+        /// that rating, and can only be sent along with a type, so the request fails to convert
+        /// otherwise.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub tp: Option<MediaType>,
+            pub rating: Option<u8>,
+            pub extended: Extended,
+            pub pagination: Pagination,
+        }
+
+        /// Path parameters, also used by `users::ratings`.
+        #[derive(Debug, Serialize)]
+        pub(crate) struct RequestPathParams {
+            pub(crate) tp: Option<MediaType>,
+            pub(crate) rating: Option<u8>,
+        }
+
+        #[derive(Debug, Serialize)]
+        pub(crate) struct RequestQueryParams {
+            extended: Extended,
+            #[serde(flatten)]
+            pagination: Pagination,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/ratings/{tp}/{rating}",
+                http::Method::GET,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!())
+            .with_optional_params(&["tp", "rating"]);
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let (path, query) = self.params()?;
+                trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
+            }
+
+            fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+                let (path, query) = self.params()?;
+                trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+            }
+        }
+
+        impl Request {
+            pub(crate) fn params(
+                self,
+            ) -> Result<(RequestPathParams, RequestQueryParams), IntoHttpError> {
+                self.pagination.validate()?;
+                if let Some(rating) = self.rating {
+                    super::validate(rating)?;
+                    if self.tp.is_none() {
+                        return Err(IntoHttpError::Validation(String::from(
+                            "A rating can only be sent along with a media type",
+                        )));
+                    }
+                }
+                Ok((
+                    RequestPathParams {
+                        tp: self.tp,
+                        rating: self.rating,
+                    },
+                    RequestQueryParams {
+                        extended: self.extended,
+                        pagination: self.pagination,
+                    },
+                ))
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(#[trakt(pagination)] pub PaginationResponse<RatedItem>);
+    }
+
+    pub mod add {
+        //! Add ratings for items
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/add-ratings/add-new-ratings>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, AddSummary, ItemsBody, Rated};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody<Rated>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/ratings",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let items = &self.items;
+                let shows = items.shows.iter().flat_map(|show| {
+                    std::iter::once(&show.extra).chain(show.episodes.iter().map(|ep| &ep.extra))
+                });
+                for extra in items
+                    .movies
+                    .iter()
+                    .chain(&items.seasons)
+                    .chain(&items.episodes)
+                    .map(|item| &item.extra)
+                    .chain(shows)
+                {
+                    super::validate(extra.rating)?;
+                }
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = CREATED)]
+        pub struct Response(pub AddSummary);
+    }
+
+    pub mod remove {
+        //! Remove ratings for items
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/remove-ratings/remove-ratings>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, ItemsBody, RemoveSummary};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/ratings/remove",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub RemoveSummary);
+    }
+
+    /// Checks that `rating` is between 1 and 10.
+    pub(crate) fn validate(rating: u8) -> Result<(), IntoHttpError> {
+        if (1..=10).contains(&rating) {
+            Ok(())
+        } else {
+            Err(IntoHttpError::Validation(format!(
+                "Rating must be between 1 and 10, got {rating}"
+            )))
+        }
+    }
+}
+
+pub mod watchlist {
+    //! Manage a user's watchlist
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/sync/get-watchlist>
+
+    pub mod get {
+        //! Get a user's watchlist
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/get-watchlist/get-watchlist>
+
+        use bytes::BufMut;
+        use serde::Serialize;
+        use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
+
+        use crate::{
+            api::sync::MediaType,
+            smo::{Extended, ListItem, ListSortHow},
+        };
+
+        /// Request for a user's watchlist.
+        ///
+        /// Items of every type are returned if `tp` is `None`. The path segments are optional
+        /// from left to right: the request fails to convert if `sort` is given without a type,
+        /// or `sort_how` without a `sort`.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub tp: Option<MediaType>,
+            /// Sort order, the order of the user's watchlist settings if `None`.
+            pub sort: Option<WatchlistSort>,
+            pub sort_how: Option<ListSortHow>,
+            pub extended: Extended,
+            pub pagination: Pagination,
+        }
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+        #[serde(rename_all = "lowercase")]
+        pub enum WatchlistSort {
+            Rank,
+            Added,
+            Released,
+            Title,
+            Runtime,
+        }
+
+        /// Path parameters, also used by `users::watchlist`.
+        #[derive(Debug, Serialize)]
+        pub(crate) struct RequestPathParams {
+            pub(crate) tp: Option<MediaType>,
+            pub(crate) sort: Option<WatchlistSort>,
+            pub(crate) sort_how: Option<ListSortHow>,
+        }
+
+        #[derive(Debug, Serialize)]
+        pub(crate) struct RequestQueryParams {
+            extended: Extended,
+            #[serde(flatten)]
+            pagination: Pagination,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/watchlist/{tp}/{sort}/{sort_how}",
+                http::Method::GET,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!())
+            .with_optional_params(&["tp", "sort", "sort_how"]);
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let (path, query) = self.params()?;
+                trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
+            }
+
+            fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+                let (path, query) = self.params()?;
+                trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+            }
+        }
+
+        impl Request {
+            pub(crate) fn params(
+                self,
+            ) -> Result<(RequestPathParams, RequestQueryParams), IntoHttpError> {
+                self.pagination.validate()?;
+                if self.tp.is_none() && self.sort.is_some() {
+                    return Err(IntoHttpError::Validation(String::from(
+                        "A sort order can only be sent along with a media type",
+                    )));
+                }
+                if self.sort.is_none() && self.sort_how.is_some() {
+                    return Err(IntoHttpError::Validation(String::from(
+                        "A sort direction can only be sent along with a sort order",
+                    )));
+                }
+                Ok((
+                    RequestPathParams {
+                        tp: self.tp,
+                        sort: self.sort,
+                        sort_how: self.sort_how,
+                    },
+                    RequestQueryParams {
+                        extended: self.extended,
+                        pagination: self.pagination,
+                    },
+                ))
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(#[trakt(pagination)] pub PaginationResponse<ListItem>);
+    }
+
+    pub mod add {
+        //! Add items to a user's watchlist
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/add-to-watchlist/add-items-to-watchlist>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, AddSummary, ItemsBody, Notes};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody<Notes>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/watchlist",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = CREATED)]
+        pub struct Response(pub AddSummary);
+    }
+
+    pub mod remove {
+        //! Remove items from a user's watchlist
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/remove-from-watchlist/remove-items-from-watchlist>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, ItemsBody, RemoveSummary};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/watchlist/remove",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub RemoveSummary);
+    }
+
+    pub mod reorder {
+        //! Reorder a user's watchlist
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/reorder-watchlist/reorder-watchlist-items>
+
+        use bytes::BufMut;
+        use serde_json::json;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, ReorderSummary};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            /// List item ids in their new order.
+            pub rank: Vec<u64>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/watchlist/reorder",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let body = json!({ "rank": self.rank });
+                construct_body_req(&ctx, &Self::METADATA, &(), &body)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub ReorderSummary);
+    }
+}
+
+pub mod history {
+    //! Manage a user's watched history
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/sync/get-history>
+
+    pub mod get {
+        //! Get a user's watched history
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/get-history/get-watched-history>
+
+        use bytes::BufMut;
+        use serde::Serialize;
+        use time::OffsetDateTime;
+        use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
+
+        use crate::{
+            api::sync::MediaType,
+            smo::{Extended, HistoryItem},
+        };
+
+        /// Request for a user's watched history.
+        ///
+        /// Plays of every type are returned if `tp` is `None`. `id` narrows the history down to a
+        /// single item by its trakt id, and can only be sent along with a type, so the request
+        /// fails to convert otherwise.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub tp: Option<MediaType>,
+            pub id: Option<u64>,
+            /// Only return plays watched at or after this time.
+            pub start_at: Option<OffsetDateTime>,
+            /// Only return plays watched at or before this time.
+            pub end_at: Option<OffsetDateTime>,
+            pub extended: Extended,
+            pub pagination: Pagination,
+        }
+
+        /// Path parameters, also used by `users::history`.
+        #[derive(Debug, Serialize)]
+        pub(crate) struct RequestPathParams {
+            pub(crate) tp: Option<MediaType>,
+            pub(crate) id: Option<u64>,
+        }
+
+        #[derive(Debug, Serialize)]
+        pub(crate) struct RequestQueryParams {
+            #[serde(
+                skip_serializing_if = "Option::is_none",
+                with = "crate::datetime::option"
+            )]
+            start_at: Option<OffsetDateTime>,
+            #[serde(
+                skip_serializing_if = "Option::is_none",
+                with = "crate::datetime::option"
+            )]
+            end_at: Option<OffsetDateTime>,
+            extended: Extended,
+            #[serde(flatten)]
+            pagination: Pagination,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/history/{tp}/{id}",
+                http::Method::GET,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!())
+            .with_optional_params(&["tp", "id"]);
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let (path, query) = self.params()?;
+                trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
+            }
+
+            fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+                let (path, query) = self.params()?;
+                trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+            }
+        }
+
+        impl Request {
+            pub(crate) fn params(
+                self,
+            ) -> Result<(RequestPathParams, RequestQueryParams), IntoHttpError> {
+                self.pagination.validate()?;
+                if self.tp.is_none() && self.id.is_some() {
+                    return Err(IntoHttpError::Validation(String::from(
+                        "An item id can only be sent along with a media type",
+                    )));
+                }
+                Ok((
+                    RequestPathParams {
+                        tp: self.tp,
+                        id: self.id,
+                    },
+                    RequestQueryParams {
+                        start_at: self.start_at,
+                        end_at: self.end_at,
+                        extended: self.extended,
+                        pagination: self.pagination,
+                    },
+                ))
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(#[trakt(pagination)] pub PaginationResponse<HistoryItem>);
+    }
+
+    pub mod add {
+        //! Add plays to a user's watched history
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/add-to-history/add-items-to-watched-history>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, AddSummary, ItemsBody, Watched};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody<Watched>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/history",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                construct_body_req(&ctx, &Self::METADATA, &(), &self.items)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = CREATED)]
+        pub struct Response(pub AddSummary);
+    }
+
+    pub mod remove {
+        //! Remove plays from a user's watched history
+        //!
+        //! Removing an item removes all of its plays, while `ids` removes single plays by their
+        //! history id.
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/remove-from-history/remove-items-from-history>
+
+        use bytes::BufMut;
+        use serde::Serialize;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use crate::api::sync::{construct_body_req, ItemsBody, RemoveSummary};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            pub items: ItemsBody,
+            /// History ids of plays to remove.
+            pub ids: Vec<u64>,
+        }
+
+        #[derive(Debug, Serialize)]
+        struct Body<'a> {
+            #[serde(flatten)]
+            items: &'a ItemsBody,
+            #[serde(skip_serializing_if = "<[u64]>::is_empty")]
+            ids: &'a [u64],
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/sync/history/remove",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let body = Body {
+                    items: &self.items,
+                    ids: &self.ids,
+                };
+                construct_body_req(&ctx, &Self::METADATA, &(), &body)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub RemoveSummary);
+    }
+}
+
+pub mod playback {
+    //! Manage a user's paused playback progress
+    //!
+    //! Playback progress is saved when a [`scrobble`](crate::api::scrobble) is paused.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/sync/playback>
+
+    pub mod get {
+        //! Get a user's paused playback progress
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/playback/get-playback-progress>
+
+        use serde::{Deserialize, Serialize};
+        use time::OffsetDateTime;
+
+        use crate::smo::WatchingItem;
+
+        /// Request for a user's paused playback progress.
+        ///
+        /// Progress of movies and episodes is returned if `tp` is `None`.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/sync/playback/{tp}",
+        example(
+            request = "Request { tp: Some(Type::Episodes), start_at: None, end_at: None }",
+            url = "https://api.trakt.tv/sync/playback/episodes",
+        ),
+        auth = Required,
+        )]
+        pub struct Request {
+            pub tp: Option<Type>,
+            /// Only return progress paused at or after this time.
+            #[serde(
+                skip_serializing_if = "Option::is_none",
+                with = "crate::datetime::option"
+            )]
+            pub start_at: Option<OffsetDateTime>,
+            /// Only return progress paused at or before this time.
+            #[serde(
+                skip_serializing_if = "Option::is_none",
+                with = "crate::datetime::option"
+            )]
+            pub end_at: Option<OffsetDateTime>,
+        }
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Type {
+            Movies,
+            Episodes,
+        }
+
+        #[derive(Debug, Clone, PartialEq, trakt_macros::Response)]
+        pub struct Response(pub Vec<PlaybackItem>);
+
+        #[derive(Debug, Clone, PartialEq, Deserialize)]
+        #[non_exhaustive]
+        pub struct PlaybackItem {
+            /// Playback id, used to [`remove`](super::remove) the progress.
+            pub id: u64,
+            /// Progress in percent, from 0 to 100.
+            pub progress: f64,
+            #[serde(with = "crate::datetime")]
+            pub paused_at: OffsetDateTime,
+            #[serde(flatten)]
+            pub item: WatchingItem,
+        }
+    }
+
+    pub mod remove {
+        //! Remove a paused playback progress
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/sync/remove-playback/remove-a-playback-item>
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/sync/playback/{id}",
+        method = DELETE,
+        auth = Required,
+        )]
+        pub struct Request {
+            /// Id of the [`PlaybackItem`](super::get::PlaybackItem).
+            pub id: u64,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = NO_CONTENT)]
+        pub struct Response;
+    }
+}
+
+pub mod last_activities {
+    //! Get the times of a user's last activities
+    //!
+    //! Use a [`Watermark`](super::Watermark) to determine which data changed since the last sync.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/sync/last-activities/get-last-activity>
+
+    use serde::Deserialize;
+    use time::OffsetDateTime;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/sync/last_activities",
+    example(
+        request = "Request",
+        url = "https://api.trakt.tv/sync/last_activities",
+    ),
+    auth = Required,
+    )]
+    pub struct Request;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub LastActivities);
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct LastActivities {
+        /// Time of the latest activity of any kind.
+        #[serde(with = "crate::datetime")]
+        pub all: OffsetDateTime,
+        #[serde(default)]
+        pub movies: MediaActivities,
+        #[serde(default)]
+        pub episodes: MediaActivities,
+        #[serde(default)]
+        pub shows: MediaActivities,
+        #[serde(default)]
+        pub seasons: MediaActivities,
+        #[serde(default)]
+        pub comments: CommentActivities,
+        #[serde(default)]
+        pub lists: ListActivities,
+        #[serde(default)]
+        pub watchlist: UpdateActivities,
+        #[serde(default)]
+        pub favorites: UpdateActivities,
+        #[serde(default)]
+        pub account: AccountActivities,
+    }
+
+    /// Activities of movies, shows, seasons or episodes.
+    ///
+    /// Fields that don't apply to the media type are `None`.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    pub struct MediaActivities {
+        #[serde(default, with = "crate::datetime::option")]
+        pub watched_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub collected_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub rated_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub watchlisted_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub favorited_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub recommendations_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub commented_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub paused_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub hidden_at: Option<OffsetDateTime>,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    pub struct CommentActivities {
+        #[serde(default, with = "crate::datetime::option")]
+        pub liked_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub blocked_at: Option<OffsetDateTime>,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    pub struct ListActivities {
+        #[serde(default, with = "crate::datetime::option")]
+        pub liked_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub updated_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub commented_at: Option<OffsetDateTime>,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    pub struct UpdateActivities {
+        #[serde(default, with = "crate::datetime::option")]
+        pub updated_at: Option<OffsetDateTime>,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    pub struct AccountActivities {
+        #[serde(default, with = "crate::datetime::option")]
+        pub settings_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub followed_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub following_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub pending_at: Option<OffsetDateTime>,
+        #[serde(default, with = "crate::datetime::option")]
+        pub requested_at: Option<OffsetDateTime>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::macros::datetime;
+    use trakt_core::{Context, Request as _, Response as _};
+
+    use super::*;
+    use crate::{
+        smo::{
+            Extended, Hdr, Id, ListSortHow, ListedItem, MediaFormat, Resolution, WatchAction,
+            WatchingItem,
+        },
+        test::assert_request,
+    };
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "client_id",
+        oauth_token: Some("token"),
+    };
+
+    #[test]
+    fn items_body() {
+        let body = ItemsBody::<Notes>::new().movie(Id::Trakt(1)).show_with(
+            Id::Imdb("tt0903747".into()),
+            Notes {
+                notes: Some("Best show ever".into()),
+            },
+        );
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            json!({
+                "movies": [{ "ids": { "trakt": 1 } }],
+                "shows": [{ "ids": { "imdb": "tt0903747" }, "notes": "Best show ever" }],
+            })
+        );
+
+        let body = ItemsBody::<()>::new().episode(Id::Tvdb(2));
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            json!({ "episodes": [{ "ids": { "tvdb": 2 } }] })
+        );
+        assert!(ItemsBody::<()>::new().is_empty());
+
+        let ids = Ids {
+            trakt: Some(1),
+            imdb: Some("tt1104001".into()),
+            tmdb: Some(20526),
+            ..Ids::default()
+        };
+        let body = ItemsBody::<()>::new().movie(ids);
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            json!({ "movies": [{ "ids": { "trakt": 1, "imdb": "tt1104001", "tmdb": 20526 } }] })
+        );
+    }
+
+    #[test]
+    fn items_body_absolute_episodes() {
+        let body = ItemsBody::<Watched>::new()
+            .absolute_episode(Id::Trakt(1), 1)
+            .absolute_episode_with(
+                Id::Trakt(1),
+                2,
+                Watched {
+                    watched_at: Some(datetime!(2014-09-01 09:10:11 UTC)),
+                },
+            )
+            .show(Id::Trakt(2))
+            .absolute_episode(Id::Trakt(2), 100);
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            json!({
+                "shows": [
+                    {
+                        "ids": { "trakt": 1 },
+                        "episodes": [
+                            { "number_abs": 1 },
+                            { "number_abs": 2, "watched_at": "2014-09-01T09:10:11.000Z" }
+                        ]
+                    },
+                    { "ids": { "trakt": 2 }, "episodes": [{ "number_abs": 100 }] }
+                ],
+            })
+        );
+
+        let req = history::add::Request { items: body };
+        let http_req = req.try_into_http_request::<Vec<u8>>(CTX).unwrap();
+        assert_eq!(http_req.uri(), "https://api.trakt.tv/sync/history");
+    }
+
+    #[test]
+    fn collection_get_request() {
+        let req = collection::get::Request::default();
+        assert_request(CTX, req, "https://api.trakt.tv/sync/collection/movies", "");
+
+        let req = collection::get::Request {
+            tp: ItemType::Shows,
+            extended: Extended::METADATA,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/collection/shows?extended=metadata",
+            "",
+        );
+    }
+
+    #[test]
+    fn collection_get_response() {
+        let body = json!([
+            {
+                "collected_at": "2014-09-01T09:10:11.000Z",
+                "updated_at": "2014-09-01T09:10:11.000Z",
+                "movie": {
+                    "title": "Star Wars: The Force Awakens",
+                    "year": 2015,
+                    "ids": { "trakt": 94024, "slug": "star-wars-the-force-awakens-2015" }
+                },
+                "metadata": {
+                    "media_type": "bluray",
+                    "resolution": "uhd_4k",
+                    "hdr": "dolby_vision",
+                    "audio": "dolby_atmos",
+                    "audio_channels": "7.1.4",
+                    "3d": false
+                }
+            },
+            {
+                "last_collected_at": "2014-09-01T09:10:11.000Z",
+                "last_updated_at": "2014-09-01T09:10:11.000Z",
+                "show": {
+                    "title": "Breaking Bad",
+                    "year": 2008,
+                    "ids": { "trakt": 1, "slug": "breaking-bad" }
+                },
+                "seasons": [
+                    {
+                        "number": 1,
+                        "episodes": [
+                            {
+                                "number": 1,
+                                "collected_at": "2014-09-01T09:10:11.000Z",
+                                "metadata": { "media_type": "digital", "resolution": "hd_720p" }
+                            },
+                            {
+                                "number": 2,
+                                "collected_at": "2014-09-01T09:10:11.000Z"
+                            }
+                        ]
+                    }
+                ]
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = collection::get::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 2);
+
+        let collection::get::ResponseItem::Movie(movie) = &response.0[0] else {
+            panic!("expected movie");
+        };
+        assert_eq!(
+            movie.metadata,
+            Some(MediaMetadata {
+                media_type: Some(MediaFormat::Bluray),
+                resolution: Some(Resolution::Uhd4k),
+                hdr: Some(Hdr::DolbyVision),
+                audio: Some("dolby_atmos".into()),
+                audio_channels: Some("7.1.4".into()),
+                is_3d: false,
+            })
+        );
+
+        let collection::get::ResponseItem::Show(show) = &response.0[1] else {
+            panic!("expected show");
+        };
+        let episodes = &show.seasons[0].episodes;
+        assert_eq!(
+            episodes[0].metadata,
+            Some(MediaMetadata {
+                media_type: Some(MediaFormat::Digital),
+                resolution: Some(Resolution::Hd720p),
+                ..MediaMetadata::default()
+            })
+        );
+        assert_eq!(episodes[1].metadata, None);
+    }
+
+    #[test]
+    fn collection_add_request() {
+        let req = collection::add::Request {
+            items: ItemsBody::new()
+                .movie(Id::Trakt(1))
+                .movie_with(
+                    Id::Imdb("tt2488496".into()),
+                    Collected {
+                        collected_at: Some(datetime!(2014-09-01 09:10:11 UTC)),
+                        metadata: Some(MediaMetadata {
+                            media_type: Some(MediaFormat::Bluray),
+                            resolution: Some(Resolution::Uhd4k),
+                            hdr: Some(Hdr::DolbyVision),
+                            audio: Some("dolby_atmos".into()),
+                            audio_channels: Some("7.1.4".into()),
+                            is_3d: false,
+                        }),
+                    },
+                )
+                .episode_with(
+                    Id::Tvdb(2),
+                    Collected {
+                        metadata: Some(MediaMetadata {
+                            media_type: Some(MediaFormat::Digital),
+                            is_3d: true,
+                            ..MediaMetadata::default()
+                        }),
+                        ..Collected::default()
+                    },
+                ),
+        };
+        let expected = json!({
+            "movies": [
+                { "ids": { "trakt": 1 } },
+                {
+                    "ids": { "imdb": "tt2488496" },
+                    "collected_at": "2014-09-01T09:10:11.000Z",
+                    "media_type": "bluray",
+                    "resolution": "uhd_4k",
+                    "hdr": "dolby_vision",
+                    "audio": "dolby_atmos",
+                    "audio_channels": "7.1.4",
+                    "3d": false
+                }
+            ],
+            "episodes": [{ "ids": { "tvdb": 2 }, "media_type": "digital", "3d": true }],
+        });
+        assert_eq!(serde_json::to_value(&req.items).unwrap(), expected);
+        let body = serde_json::to_string(&req.items).unwrap();
+        assert_request(CTX, req, "https://api.trakt.tv/sync/collection", &body);
+    }
+
+    #[test]
+    fn collection_remove_request() {
+        let req = collection::remove::Request {
+            items: ItemsBody::new().show(Id::Trakt(1)).season(Id::Trakt(61430)),
+        };
+        let expected = json!({
+            "shows": [{ "ids": { "trakt": 1 } }],
+            "seasons": [{ "ids": { "trakt": 61430 } }],
+        });
+        assert_eq!(serde_json::to_value(&req.items).unwrap(), expected);
+        let body = serde_json::to_string(&req.items).unwrap();
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/collection/remove",
+            &body,
+        );
+
+        let body = json!({
+            "deleted": { "movies": 0, "episodes": 62 },
+            "not_found": {
+                "movies": [],
+                "shows": [],
+                "seasons": [{ "ids": { "trakt": 61431 } }],
+                "episodes": []
+            }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = collection::remove::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.deleted.episodes, 62);
+        assert_eq!(response.0.not_found.seasons[0].ids.trakt, Some(61431));
+    }
+
+    #[test]
+    fn favorites_get_request() {
+        let req = favorites::get::Request::default();
+        assert_request(CTX, req, "https://api.trakt.tv/sync/favorites", "");
+        assert_eq!(req.uri(CTX).unwrap(), "https://api.trakt.tv/sync/favorites");
+
+        let req = favorites::get::Request {
+            tp: None,
+            sort: Some(favorites::get::Sort::Title),
+        };
+        assert!(matches!(
+            req.try_into_http_request::<Vec<u8>>(CTX),
+            Err(IntoHttpError::Validation(_))
+        ));
+
+        let req = favorites::get::Request {
+            tp: Some(ItemType::Movies),
+            sort: None,
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/sync/favorites/movies", "");
+
+        let req = favorites::get::Request {
+            tp: Some(ItemType::Movies),
+            sort: Some(favorites::get::Sort::Rank),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/favorites/movies/rank",
+            "",
+        );
+
+        let req = favorites::get::Request {
+            tp: Some(ItemType::Shows),
+            sort: Some(favorites::get::Sort::Released),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/favorites/shows/released",
+            "",
+        );
+    }
+
+    #[test]
+    fn favorites_get_response() {
+        let body = json!([
+            {
+                "rank": 1,
+                "id": 101,
+                "listed_at": "2014-09-01T09:10:11.000Z",
+                "notes": "Daft Punk really knocks it out of the park on the soundtrack.",
+                "type": "movie",
+                "movie": {
+                    "title": "TRON: Legacy",
+                    "year": 2010,
+                    "ids": {
+                        "trakt": 1,
+                        "slug": "tron-legacy-2010",
+                        "imdb": "tt1104001",
+                        "tmdb": 20526
+                    }
+                }
+            },
+            {
+                "rank": 2,
+                "id": 102,
+                "listed_at": "2014-09-01T09:10:11.000Z",
+                "notes": null,
+                "type": "show",
+                "show": {
+                    "title": "Breaking Bad",
+                    "year": 2008,
+                    "ids": {
+                        "trakt": 1,
+                        "slug": "breaking-bad",
+                        "tvdb": 81189,
+                        "imdb": "tt0903747",
+                        "tmdb": 1396
+                    }
+                }
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = favorites::get::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 2);
+        assert_eq!(response.0[0].rank, 1);
+        assert!(response.0[0].notes.is_some());
+        assert!(matches!(&response.0[0].item, ListedItem::Movie { movie } if movie.year == 2010));
+        assert_eq!(response.0[1].notes, None);
+        assert!(
+            matches!(&response.0[1].item, ListedItem::Show { show } if show.title == "Breaking Bad")
+        );
+    }
+
+    #[test]
+    fn favorites_add_request() {
+        let req = favorites::add::Request {
+            items: ItemsBody::new().movie_with(
+                Id::Trakt(1),
+                Notes {
+                    notes: Some("Great soundtrack".into()),
+                },
+            ),
+        };
+        let expected = json!({
+            "movies": [{ "ids": { "trakt": 1 }, "notes": "Great soundtrack" }],
+        });
+        assert_request(
+            CTX,
+            req.clone(),
+            "https://api.trakt.tv/sync/favorites",
+            &expected,
+        );
+
+        let plan = trakt_core::RequestPlan::new(req, CTX).unwrap().redacted();
+        assert_eq!(plan.method, "POST");
+        assert_eq!(plan.url, "https://api.trakt.tv/sync/favorites");
+        assert_eq!(plan.headers["authorization"], "Bearer [redacted]");
+        assert_eq!(plan.body_json, Some(expected));
+    }
+
+    #[test]
+    fn favorites_add_response() {
+        let body = json!({
+            "added": { "movies": 1, "shows": 1 },
+            "existing": { "movies": 0, "shows": 0 },
+            "not_found": {
+                "movies": [{ "ids": { "imdb": "tt0000111" } }],
+                "shows": []
+            },
+            "list": {
+                "updated_at": "2022-04-27T21:40:41.000Z",
+                "item_count": 2
+            }
+        });
+        let response = http::Response::builder()
+            .status(201)
+            .body(body.to_string())
+            .unwrap();
+        let response = favorites::add::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.added.movies, 1);
+        assert_eq!(response.0.added.shows, 1);
+        assert_eq!(response.0.added.episodes, 0);
+        assert_eq!(
+            response.0.not_found.movies[0].ids.imdb.as_deref(),
+            Some("tt0000111")
+        );
+        assert_eq!(response.0.list.unwrap().item_count, 2);
+    }
+
+    #[test]
+    fn favorites_remove() {
+        let req = favorites::remove::Request {
+            items: ItemsBody::new().show(Id::Trakt(1)),
+        };
+        let expected = json!({ "shows": [{ "ids": { "trakt": 1 } }] });
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/favorites/remove",
+            &expected,
+        );
+
+        let body = json!({
+            "deleted": { "movies": 0, "shows": 1 },
+            "not_found": { "movies": [], "shows": [] },
+            "list": {
+                "updated_at": "2022-04-27T21:40:41.000Z",
+                "item_count": 1
+            }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = favorites::remove::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.deleted.shows, 1);
+        assert!(response.0.not_found.shows.is_empty());
+    }
+
+    #[test]
+    fn ratings_get() {
+        let req = ratings::get::Request::default();
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/ratings?page=1&limit=10",
+            "",
+        );
+
+        let req = ratings::get::Request {
+            tp: Some(MediaType::Episodes),
+            rating: Some(10),
+            ..Default::default()
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/ratings/episodes/10?page=1&limit=10",
+            "",
+        );
+
+        let req = ratings::get::Request {
+            tp: Some(MediaType::Movies),
+            rating: Some(11),
+            ..Default::default()
+        };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
+
+        let req = ratings::get::Request {
+            rating: Some(10),
+            ..Default::default()
+        };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
+
+        let body = json!([
+            {
+                "rated_at": "2014-09-01T09:10:11.000Z",
+                "rating": 10,
+                "type": "movie",
+                "movie": trakt_fixtures::json(trakt_fixtures::MOVIE)
+            },
+            {
+                "rated_at": "2014-09-01T09:10:11.000Z",
+                "rating": 8,
+                "type": "episode",
+                "episode": trakt_fixtures::json(trakt_fixtures::EPISODE),
+                "show": trakt_fixtures::json(trakt_fixtures::SHOW)
+            }
+        ]);
+        let response = trakt_fixtures::paginated(body.to_string(), 1, 10, 1, 2);
+        let response = ratings::get::Response::try_from_http_response(response).unwrap();
+        let items = response.0.items();
+        assert_eq!(items[0].rating, 10);
+        assert!(matches!(items[1].item, ListedItem::Episode { .. }));
+    }
+
+    #[test]
+    fn ratings_add() {
+        let req = ratings::add::Request {
+            items: ItemsBody::new()
+                .movie_with(Id::Trakt(1), Rated::new(10))
+                .show_with(
+                    Id::Trakt(1),
+                    Rated {
+                        rating: 9,
+                        rated_at: Some(datetime!(2014-09-01 09:10:11 UTC)),
+                    },
+                ),
+        };
+        let expected = json!({
+            "movies": [{ "ids": { "trakt": 1 }, "rating": 10 }],
+            "shows": [{ "ids": { "trakt": 1 }, "rating": 9, "rated_at": "2014-09-01T09:10:11.000Z" }],
+        });
+        assert_eq!(serde_json::to_value(&req.items).unwrap(), expected);
+        let body = serde_json::to_string(&req.items).unwrap();
+        assert_request(CTX, req, "https://api.trakt.tv/sync/ratings", &body);
+
+        for rating in [0, 11] {
+            let req = ratings::add::Request {
+                items: ItemsBody::new().episode_with(Id::Trakt(16), Rated::new(rating)),
+            };
+            assert!(matches!(
+                req.try_into_http_request::<Vec<u8>>(CTX),
+                Err(IntoHttpError::Validation(_))
+            ));
+        }
+
+        let req = ratings::remove::Request {
+            items: ItemsBody::new().movie(Id::Trakt(1)),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/ratings/remove",
+            &json!({ "movies": [{ "ids": { "trakt": 1 } }] }),
+        );
+    }
+
+    #[test]
+    fn watchlist_get() {
+        let req = watchlist::get::Request::default();
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/watchlist?page=1&limit=10",
+            "",
+        );
+
+        let req = watchlist::get::Request {
+            tp: Some(MediaType::Shows),
+            sort: Some(watchlist::get::WatchlistSort::Added),
+            extended: Extended::FULL,
+            pagination: trakt_core::Pagination::new(2, 50),
+            ..Default::default()
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/watchlist/shows/added?extended=full&page=2&limit=50",
+            "",
+        );
+
+        let req = watchlist::get::Request {
+            tp: Some(MediaType::Movies),
+            sort: Some(watchlist::get::WatchlistSort::Runtime),
+            sort_how: Some(ListSortHow::Desc),
+            ..Default::default()
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/watchlist/movies/runtime/desc?page=1&limit=10",
+            "",
+        );
+
+        // Segments can't be given without the segment before them
+        let req = watchlist::get::Request {
+            tp: Some(MediaType::Movies),
+            sort_how: Some(ListSortHow::Asc),
+            ..Default::default()
+        };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
+        let req = watchlist::get::Request {
+            sort: Some(watchlist::get::WatchlistSort::Title),
+            ..Default::default()
+        };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
+
+        let body = json!([
+            {
+                "rank": 1,
+                "id": 101,
+                "listed_at": "2014-09-01T09:10:11.000Z",
+                "notes": null,
+                "type": "movie",
+                "movie": trakt_fixtures::json(trakt_fixtures::MOVIE)
+            }
+        ]);
+        let response = trakt_fixtures::paginated(body.to_string(), 1, 10, 1, 1);
+        let response = watchlist::get::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.items().len(), 1);
+        assert!(matches!(
+            &response.0.items()[0].item,
+            ListedItem::Movie { .. }
+        ));
+    }
+
+    #[test]
+    fn watchlist_modify() {
+        let req = watchlist::add::Request {
+            items: ItemsBody::new().show_with(
+                Id::Trakt(1),
+                Notes {
+                    notes: Some("Catch up before the finale".into()),
+                },
+            ),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/watchlist",
+            &json!({ "shows": [{ "ids": { "trakt": 1 }, "notes": "Catch up before the finale" }] }),
+        );
+
+        let req = watchlist::remove::Request {
+            items: ItemsBody::new().movie(Id::Trakt(1)),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/watchlist/remove",
+            &json!({ "movies": [{ "ids": { "trakt": 1 } }] }),
+        );
+
+        let req = watchlist::reorder::Request {
+            rank: vec![823, 224, 88],
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/watchlist/reorder",
+            r#"{"rank":[823,224,88]}"#,
+        );
+
+        let body = json!({
+            "updated": 3,
+            "skipped_ids": [],
+            "list": { "updated_at": "2022-04-27T21:40:41.000Z", "item_count": 3 }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = watchlist::reorder::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.updated, 3);
+        assert_eq!(response.0.list.map(|list| list.item_count), Some(3));
+    }
+
+    #[test]
+    fn history_get() {
+        let req = history::get::Request::default();
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/history?page=1&limit=10",
+            "",
+        );
+
+        let req = history::get::Request {
+            id: Some(1),
+            ..Default::default()
+        };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
+
+        let req = history::get::Request {
+            tp: Some(MediaType::Movies),
+            id: Some(1),
+            start_at: Some(datetime!(2016-06-01 00:00 UTC)),
+            end_at: Some(datetime!(2016-07-01 23:59:59 UTC)),
+            extended: Extended::FULL,
+            pagination: trakt_core::Pagination::new(2, 20),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/history/movies/1?start_at=2016-06-01T00%3A00%3A00.000Z&end_at=2016-07-01T23%3A59%3A59.000Z&extended=full&page=2&limit=20",
+            "",
+        );
+
+        let body = json!([
+            {
+                "id": 1_982_346,
+                "watched_at": "2014-03-31T09:28:53.000Z",
+                "action": "scrobble",
+                "type": "episode",
+                "episode": trakt_fixtures::json(trakt_fixtures::EPISODE),
+                "show": trakt_fixtures::json(trakt_fixtures::SHOW)
+            },
+            {
+                "id": 1_982_347,
+                "watched_at": "2014-03-31T09:28:53.000Z",
+                "action": "checkin",
+                "type": "movie",
+                "movie": trakt_fixtures::json(trakt_fixtures::MOVIE)
+            }
+        ]);
+        let response = trakt_fixtures::paginated(body.to_string(), 1, 10, 1, 2);
+        let response = history::get::Response::try_from_http_response(response).unwrap();
+        let items = response.0.items();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id, 1_982_346);
+        assert_eq!(items[0].action, WatchAction::Scrobble);
+        assert!(matches!(items[0].item, WatchingItem::Episode { .. }));
+        assert!(matches!(items[1].item, WatchingItem::Movie { .. }));
+    }
+
+    #[test]
+    fn history_add() {
+        let req = history::add::Request {
+            items: ItemsBody::new().movie(Id::Trakt(1)).episode_with(
+                Id::Trakt(16),
+                Watched {
+                    watched_at: Some(datetime!(2014-09-01 09:10:11 UTC)),
+                },
+            ),
+        };
+        let expected = json!({
+            "movies": [{ "ids": { "trakt": 1 } }],
+            "episodes": [{ "ids": { "trakt": 16 }, "watched_at": "2014-09-01T09:10:11.000Z" }],
+        });
+        assert_eq!(serde_json::to_value(&req.items).unwrap(), expected);
+        let body = serde_json::to_string(&req.items).unwrap();
+        assert_request(CTX, req, "https://api.trakt.tv/sync/history", &body);
+
+        let body = json!({
+            "added": { "movies": 1, "episodes": 1 },
+            "not_found": { "movies": [{ "ids": { "imdb": "tt0000111" } }] }
+        });
+        let response = http::Response::builder()
+            .status(201)
+            .body(body.to_string())
+            .unwrap();
+        let response = history::add::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.added.episodes, 1);
+        assert_eq!(response.0.not_found.movies.len(), 1);
+    }
+
+    #[test]
+    fn history_remove() {
+        let req = history::remove::Request {
+            items: ItemsBody::new().movie(Id::Trakt(1)),
+            ids: vec![1_982_346, 1_982_347],
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/history/remove",
+            r#"{"movies":[{"ids":{"trakt":1}}],"ids":[1982346,1982347]}"#,
+        );
+
+        let req = history::remove::Request {
+            ids: vec![1_982_346],
+            ..Default::default()
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/history/remove",
+            r#"{"ids":[1982346]}"#,
+        );
+
+        let body = json!({
+            "deleted": { "movies": 1, "episodes": 1 },
+            "not_found": { "movies": [], "ids": [23312] }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = history::remove::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.deleted.movies, 1);
+        assert_eq!(response.0.not_found.ids, [23312]);
+    }
+
+    #[test]
+    fn playback() {
+        let req = playback::get::Request::default();
+        assert_request(CTX, req, "https://api.trakt.tv/sync/playback", "");
+
+        let req = playback::get::Request {
+            tp: Some(playback::get::Type::Episodes),
+            start_at: Some(datetime!(2016-06-01 00:00 UTC)),
+            end_at: None,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/playback/episodes?start_at=2016-06-01T00%3A00%3A00.000Z",
+            "",
+        );
+
+        let body = json!([
+            {
+                "progress": 10.5,
+                "paused_at": "2015-01-25T22:01:32.000Z",
+                "id": 13,
+                "type": "movie",
+                "movie": trakt_fixtures::json(trakt_fixtures::MOVIE)
+            },
+            {
+                "progress": 65.5,
+                "paused_at": "2015-01-25T22:01:32.000Z",
+                "id": 37,
+                "type": "episode",
+                "episode": trakt_fixtures::json(trakt_fixtures::EPISODE),
+                "show": trakt_fixtures::json(trakt_fixtures::SHOW)
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = playback::get::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.len(), 2);
+        assert_eq!(response.0[0].id, 13);
+        assert!((response.0[1].progress - 65.5).abs() < f64::EPSILON);
+        assert!(matches!(response.0[1].item, WatchingItem::Episode { .. }));
+
+        let req = playback::remove::Request { id: 13 };
+        let http_req = req.try_into_http_request::<Vec<u8>>(CTX).unwrap();
+        assert_eq!(http_req.method(), http::Method::DELETE);
+        assert_eq!(http_req.uri(), "https://api.trakt.tv/sync/playback/13");
+
+        let response = http::Response::builder()
+            .status(204)
+            .body(String::new())
+            .unwrap();
+        assert!(playback::remove::Response::try_from_http_response(response).is_ok());
+    }
+
+    #[test]
+    fn favorites_reorder() {
+        let req = favorites::reorder::Request {
+            rank: vec![823, 224, 88],
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/sync/favorites/reorder",
+            r#"{"rank":[823,224,88]}"#,
+        );
+
+        let body = json!({
+            "updated": 2,
+            "skipped_ids": [12],
+            "list": {
+                "updated_at": "2022-04-27T21:40:41.000Z",
+                "item_count": 3
+            }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = favorites::reorder::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0.updated, 2);
+        assert_eq!(response.0.skipped_ids, [12]);
+    }
+
+    fn last_activities_body() -> serde_json::Value {
+        json!({
+            "all": "2014-11-20T07:01:32.000Z",
+            "movies": {
+                "watched_at": "2014-11-19T21:42:41.000Z",
+                "collected_at": "2014-11-20T06:51:30.000Z",
+                "rated_at": "2014-11-19T18:32:29.000Z",
+                "watchlisted_at": "2014-11-19T21:42:41.000Z",
+                "favorited_at": "2014-11-19T21:42:41.000Z",
+                "recommendations_at": "2014-11-19T21:42:41.000Z",
+                "commented_at": "2014-11-20T06:51:30.000Z",
+                "paused_at": "2014-11-20T06:51:30.000Z",
+                "hidden_at": "2016-08-20T06:51:30.000Z"
+            },
+            "episodes": {
+                "watched_at": "2014-11-20T06:51:30.000Z",
+                "collected_at": "2014-11-19T22:02:41.000Z",
+                "rated_at": "2014-11-20T06:51:30.000Z",
+                "watchlisted_at": "2014-11-20T06:51:30.000Z",
+                "commented_at": "2014-11-20T06:51:30.000Z",
+                "paused_at": "2014-11-20T06:51:30.000Z"
+            },
+            "shows": {
+                "rated_at": "2014-11-19T19:50:58.000Z",
+                "watchlisted_at": "2014-11-20T06:51:30.000Z",
+                "favorited_at": "2014-11-20T06:51:30.000Z",
+                "recommendations_at": "2014-11-20T06:51:30.000Z",
+                "commented_at": "2014-11-20T06:51:30.000Z",
+                "hidden_at": "2016-08-20T06:51:30.000Z"
+            },
+            "seasons": {
+                "rated_at": "2014-11-19T19:54:24.000Z",
+                "watchlisted_at": "2014-11-20T06:51:30.000Z",
+                "commented_at": "2014-11-20T06:51:30.000Z",
+                "hidden_at": "2016-08-20T06:51:30.000Z"
+            },
+            "comments": {
+                "liked_at": "2014-11-20T03:38:09.000Z",
+                "blocked_at": "2022-02-22T03:38:09.000Z"
+            },
+            "lists": {
+                "liked_at": "2014-11-20T00:36:48.000Z",
+                "updated_at": "2014-11-20T06:52:18.000Z",
+                "commented_at": "2014-11-20T06:51:30.000Z"
+            },
+            "watchlist": { "updated_at": "2014-11-20T06:52:18.000Z" },
+            "favorites": { "updated_at": "2014-11-20T06:52:18.000Z" },
+            "account": {
+                "settings_at": "2020-03-04T03:38:09.000Z",
+                "followed_at": "2020-03-04T03:38:09.000Z",
+                "following_at": "2020-03-04T03:38:09.000Z",
+                "pending_at": "2020-03-04T03:38:09.000Z",
+                "requested_at": "2022-04-27T03:38:09.000Z"
+            },
+            "saved_filters": { "updated_at": "2022-06-14T06:52:18.000Z" }
+        })
+    }
+
+    fn parse_last_activities(body: &serde_json::Value) -> last_activities::LastActivities {
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        last_activities::Response::try_from_http_response(response)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn last_activities() {
+        assert_request(
+            CTX,
+            last_activities::Request,
+            "https://api.trakt.tv/sync/last_activities",
+            "",
+        );
+
+        let activities = parse_last_activities(&last_activities_body());
+        assert_eq!(
+            activities.all,
+            time::macros::datetime!(2014-11-20 07:01:32 UTC)
+        );
+        assert_eq!(
+            activities.episodes.collected_at,
+            Some(time::macros::datetime!(2014-11-19 22:02:41 UTC))
+        );
+        assert_eq!(activities.seasons.watched_at, None);
+
+        let activities = parse_last_activities(&json!({ "all": "2014-11-20T07:01:32.000Z" }));
+        assert_eq!(
+            activities.movies,
+            last_activities::MediaActivities::default()
+        );
+    }
+
+    #[test]
+    fn watermark() {
+        let mut body = last_activities_body();
+        let activities = parse_last_activities(&body);
+
+        let mut watermark = Watermark::new();
+        assert_eq!(watermark.last_synced(), None);
+        assert_eq!(watermark.refresh(&activities).len(), 16);
+
+        watermark.update(activities.clone());
+        assert_eq!(watermark.last_synced(), Some(activities.all));
+        assert!(watermark.refresh(&activities).is_empty());
+
+        body["all"] = json!("2014-11-21T07:01:32.000Z");
+        body["episodes"]["collected_at"] = json!("2014-11-21T07:01:32.000Z");
+        body["shows"]["favorited_at"] = json!("2014-11-21T07:01:32.000Z");
+        body["episodes"]["watched_at"] = json!("2014-11-21T07:01:32.000Z");
+        body["seasons"]["rated_at"] = json!("2014-11-21T07:01:32.000Z");
+        body["shows"]["watchlisted_at"] = json!("2014-11-21T07:01:32.000Z");
+        body["movies"]["paused_at"] = json!("2014-11-21T07:01:32.000Z");
+        let activities = parse_last_activities(&body);
+        assert_eq!(
+            watermark.refresh(&activities),
+            [
+                Refresh::Collection(collection::get::Request {
+                    tp: ItemType::Shows,
+                    extended: Extended::empty(),
+                }),
+                Refresh::Favorites(favorites::get::Request {
+                    tp: Some(ItemType::Shows),
+                    sort: None,
+                }),
+                Refresh::History(history::get::Request {
+                    tp: Some(MediaType::Episodes),
+                    ..Default::default()
+                }),
+                Refresh::Ratings(ratings::get::Request {
+                    tp: Some(MediaType::Seasons),
+                    ..Default::default()
+                }),
+                Refresh::Watchlist(watchlist::get::Request {
+                    tp: Some(MediaType::Shows),
+                    ..Default::default()
+                }),
+                Refresh::Playback(playback::get::Request {
+                    tp: Some(playback::get::Type::Movies),
+                    ..Default::default()
+                }),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1972 @@
+//! User endpoints
+//!
+//! <https://trakt.docs.apiary.io/#reference/users>
+
+pub mod profile {
+    //! Get a user's profile
+    //!
+    //! Private profiles are only returned to the user themselves or their friends.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/profile/get-user-profile>
+
+    use crate::smo::{Extended, SmallString, User};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}",
+    example(
+        request = r#"Request { id: "sean".into(), extended: trakt_rs::smo::Extended::FULL }"#,
+        url = "https://api.trakt.tv/users/sean?extended=full",
+    ),
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub User);
+}
+
+pub mod watching {
+    //! Get what a user is watching right now
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/watching/get-watching>
+
+    use crate::smo::{SmallString, Watching};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/watching",
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+    }
+
+    /// What the user is watching, `None` if they aren't watching anything.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(empty = NO_CONTENT)]
+    pub struct Response(pub Option<Watching>);
+}
+
+pub mod follow {
+    //! Follow a user
+    //!
+    //! Following a private user sends a follow request that they must approve.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/follow/follow-this-user>
+
+    use crate::smo::{FollowResult, SmallString};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/follow",
+    method = POST,
+    auth = Required,
+    )]
+    pub struct Request {
+        /// Username slug of the user to follow.
+        pub id: SmallString,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = CREATED)]
+    pub struct Response(pub FollowResult);
+}
+
+pub mod unfollow {
+    //! Unfollow a user
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/follow/unfollow-this-user>
+
+    use crate::smo::SmallString;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/follow",
+    method = DELETE,
+    auth = Required,
+    )]
+    pub struct Request {
+        /// Username slug of the user to unfollow.
+        pub id: SmallString,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    #[trakt(expected = NO_CONTENT)]
+    pub struct Response;
+}
+
+pub mod followers {
+    //! Get a user's followers
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/followers/get-followers>
+
+    use trakt_core::{Pagination, PaginationResponse};
+
+    use crate::smo::{Extended, Follow, SmallString};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/followers",
+    example(
+        request = r#"Request { id: "sean".into(), extended: trakt_rs::smo::Extended::empty(), pagination: Default::default() }"#,
+        url = "https://api.trakt.tv/users/sean/followers?page=1&limit=10",
+    ),
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub extended: Extended,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response {
+        #[trakt(pagination)]
+        pub followers: PaginationResponse<Follow>,
+    }
+}
+
+pub mod following {
+    //! Get the users a user is following
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/following/get-following>
+
+    use trakt_core::{Pagination, PaginationResponse};
+
+    use crate::smo::{Extended, Follow, SmallString};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/following",
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub extended: Extended,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response {
+        #[trakt(pagination)]
+        pub following: PaginationResponse<Follow>,
+    }
+}
+
+pub mod friends {
+    //! Get the friends of a user
+    //!
+    //! Friends are users that follow each other.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/friends/get-friends>
+
+    use crate::smo::{Extended, Friend, SmallString};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/friends",
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<Friend>);
+}
+
+pub mod settings {
+    //! Get the settings of the authenticated user
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/settings/retrieve-settings>
+
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use crate::smo::User;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/settings",
+    example(
+        request = "Request",
+        url = "https://api.trakt.tv/users/settings",
+    ),
+    auth = Required,
+    )]
+    pub struct Request;
+
+    #[derive(Debug, Clone, Eq, PartialEq, Deserialize, trakt_macros::Response)]
+    pub struct Response {
+        pub user: User,
+        pub account: Account,
+        #[serde(default)]
+        pub connections: Connections,
+        #[serde(default)]
+        pub sharing_text: SharingText,
+        #[serde(default)]
+        pub limits: Limits,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct Account {
+        pub timezone: Option<String>,
+        pub date_format: Option<String>,
+        #[serde(default)]
+        pub time_24hr: bool,
+        pub cover_image: Option<String>,
+    }
+
+    /// Services connected to the account for sharing.
+    #[allow(clippy::struct_excessive_bools)]
+    #[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize)]
+    #[serde(default)]
+    pub struct Connections {
+        pub facebook: bool,
+        pub twitter: bool,
+        pub mastodon: bool,
+        pub google: bool,
+        pub tumblr: bool,
+        pub medium: bool,
+        pub slack: bool,
+        pub apple: bool,
+        pub dropbox: bool,
+        pub microsoft: bool,
+        /// Services not known to this library.
+        #[serde(flatten)]
+        pub other: HashMap<String, bool>,
+    }
+
+    /// Templates used when sharing to connected services.
+    ///
+    /// `[item]` is replaced with the movie or episode, and `[stars]` with the rating.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    #[serde(default)]
+    pub struct SharingText {
+        pub watching: Option<String>,
+        pub watched: Option<String>,
+        pub rated: Option<String>,
+    }
+
+    /// Account limits, which are higher for VIP users.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    #[serde(default)]
+    pub struct Limits {
+        pub list: ListLimit,
+        pub watchlist: Limit,
+        pub favorites: Limit,
+        pub recommendations: Limit,
+    }
+
+    /// Maximum number of items in a list-like collection.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    pub struct Limit {
+        pub item_count: u64,
+    }
+
+    impl Limit {
+        /// Returns the number of items that can still be added.
+        #[must_use]
+        pub const fn remaining(&self, count_in_use: u64) -> u64 {
+            self.item_count.saturating_sub(count_in_use)
+        }
+
+        /// Returns `true` if no more items can be added.
+        #[must_use]
+        pub const fn is_reached(&self, count_in_use: u64) -> bool {
+            count_in_use >= self.item_count
+        }
+    }
+
+    /// Maximum number of personal lists, and items in each list.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    pub struct ListLimit {
+        pub count: u64,
+        pub item_count: u64,
+    }
+
+    impl ListLimit {
+        /// Returns the number of lists that can still be created.
+        #[must_use]
+        pub const fn remaining(&self, count_in_use: u64) -> u64 {
+            self.count.saturating_sub(count_in_use)
+        }
+
+        /// Returns the number of items that can still be added to a list.
+        #[must_use]
+        pub const fn remaining_items(&self, count_in_use: u64) -> u64 {
+            self.item_count.saturating_sub(count_in_use)
+        }
+    }
+}
+
+pub mod collection {
+    //! Get a user's collection
+    //!
+    //! Use [`Extended::METADATA`] to include the media info of each collected item.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/collection/get-collection>
+
+    pub use crate::api::sync::{
+        collection::get::{
+            CollectedEpisode, CollectedMovie, CollectedSeason, CollectedShow, Response,
+            ResponseItem,
+        },
+        ItemType,
+    };
+    use crate::smo::{Extended, SmallString};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/collection/{tp}",
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub tp: ItemType,
+        pub extended: Extended,
+    }
+}
+
+pub mod history {
+    //! Get a user's watched history
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/history/get-watched-history>
+
+    use bytes::BufMut;
+    use serde::Serialize;
+    use time::OffsetDateTime;
+    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
+
+    pub use crate::api::sync::MediaType;
+    use crate::{
+        api::sync::history::get::{self as sync, RequestQueryParams},
+        smo::{Extended, HistoryItem, SmallString},
+    };
+
+    /// Request for a user's watched history.
+    ///
+    /// Plays of every type are returned if `tp` is `None`. `item_id` narrows the history down to a
+    /// single item by its trakt id, and can only be sent along with a type, so the request fails
+    /// to convert otherwise.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub tp: Option<MediaType>,
+        pub item_id: Option<u64>,
+        /// Only return plays watched at or after this time.
+        pub start_at: Option<OffsetDateTime>,
+        /// Only return plays watched at or before this time.
+        pub end_at: Option<OffsetDateTime>,
+        pub extended: Extended,
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct RequestPathParams<'a> {
+        id: &'a str,
+        tp: Option<MediaType>,
+        item_id: Option<u64>,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/users/{id}/history/{tp}/{item_id}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::Optional,
+        )
+        .with_name(trakt_core::endpoint_name!())
+        .with_optional_params(&["tp", "item_id"]);
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            let (path, query) = self.params()?;
+            trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
+        }
+
+        fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+            let (path, query) = self.params()?;
+            trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+        }
+    }
+
+    impl Request {
+        fn params(&self) -> Result<(RequestPathParams<'_>, RequestQueryParams), IntoHttpError> {
+            let (path, query) = sync::Request {
+                tp: self.tp,
+                id: self.item_id,
+                start_at: self.start_at,
+                end_at: self.end_at,
+                extended: self.extended,
+                pagination: self.pagination,
+            }
+            .params()?;
+            Ok((
+                RequestPathParams {
+                    id: &self.id,
+                    tp: path.tp,
+                    item_id: path.id,
+                },
+                query,
+            ))
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(#[trakt(pagination)] pub PaginationResponse<HistoryItem>);
+}
+
+pub mod ratings {
+    //! Get a user's ratings
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/ratings/get-ratings>
+
+    use bytes::BufMut;
+    use serde::Serialize;
+    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
+
+    pub use crate::api::sync::MediaType;
+    use crate::{
+        api::sync::ratings::get::{self as sync, RequestQueryParams},
+        smo::{Extended, RatedItem, SmallString},
+    };
+
+    /// Request for a user's ratings.
+    ///
+    /// Ratings of every type are returned if `tp` is `None`. `rating` only returns items with
+    /// that rating, and can only be sent along with a type, so the request fails to convert
+    /// otherwise.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub tp: Option<MediaType>,
+        pub rating: Option<u8>,
+        pub extended: Extended,
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct RequestPathParams<'a> {
+        id: &'a str,
+        tp: Option<MediaType>,
+        rating: Option<u8>,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/users/{id}/ratings/{tp}/{rating}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::Optional,
+        )
+        .with_name(trakt_core::endpoint_name!())
+        .with_optional_params(&["tp", "rating"]);
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            let (path, query) = self.params()?;
+            trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
+        }
+
+        fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+            let (path, query) = self.params()?;
+            trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+        }
+    }
+
+    impl Request {
+        fn params(&self) -> Result<(RequestPathParams<'_>, RequestQueryParams), IntoHttpError> {
+            let (path, query) = sync::Request {
+                tp: self.tp,
+                rating: self.rating,
+                extended: self.extended,
+                pagination: self.pagination,
+            }
+            .params()?;
+            Ok((
+                RequestPathParams {
+                    id: &self.id,
+                    tp: path.tp,
+                    rating: path.rating,
+                },
+                query,
+            ))
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(#[trakt(pagination)] pub PaginationResponse<RatedItem>);
+}
+
+pub mod watched {
+    //! Get the movies or shows a user has watched
+    //!
+    //! Use [`Extended::NOSEASONS`] to leave out the seasons of each show.
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/watched/get-watched>
+
+    use serde::Deserialize;
+    use time::OffsetDateTime;
+
+    pub use crate::api::sync::ItemType;
+    use crate::smo::{Extended, Movie, Show, SmallString};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/watched/{tp}",
+    example(
+        request = r#"Request { id: "me".into(), tp: ItemType::Movies, extended: trakt_rs::smo::Extended::empty() }"#,
+        url = "https://api.trakt.tv/users/me/watched/movies",
+    ),
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub tp: ItemType,
+        pub extended: Extended,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(pub Vec<ResponseItem>);
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    #[serde(untagged)]
+    #[non_exhaustive]
+    pub enum ResponseItem {
+        Movie(WatchedMovie),
+        Show(WatchedShow),
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct WatchedMovie {
+        pub plays: u64,
+        #[serde(with = "crate::datetime")]
+        pub last_watched_at: OffsetDateTime,
+        #[serde(with = "crate::datetime")]
+        pub last_updated_at: OffsetDateTime,
+        pub movie: Movie,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct WatchedShow {
+        pub plays: u64,
+        #[serde(with = "crate::datetime")]
+        pub last_watched_at: OffsetDateTime,
+        #[serde(with = "crate::datetime")]
+        pub last_updated_at: OffsetDateTime,
+        /// When the user last reset their watched progress, if ever.
+        #[serde(default, with = "crate::datetime::option")]
+        pub reset_at: Option<OffsetDateTime>,
+        pub show: Show,
+        /// Empty with [`Extended::NOSEASONS`].
+        #[serde(default)]
+        pub seasons: Vec<WatchedSeason>,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct WatchedSeason {
+        pub number: u16,
+        pub episodes: Vec<WatchedEpisode>,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+    pub struct WatchedEpisode {
+        pub number: u16,
+        pub plays: u64,
+        #[serde(with = "crate::datetime")]
+        pub last_watched_at: OffsetDateTime,
+    }
+}
+
+pub mod watchlist {
+    //! Get a user's watchlist
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/watchlist/get-watchlist>
+
+    use bytes::BufMut;
+    use serde::Serialize;
+    use trakt_core::{error::IntoHttpError, Context, Metadata, Pagination, PaginationResponse};
+
+    pub use crate::api::sync::{watchlist::get::WatchlistSort, MediaType};
+    use crate::{
+        api::sync::watchlist::get::{self as sync, RequestQueryParams},
+        smo::{Extended, ListItem, ListSortHow, SmallString},
+    };
+
+    /// Request for a user's watchlist.
+    ///
+    /// Items of every type are returned if `tp` is `None`. The path segments are optional
+    /// from left to right: the request fails to convert if `sort` is given without a type,
+    /// or `sort_how` without a `sort`.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+        pub tp: Option<MediaType>,
+        /// Sort order, the order of the user's watchlist settings if `None`.
+        pub sort: Option<WatchlistSort>,
+        pub sort_how: Option<ListSortHow>,
+        pub extended: Extended,
+        pub pagination: Pagination,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct RequestPathParams<'a> {
+        id: &'a str,
+        tp: Option<MediaType>,
+        sort: Option<WatchlistSort>,
+        sort_how: Option<ListSortHow>,
+    }
+
+    impl trakt_core::Request for Request {
+        type Response = Response;
+        const METADATA: Metadata = Metadata::new(
+            "/users/{id}/watchlist/{tp}/{sort}/{sort_how}",
+            http::Method::GET,
+            trakt_core::AuthRequirement::Optional,
+        )
+        .with_name(trakt_core::endpoint_name!())
+        .with_optional_params(&["tp", "sort", "sort_how"]);
+
+        fn try_into_http_request<T: Default + BufMut>(
+            self,
+            ctx: Context,
+        ) -> Result<http::Request<T>, IntoHttpError> {
+            let (path, query) = self.params()?;
+            trakt_core::construct_req(&ctx, &Self::METADATA, &path, &query, T::default())
+        }
+
+        fn uri(&self, ctx: Context) -> Result<http::Uri, IntoHttpError> {
+            let (path, query) = self.params()?;
+            trakt_core::construct_uri(&ctx, &Self::METADATA, &path, &query)
+        }
+    }
+
+    impl Request {
+        fn params(&self) -> Result<(RequestPathParams<'_>, RequestQueryParams), IntoHttpError> {
+            let (path, query) = sync::Request {
+                tp: self.tp,
+                sort: self.sort,
+                sort_how: self.sort_how,
+                extended: self.extended,
+                pagination: self.pagination,
+            }
+            .params()?;
+            Ok((
+                RequestPathParams {
+                    id: &self.id,
+                    tp: path.tp,
+                    sort: path.sort,
+                    sort_how: path.sort_how,
+                },
+                query,
+            ))
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+    pub struct Response(#[trakt(pagination)] pub PaginationResponse<ListItem>);
+}
+
+pub mod lists {
+    //! Manage a user's personal lists
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/lists>
+
+    use serde::Serialize;
+
+    use crate::smo::{Id, ListPrivacy, ListSortBy, ListSortHow};
+
+    /// Path of the lists of a user.
+    #[derive(Debug, Serialize)]
+    struct UserPath<'a> {
+        id: &'a str,
+    }
+
+    /// Path of a single list of a user.
+    #[derive(Debug, Serialize)]
+    struct ListPath<'a> {
+        id: &'a str,
+        list_id: &'a Id,
+    }
+
+    /// Settings of a list, sent when creating or updating it.
+    ///
+    /// Settings that are `None` are left out, so Trakt uses its defaults when creating a list and
+    /// keeps the current value when updating one.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+    pub struct ListSettings {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub privacy: Option<ListPrivacy>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub display_numbers: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allow_comments: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sort_by: Option<ListSortBy>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sort_how: Option<ListSortHow>,
+    }
+
+    /// Body of a request creating or updating a list.
+    #[derive(Debug, Serialize)]
+    struct ListBody<'a> {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<&'a str>,
+        #[serde(flatten)]
+        settings: &'a ListSettings,
+    }
+
+    pub mod get {
+        //! Get a user's personal lists
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/lists/get-a-user's-personal-lists>
+
+        use crate::smo::{List, SmallString};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/users/{id}/lists",
+        auth = Optional,
+        )]
+        pub struct Request {
+            /// Username slug of the user, or `me` for the authenticated user.
+            pub id: SmallString,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub Vec<List>);
+    }
+
+    pub mod create {
+        //! Create a personal list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/lists/create-personal-list>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use super::{ListBody, ListSettings, UserPath};
+        use crate::{
+            api::sync::construct_body_req,
+            smo::{List, SmallString},
+        };
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            /// Username slug of the user, or `me` for the authenticated user.
+            pub id: SmallString,
+            pub name: String,
+            pub settings: ListSettings,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/users/{id}/lists",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                if self.name.trim().is_empty() {
+                    return Err(IntoHttpError::Validation(
+                        "List name must not be empty".to_owned(),
+                    ));
+                }
+                let body = ListBody {
+                    name: Some(&self.name),
+                    settings: &self.settings,
+                };
+                construct_body_req(&ctx, &Self::METADATA, &UserPath { id: &self.id }, &body)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = CREATED)]
+        pub struct Response(pub List);
+    }
+
+    pub mod reorder {
+        //! Reorder a user's lists
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/reorder-lists/reorder-a-user's-lists>
+
+        use bytes::BufMut;
+        use serde_json::json;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use super::UserPath;
+        use crate::{
+            api::sync::{construct_body_req, ReorderSummary},
+            smo::SmallString,
+        };
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+        pub struct Request {
+            /// Username slug of the user, or `me` for the authenticated user.
+            pub id: SmallString,
+            /// Trakt ids of the lists in their new order.
+            pub rank: Vec<u64>,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/users/{id}/lists/reorder",
+                http::Method::POST,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let body = json!({ "rank": self.rank });
+                construct_body_req(&ctx, &Self::METADATA, &UserPath { id: &self.id }, &body)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub ReorderSummary);
+    }
+
+    pub mod summary {
+        //! Get a single personal list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/list/get-personal-list>
+
+        use crate::smo::{Id, List, SmallString};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/users/{id}/lists/{list_id}",
+        example(
+            request = r#"Request { id: "sean".into(), list_id: trakt_rs::smo::Id::Slug("star-wars-in-machete-order".parse().unwrap()) }"#,
+            url = "https://api.trakt.tv/users/sean/lists/star-wars-in-machete-order",
+        ),
+        auth = Optional,
+        )]
+        pub struct Request {
+            /// Username slug of the user, or `me` for the authenticated user.
+            pub id: SmallString,
+            /// Trakt ID or slug of the list.
+            pub list_id: Id,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub List);
+    }
+
+    pub mod update {
+        //! Update a personal list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/list/update-personal-list>
+
+        use bytes::BufMut;
+        use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+        use super::{ListBody, ListPath, ListSettings};
+        use crate::{
+            api::sync::construct_body_req,
+            smo::{Id, List, SmallString},
+        };
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+        pub struct Request {
+            /// Username slug of the user, or `me` for the authenticated user.
+            pub id: SmallString,
+            /// Trakt ID or slug of the list.
+            pub list_id: Id,
+            /// New name of the list, unchanged if `None`.
+            pub name: Option<String>,
+            pub settings: ListSettings,
+        }
+
+        impl trakt_core::Request for Request {
+            type Response = Response;
+            const METADATA: Metadata = Metadata::new(
+                "/users/{id}/lists/{list_id}",
+                http::Method::PUT,
+                trakt_core::AuthRequirement::Required,
+            )
+            .with_name(trakt_core::endpoint_name!());
+
+            fn try_into_http_request<T: Default + BufMut>(
+                self,
+                ctx: Context,
+            ) -> Result<http::Request<T>, IntoHttpError> {
+                let path = ListPath {
+                    id: &self.id,
+                    list_id: &self.list_id,
+                };
+                let body = ListBody {
+                    name: self.name.as_deref(),
+                    settings: &self.settings,
+                };
+                construct_body_req(&ctx, &Self::METADATA, &path, &body)
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        pub struct Response(pub List);
+    }
+
+    pub mod delete {
+        //! Delete a personal list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/list/delete-a-user's-personal-list>
+
+        use crate::smo::{Id, SmallString};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/users/{id}/lists/{list_id}",
+        method = DELETE,
+        auth = Required,
+        )]
+        pub struct Request {
+            /// Username slug of the user, or `me` for the authenticated user.
+            pub id: SmallString,
+            /// Trakt ID or slug of the list.
+            pub list_id: Id,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = NO_CONTENT)]
+        pub struct Response;
+    }
+
+    pub mod like {
+        //! Like a list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/list-like/like-a-list>
+
+        use crate::smo::{Id, SmallString};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/users/{id}/lists/{list_id}/like",
+        method = POST,
+        auth = Required,
+        )]
+        pub struct Request {
+            /// Username slug of the owner of the list.
+            pub id: SmallString,
+            /// Trakt ID or slug of the list.
+            pub list_id: Id,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = NO_CONTENT)]
+        pub struct Response;
+    }
+
+    pub mod remove_like {
+        //! Remove like from a list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/list-like/remove-like-on-a-list>
+
+        use crate::smo::{Id, SmallString};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/users/{id}/lists/{list_id}/like",
+        method = DELETE,
+        auth = Required,
+        )]
+        pub struct Request {
+            /// Username slug of the owner of the list.
+            pub id: SmallString,
+            /// Trakt ID or slug of the list.
+            pub list_id: Id,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+        #[trakt(expected = NO_CONTENT)]
+        pub struct Response;
+    }
+
+    pub mod likes {
+        //! Get users who liked a list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/list-likes/get-all-users-who-liked-a-list>
+
+        use trakt_core::Pagination;
+
+        pub use crate::api::comments::likes::{Response, ResponseItem};
+        use crate::smo::{Id, SmallString};
+
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+        #[trakt(
+        response = Response,
+        endpoint = "/users/{id}/lists/{list_id}/likes",
+        )]
+        pub struct Request {
+            /// Username slug of the owner of the list.
+            pub id: SmallString,
+            /// Trakt ID or slug of the list.
+            pub list_id: Id,
+            #[serde(flatten)]
+            pub pagination: Pagination,
+        }
+    }
+
+    pub mod items {
+        //! Manage the items of a personal list
+        //!
+        //! <https://trakt.docs.apiary.io/#reference/users/list-items>
+
+        pub mod get {
+            //! Get the items of a personal list
+            //!
+            //! <https://trakt.docs.apiary.io/#reference/users/list-items/get-items-on-a-personal-list>
+
+            use trakt_core::Pagination;
+
+            pub use crate::api::lists::{items::Response, ListItemType};
+            use crate::smo::{Extended, Id, SmallString};
+
+            #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+            #[trakt(
+            response = Response,
+            endpoint = "/users/{id}/lists/{list_id}/items/{tp}",
+            example(
+                request = r#"Request { id: "sean".into(), list_id: trakt_rs::smo::Id::Trakt(55), tp: trakt_rs::api::lists::ListItemType::empty(), extended: trakt_rs::smo::Extended::empty(), pagination: Default::default() }"#,
+                url = "https://api.trakt.tv/users/sean/lists/55/items?page=1&limit=10",
+            ),
+            auth = Optional,
+            )]
+            pub struct Request {
+                /// Username slug of the owner of the list.
+                pub id: SmallString,
+                /// Trakt ID or slug of the list.
+                pub list_id: Id,
+                /// Types of items to return, all types if empty.
+                #[trakt(optional)]
+                pub tp: ListItemType,
+                pub extended: Extended,
+                #[serde(flatten)]
+                pub pagination: Pagination,
+            }
+        }
+
+        pub mod add {
+            //! Add items to a personal list
+            //!
+            //! <https://trakt.docs.apiary.io/#reference/users/add-list-items/add-items-to-personal-list>
+
+            use bytes::BufMut;
+            use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+            use super::super::ListPath;
+            use crate::{
+                api::sync::{construct_body_req, AddSummary, ItemsBody, Notes},
+                smo::{Id, SmallString},
+            };
+
+            #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+            pub struct Request {
+                /// Username slug of the user, or `me` for the authenticated user.
+                pub id: SmallString,
+                /// Trakt ID or slug of the list.
+                pub list_id: Id,
+                pub items: ItemsBody<Notes>,
+            }
+
+            impl trakt_core::Request for Request {
+                type Response = Response;
+                const METADATA: Metadata = Metadata::new(
+                    "/users/{id}/lists/{list_id}/items",
+                    http::Method::POST,
+                    trakt_core::AuthRequirement::Required,
+                )
+                .with_name(trakt_core::endpoint_name!());
+
+                fn try_into_http_request<T: Default + BufMut>(
+                    self,
+                    ctx: Context,
+                ) -> Result<http::Request<T>, IntoHttpError> {
+                    let path = ListPath {
+                        id: &self.id,
+                        list_id: &self.list_id,
+                    };
+                    construct_body_req(&ctx, &Self::METADATA, &path, &self.items)
+                }
+            }
+
+            #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+            #[trakt(expected = CREATED)]
+            pub struct Response(pub AddSummary);
+        }
+
+        pub mod remove {
+            //! Remove items from a personal list
+            //!
+            //! <https://trakt.docs.apiary.io/#reference/users/remove-list-items/remove-items-from-personal-list>
+
+            use bytes::BufMut;
+            use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+            use super::super::ListPath;
+            use crate::{
+                api::sync::{construct_body_req, ItemsBody, RemoveSummary},
+                smo::{Id, SmallString},
+            };
+
+            #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+            pub struct Request {
+                /// Username slug of the user, or `me` for the authenticated user.
+                pub id: SmallString,
+                /// Trakt ID or slug of the list.
+                pub list_id: Id,
+                pub items: ItemsBody,
+            }
+
+            impl trakt_core::Request for Request {
+                type Response = Response;
+                const METADATA: Metadata = Metadata::new(
+                    "/users/{id}/lists/{list_id}/items/remove",
+                    http::Method::POST,
+                    trakt_core::AuthRequirement::Required,
+                )
+                .with_name(trakt_core::endpoint_name!());
+
+                fn try_into_http_request<T: Default + BufMut>(
+                    self,
+                    ctx: Context,
+                ) -> Result<http::Request<T>, IntoHttpError> {
+                    let path = ListPath {
+                        id: &self.id,
+                        list_id: &self.list_id,
+                    };
+                    construct_body_req(&ctx, &Self::METADATA, &path, &self.items)
+                }
+            }
+
+            #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+            pub struct Response(pub RemoveSummary);
+        }
+
+        pub mod reorder {
+            //! Reorder the items of a personal list
+            //!
+            //! <https://trakt.docs.apiary.io/#reference/users/reorder-list-items/reorder-items-on-a-list>
+
+            use bytes::BufMut;
+            use serde_json::json;
+            use trakt_core::{error::IntoHttpError, Context, Metadata};
+
+            use super::super::ListPath;
+            use crate::{
+                api::sync::{construct_body_req, ReorderSummary},
+                smo::{Id, SmallString},
+            };
+
+            #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+            pub struct Request {
+                /// Username slug of the user, or `me` for the authenticated user.
+                pub id: SmallString,
+                /// Trakt ID or slug of the list.
+                pub list_id: Id,
+                /// List item ids in their new order.
+                pub rank: Vec<u64>,
+            }
+
+            impl trakt_core::Request for Request {
+                type Response = Response;
+                const METADATA: Metadata = Metadata::new(
+                    "/users/{id}/lists/{list_id}/items/reorder",
+                    http::Method::POST,
+                    trakt_core::AuthRequirement::Required,
+                )
+                .with_name(trakt_core::endpoint_name!());
+
+                fn try_into_http_request<T: Default + BufMut>(
+                    self,
+                    ctx: Context,
+                ) -> Result<http::Request<T>, IntoHttpError> {
+                    let path = ListPath {
+                        id: &self.id,
+                        list_id: &self.list_id,
+                    };
+                    let body = json!({ "rank": self.rank });
+                    construct_body_req(&ctx, &Self::METADATA, &path, &body)
+                }
+            }
+
+            #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Response)]
+            pub struct Response(pub ReorderSummary);
+        }
+    }
+}
+
+pub mod stats {
+    //! Get the stats of a user
+    //!
+    //! <https://trakt.docs.apiary.io/#reference/users/stats/get-stats>
+
+    use serde::Deserialize;
+
+    use crate::smo::{Distribution, SmallString};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, trakt_macros::Request)]
+    #[trakt(
+    response = Response,
+    endpoint = "/users/{id}/stats",
+    example(
+        request = r#"Request { id: "sean".into() }"#,
+        url = "https://api.trakt.tv/users/sean/stats",
+    ),
+    auth = Optional,
+    )]
+    pub struct Request {
+        /// Username slug of the user, or `me` for the authenticated user.
+        pub id: SmallString,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Deserialize, trakt_macros::Response)]
+    #[serde(default)]
+    pub struct Response {
+        pub movies: MediaStats,
+        pub shows: MediaStats,
+        pub seasons: MediaStats,
+        pub episodes: MediaStats,
+        pub network: Network,
+        pub ratings: RatingStats,
+    }
+
+    impl Response {
+        /// Returns the number of plays of movies and episodes.
+        #[must_use]
+        pub const fn total_plays(&self) -> u64 {
+            self.movies.plays + self.episodes.plays
+        }
+
+        /// Returns the number of minutes spent watching movies and episodes.
+        #[must_use]
+        pub const fn total_minutes(&self) -> u64 {
+            self.movies.minutes + self.episodes.minutes
+        }
+
+        /// Returns the number of comments on movies, shows, seasons, and episodes.
+        #[must_use]
+        pub const fn total_comments(&self) -> u64 {
+            self.movies.comments
+                + self.shows.comments
+                + self.seasons.comments
+                + self.episodes.comments
+        }
+    }
+
+    /// Stats of a single media type.
+    ///
+    /// Stats that don't apply to the media type, e.g. plays of seasons, are `0`.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    #[serde(default)]
+    pub struct MediaStats {
+        pub plays: u64,
+        pub watched: u64,
+        pub minutes: u64,
+        pub collected: u64,
+        pub ratings: u64,
+        pub comments: u64,
+    }
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    #[serde(default)]
+    pub struct Network {
+        pub friends: u64,
+        pub followers: u64,
+        pub following: u64,
+    }
+
+    /// Ratings the user has given, across all media types.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+    #[serde(default)]
+    pub struct RatingStats {
+        pub total: u64,
+        pub distribution: Distribution,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trakt_core::{Context, Response as _};
+
+    use super::*;
+    use crate::test::assert_request;
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "client_id",
+        oauth_token: Some("token"),
+    };
+
+    #[test]
+    fn profile() {
+        use time::macros::datetime;
+
+        use crate::smo::Extended;
+
+        let req = profile::Request {
+            id: "sean".into(),
+            extended: Extended::empty(),
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/users/sean", "");
+
+        let req = profile::Request {
+            id: "me".into(),
+            extended: Extended::FULL,
+        };
+        assert_request(CTX, req, "https://api.trakt.tv/users/me?extended=full", "");
+
+        let body = json!({
+            "username": "sean",
+            "private": false,
+            "name": "Sean Rudford",
+            "vip": true,
+            "vip_ep": true,
+            "ids": { "slug": "sean" },
+            "joined_at": "2010-09-25T17:49:25.000Z",
+            "location": "SF",
+            "about": "I have all your cassette tapes.",
+            "gender": "male",
+            "age": 35,
+            "images": { "avatar": { "full": "https://walter.trakt.tv/hotlink-ok/placeholders/medium/fry.png" } },
+            "vip_og": true,
+            "vip_years": 5
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let user = profile::Response::try_from_http_response(response)
+            .unwrap()
+            .0;
+        assert_eq!(user.name.as_deref(), Some("Sean Rudford"));
+        assert_eq!(user.joined_at, Some(datetime!(2010-09-25 17:49:25 UTC)));
+        assert_eq!(user.age, Some(35));
+        assert_eq!(user.vip_og, Some(true));
+        assert_eq!(user.vip_years, Some(5));
+        assert_eq!(user.director, None);
+
+        // Public payloads may leave out everything but the username and ids
+        let body = json!({
+            "username": "anon",
+            "private": true,
+            "name": null,
+            "ids": { "slug": "anon" }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let user = profile::Response::try_from_http_response(response)
+            .unwrap()
+            .0;
+        assert_eq!(user.name, None);
+        assert!(!user.vip);
+        assert_eq!(user.joined_at, None);
+    }
+
+    #[test]
+    fn watching() {
+        use time::macros::datetime;
+
+        use crate::smo::{WatchAction, WatchingItem};
+
+        let req = watching::Request { id: "sean".into() };
+        assert_request(CTX, req, "https://api.trakt.tv/users/sean/watching", "");
+
+        let body = json!({
+            "expires_at": "2014-10-23T08:36:02.000Z",
+            "started_at": "2014-10-23T06:44:02.000Z",
+            "action": "checkin",
+            "type": "episode",
+            "episode": {
+                "season": 2,
+                "number": 12,
+                "title": "Burned",
+                "ids": { "trakt": 1, "tvdb": 4_801_670 }
+            },
+            "show": {
+                "title": "Breaking Bad",
+                "year": 2008,
+                "ids": { "trakt": 1, "slug": "breaking-bad" }
+            }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let watching = watching::Response::try_from_http_response(response)
+            .unwrap()
+            .0
+            .unwrap();
+        assert_eq!(watching.action, WatchAction::Checkin);
+        assert_eq!(watching.started_at, datetime!(2014-10-23 06:44:02 UTC));
+        assert_eq!(watching.expires_at, datetime!(2014-10-23 08:36:02 UTC));
+        let WatchingItem::Episode { episode, show } = watching.item else {
+            panic!("expected episode");
+        };
+        assert_eq!(episode.title, "Burned");
+        assert_eq!(show.title, "Breaking Bad");
+
+        let response = http::Response::builder().status(204).body("").unwrap();
+        let response = watching::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.0, None);
+
+        let response = http::Response::builder().status(404).body("").unwrap();
+        assert!(watching::Response::try_from_http_response(response).is_err());
+    }
+
+    #[test]
+    fn follow() {
+        let ctx = Context {
+            oauth_token: Some("token"),
+            ..CTX
+        };
+
+        let req = follow::Request { id: "sean".into() };
+        assert_request(ctx, req, "https://api.trakt.tv/users/sean/follow", "");
+
+        let user = trakt_fixtures::json(trakt_fixtures::USER);
+        let response = http::Response::builder()
+            .status(201)
+            .body(json!({ "approved_at": "2014-11-15T09:41:34.704Z", "user": user }).to_string())
+            .unwrap();
+        let response = follow::Response::try_from_http_response(response).unwrap();
+        assert!(!response.0.is_pending());
+        assert_eq!(response.0.user().username, "sean");
+
+        let response = http::Response::builder()
+            .status(201)
+            .body(json!({ "approved_at": null, "user": user }).to_string())
+            .unwrap();
+        let response = follow::Response::try_from_http_response(response).unwrap();
+        assert!(response.0.is_pending());
+
+        let req = unfollow::Request { id: "sean".into() };
+        assert_request(ctx, req, "https://api.trakt.tv/users/sean/follow", "");
+    }
+
+    #[test]
+    fn followers() {
+        use trakt_core::Pagination;
+
+        use crate::smo::Extended;
+
+        let req = followers::Request {
+            id: "sean".into(),
+            extended: Extended::FULL,
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/followers?extended=full&page=1&limit=10",
+            "",
+        );
+
+        let req = following::Request {
+            id: "sean".into(),
+            extended: Extended::empty(),
+            pagination: Pagination::new(2, 5),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/following?page=2&limit=5",
+            "",
+        );
+
+        let req = friends::Request {
+            id: "sean".into(),
+            extended: Extended::FULL,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/friends?extended=full",
+            "",
+        );
+
+        let body = json!([
+            {
+                "followed_at": "2014-09-01T09:10:11.000Z",
+                "user": trakt_fixtures::json(trakt_fixtures::USER)
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .header("X-Pagination-Page", "1")
+            .header("X-Pagination-Limit", "10")
+            .header("X-Pagination-Page-Count", "1")
+            .header("X-Pagination-Item-Count", "1")
+            .body(body.to_string())
+            .unwrap();
+        let response = followers::Response::try_from_http_response(response).unwrap();
+        assert_eq!(response.followers.items()[0].user.username, "sean");
+    }
+
+    #[test]
+    fn settings() {
+        assert_request(
+            CTX,
+            settings::Request,
+            "https://api.trakt.tv/users/settings",
+            "",
+        );
+
+        let body = json!({
+            "user": {
+                "username": "justin",
+                "private": false,
+                "name": "Justin Nemeth",
+                "vip": true,
+                "vip_ep": false,
+                "ids": { "slug": "justin" }
+            },
+            "account": {
+                "timezone": "America/Los_Angeles",
+                "date_format": "mdy",
+                "time_24hr": false,
+                "cover_image": "https://walter.trakt.tv/images/movies/000/001/545/fanarts/original/0abb604492.jpg"
+            },
+            "connections": {
+                "facebook": false,
+                "twitter": true,
+                "mastodon": true,
+                "google": true,
+                "tumblr": false,
+                "medium": false,
+                "slack": false,
+                "apple": false,
+                "dropbox": false,
+                "microsoft": false,
+                "bluesky": true
+            },
+            "sharing_text": {
+                "watching": "I'm watching [item]",
+                "watched": "I just watched [item]",
+                "rated": "[item] [stars]"
+            },
+            "limits": {
+                "list": { "count": 2, "item_count": 100 },
+                "watchlist": { "item_count": 100 },
+                "favorites": { "item_count": 100 },
+                "recommendations": { "item_count": 50 }
+            }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = settings::Response::try_from_http_response(response).unwrap();
+
+        assert_eq!(response.user.username, "justin");
+        assert!(response.connections.twitter);
+        assert!(!response.connections.facebook);
+        assert_eq!(response.connections.other.get("bluesky"), Some(&true));
+        assert_eq!(
+            response.sharing_text.watching.as_deref(),
+            Some("I'm watching [item]")
+        );
+
+        let limits = response.limits;
+        assert_eq!(limits.list.remaining(1), 1);
+        assert_eq!(limits.list.remaining(5), 0);
+        assert_eq!(limits.list.remaining_items(40), 60);
+        assert_eq!(limits.watchlist.remaining(30), 70);
+        assert!(!limits.watchlist.is_reached(99));
+        assert!(limits.recommendations.is_reached(50));
+    }
+
+    #[test]
+    fn collection_and_watched() {
+        use time::macros::datetime;
+
+        use crate::smo::Extended;
+
+        let req = collection::Request {
+            id: "sean".into(),
+            tp: collection::ItemType::Movies,
+            extended: Extended::METADATA,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/collection/movies?extended=metadata",
+            "",
+        );
+
+        let req = watched::Request {
+            id: "sean".into(),
+            tp: watched::ItemType::Shows,
+            extended: Extended::NOSEASONS,
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/watched/shows?extended=noseasons",
+            "",
+        );
+
+        let body = json!([
+            {
+                "plays": 56,
+                "last_watched_at": "2014-10-11T17:00:54.000Z",
+                "last_updated_at": "2014-10-11T17:00:54.000Z",
+                "reset_at": null,
+                "show": trakt_fixtures::json(trakt_fixtures::SHOW),
+                "seasons": [{
+                    "number": 1,
+                    "episodes": [{
+                        "number": 1,
+                        "plays": 1,
+                        "last_watched_at": "2014-10-11T17:00:54.000Z"
+                    }]
+                }]
+            },
+            {
+                "plays": 4,
+                "last_watched_at": "2014-10-11T17:00:54.000Z",
+                "last_updated_at": "2014-10-11T17:00:54.000Z",
+                "movie": trakt_fixtures::json(trakt_fixtures::MOVIE)
+            }
+        ]);
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = watched::Response::try_from_http_response(response).unwrap();
+
+        let watched::ResponseItem::Show(show) = &response.0[0] else {
+            panic!("expected a show");
+        };
+        assert_eq!(show.plays, 56);
+        assert_eq!(show.reset_at, None);
+        assert_eq!(
+            show.seasons[0].episodes[0].last_watched_at,
+            datetime!(2014-10-11 17:00:54 UTC)
+        );
+        assert!(matches!(&response.0[1], watched::ResponseItem::Movie(m) if m.plays == 4));
+    }
+
+    #[test]
+    fn history_ratings_watchlist() {
+        use time::macros::datetime;
+        use trakt_core::{error::IntoHttpError, Pagination, Request as _};
+
+        use crate::smo::ListSortHow;
+
+        let req = history::Request {
+            id: "sean".into(),
+            tp: Some(history::MediaType::Movies),
+            item_id: Some(12_601),
+            start_at: Some(datetime!(2016-06-01 00:00 UTC)),
+            ..Default::default()
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/history/movies/12601?start_at=2016-06-01T00%3A00%3A00.000Z&page=1&limit=10",
+            "",
+        );
+
+        let req = history::Request {
+            id: "sean".into(),
+            item_id: Some(12_601),
+            ..Default::default()
+        };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
+
+        let req = ratings::Request {
+            id: "sean".into(),
+            tp: Some(ratings::MediaType::Shows),
+            rating: Some(9),
+            ..Default::default()
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/ratings/shows/9?page=1&limit=10",
+            "",
+        );
+
+        let req = ratings::Request {
+            id: "sean".into(),
+            rating: Some(11),
+            ..Default::default()
+        };
+        assert!(req.try_into_http_request::<Vec<u8>>(CTX).is_err());
+
+        let req = watchlist::Request {
+            id: "sean".into(),
+            tp: Some(watchlist::MediaType::Movies),
+            sort: Some(watchlist::WatchlistSort::Added),
+            sort_how: Some(ListSortHow::Desc),
+            pagination: Pagination::new(2, 20),
+            ..Default::default()
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/watchlist/movies/added/desc?page=2&limit=20",
+            "",
+        );
+
+        let req = watchlist::Request {
+            id: "sean".into(),
+            tp: Some(watchlist::MediaType::Movies),
+            sort_how: Some(ListSortHow::Desc),
+            ..Default::default()
+        };
+        assert!(matches!(req.uri(CTX), Err(IntoHttpError::Validation(_))));
+    }
+
+    #[test]
+    fn lists() {
+        use trakt_core::{Pagination, Request as _};
+
+        use crate::smo::{Id, ListPrivacy, ListSortBy};
+
+        let req = lists::get::Request { id: "sean".into() };
+        assert_request(CTX, req, "https://api.trakt.tv/users/sean/lists", "");
+
+        let req = lists::create::Request {
+            id: "sean".into(),
+            name: "Star Wars in machete order".to_owned(),
+            settings: lists::ListSettings {
+                privacy: Some(ListPrivacy::Public),
+                sort_by: Some(ListSortBy::MyRating),
+                ..Default::default()
+            },
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/lists",
+            r#"{"name":"Star Wars in machete order","privacy":"public","sort_by":"my_rating"}"#,
+        );
+
+        let req = lists::create::Request {
+            id: "sean".into(),
+            name: " ".to_owned(),
+            settings: lists::ListSettings::default(),
+        };
+        assert!(req.try_into_http_request::<Vec<u8>>(CTX).is_err());
+
+        let req = lists::update::Request {
+            id: "sean".into(),
+            list_id: Id::Slug("star-wars-in-machete-order".parse().unwrap()),
+            name: None,
+            settings: lists::ListSettings {
+                allow_comments: Some(false),
+                ..Default::default()
+            },
+        };
+        let http_req: http::Request<Vec<u8>> = req.try_into_http_request(CTX).unwrap();
+        assert_eq!(http_req.method(), http::Method::PUT);
+        assert_eq!(
+            http_req.uri(),
+            "https://api.trakt.tv/users/sean/lists/star-wars-in-machete-order"
+        );
+        assert_eq!(http_req.body(), br#"{"allow_comments":false}"#);
+
+        let req = lists::reorder::Request {
+            id: "me".into(),
+            rank: vec![823, 224, 88_768],
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/me/lists/reorder",
+            r#"{"rank":[823,224,88768]}"#,
+        );
+
+        let req = lists::delete::Request {
+            id: "sean".into(),
+            list_id: Id::Trakt(55),
+        };
+        let http_req: http::Request<Vec<u8>> = req.try_into_http_request(CTX).unwrap();
+        assert_eq!(http_req.method(), http::Method::DELETE);
+        assert_eq!(http_req.uri(), "https://api.trakt.tv/users/sean/lists/55");
+
+        let req = lists::likes::Request {
+            id: "sean".into(),
+            list_id: Id::Trakt(55),
+            pagination: Pagination::default(),
+        };
+        let ctx = Context {
+            oauth_token: None,
+            ..CTX
+        };
+        assert_request(
+            ctx,
+            req,
+            "https://api.trakt.tv/users/sean/lists/55/likes?page=1&limit=10",
+            "",
+        );
+    }
+
+    #[test]
+    fn list_items() {
+        use trakt_core::Pagination;
+
+        use crate::{
+            api::sync::ItemsBody,
+            smo::{Extended, Id},
+        };
+
+        let req = lists::items::get::Request {
+            id: "sean".into(),
+            list_id: Id::Trakt(55),
+            tp: lists::items::get::ListItemType::MOVIE,
+            extended: Extended::empty(),
+            pagination: Pagination::default(),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/lists/55/items/movie?page=1&limit=10",
+            "",
+        );
+
+        let req = lists::items::add::Request {
+            id: "sean".into(),
+            list_id: Id::Trakt(55),
+            items: ItemsBody::new().movie(Id::Trakt(1)),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/lists/55/items",
+            r#"{"movies":[{"ids":{"trakt":1}}]}"#,
+        );
+
+        let req = lists::items::remove::Request {
+            id: "sean".into(),
+            list_id: Id::Trakt(55),
+            items: ItemsBody::new().show(Id::Trakt(2)),
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/lists/55/items/remove",
+            r#"{"shows":[{"ids":{"trakt":2}}]}"#,
+        );
+
+        let req = lists::items::reorder::Request {
+            id: "sean".into(),
+            list_id: Id::Trakt(55),
+            rank: vec![2, 1],
+        };
+        assert_request(
+            CTX,
+            req,
+            "https://api.trakt.tv/users/sean/lists/55/items/reorder",
+            r#"{"rank":[2,1]}"#,
+        );
+    }
+
+    #[test]
+    fn stats() {
+        let req = stats::Request { id: "sean".into() };
+        assert_request(CTX, req, "https://api.trakt.tv/users/sean/stats", "");
+
+        let body = json!({
+            "movies": {
+                "plays": 552,
+                "watched": 534,
+                "minutes": 91_383,
+                "collected": 117,
+                "ratings": 64,
+                "comments": 14
+            },
+            "shows": { "watched": 16, "collected": 7, "ratings": 63, "comments": 20 },
+            "seasons": { "ratings": 6, "comments": 1 },
+            "episodes": {
+                "plays": 2256,
+                "watched": 2100,
+                "minutes": 103_338,
+                "collected": 1238,
+                "ratings": 2,
+                "comments": 7
+            },
+            "network": { "friends": 1, "followers": 4, "following": 11 },
+            "ratings": {
+                "total": 135,
+                "distribution": {
+                    "1": 18, "2": 1, "3": 4, "4": 1, "5": 10,
+                    "6": 9, "7": 37, "8": 37, "9": 5, "10": 13
+                }
+            }
+        });
+        let response = http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+        let response = stats::Response::try_from_http_response(response).unwrap();
+
+        assert_eq!(response.seasons.plays, 0);
+        assert_eq!(response.network.following, 11);
+        assert_eq!(response.total_plays(), 2808);
+        assert_eq!(response.total_minutes(), 194_721);
+        assert_eq!(response.total_comments(), 42);
+
+        let distribution = &response.ratings.distribution;
+        assert_eq!(distribution.total(), response.ratings.total);
+        assert_eq!(distribution.get(7), Some(37));
+        assert_eq!(distribution.get(0), None);
+        assert_eq!(distribution.get(11), None);
+        assert!((distribution.mean().unwrap() - 6.444).abs() < 0.001);
+        assert_eq!(stats::RatingStats::default().distribution.mean(), None);
+    }
+}
@@ -0,0 +1,130 @@
+//! A minimal `curl`-style client for the Trakt.tv API.
+//!
+//! Executes a GET endpoint by name with `name=value` parameters, and prints the response body:
+//!
+//! ```text
+//! trakt-cli movies.summary id=tron-legacy-2010 extended=full
+//! ```
+//!
+//! Parameters of the endpoint path are filled in, and the rest are sent as query parameters.
+//! Optional path parameters that aren't given are omitted, along with the ones after them. Run
+//! `trakt-cli --list` to list the endpoints.
+//!
+//! The context is read from the environment with [`ContextBuf::from_env`].
+#![forbid(unsafe_code)]
+#![warn(
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::cargo,
+    clippy::as_underscore,
+    clippy::clone_on_ref_ptr,
+    clippy::format_push_string,
+    clippy::mod_module_files,
+    clippy::str_to_string
+)]
+
+use std::{collections::BTreeMap, error::Error, process::ExitCode};
+
+use http::Method;
+use trakt_rs::{api, construct_req, endpoint_params, trakt_core::ContextBuf, Metadata, Request};
+
+/// Endpoints of the `api` module, generated by the build script. Only the GET endpoints can be
+/// executed, looked up by [`Metadata::name`].
+const ENDPOINTS: &[Metadata] = include!(concat!(env!("OUT_DIR"), "/endpoints.rs"));
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+    let Some((name, params)) = args.split_first() else {
+        eprintln!("usage: trakt-cli <endpoint> [name=value ...]");
+        eprintln!("       trakt-cli --list");
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let mut endpoints = ENDPOINTS.iter().filter(|md| md.method == Method::GET);
+    if name == "--list" {
+        for md in endpoints {
+            println!("{:<32} {}", md.name(), md.endpoint);
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let md = endpoints
+        .find(|md| md.name() == *name)
+        .ok_or_else(|| format!("unknown endpoint `{name}`, see `trakt-cli --list`"))?;
+
+    let keys = endpoint_params(md.endpoint)?;
+    let mut path = keys
+        .iter()
+        .map(|&key| (key, None))
+        .collect::<BTreeMap<_, _>>();
+    let mut query = BTreeMap::new();
+    for param in params {
+        let (key, value) = param
+            .split_once('=')
+            .ok_or_else(|| format!("expected `name=value`, got `{param}`"))?;
+        match path.get_mut(key) {
+            Some(slot) => *slot = Some(value),
+            None => {
+                query.insert(key, value);
+            }
+        }
+    }
+    check_path(md, &keys, &path)?;
+
+    let ctx = ContextBuf::from_env()?;
+    let request = construct_req(&ctx.as_context(), md, &path, &query, Vec::new())?;
+    let (parts, body) = request.into_parts();
+
+    let response = match ureq::Request::from(parts).send_bytes(&body) {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => return Err(e.into()),
+    };
+    let response = http::Response::<Vec<u8>>::from(response);
+
+    // Pretty print JSON bodies
+    let body = response.body();
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(json) => println!("{json:#}"),
+        Err(_) => println!("{}", String::from_utf8_lossy(body)),
+    }
+
+    if response.status().is_success() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        eprintln!("error: {}", response.status());
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// Checks that every required path parameter is given, and that no parameter is given after an
+/// omitted one, as that would fill in the omitted segment instead.
+fn check_path(
+    md: &Metadata,
+    keys: &[&str],
+    path: &BTreeMap<&str, Option<&str>>,
+) -> Result<(), String> {
+    let mut omitted = None;
+    for &key in keys {
+        match (path[key], omitted) {
+            (None, _) if !md.optional_params().contains(&key) => {
+                return Err(format!("missing path parameter `{key}`"));
+            }
+            (None, None) => omitted = Some(key),
+            (Some(_), Some(omitted)) => {
+                return Err(format!("path parameter `{key}` requires `{omitted}`"));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,217 @@
+//! (De)serialization of the datetimes used by Trakt.
+//!
+//! Datetimes are always serialized as ISO 8601 in UTC, with a four digit year and millisecond
+//! precision (e.g. `2014-09-01T09:10:11.000Z`), the same format Trakt returns.
+//!
+//! With the `lenient-datetime` feature (enabled by default), deserialization also accepts the
+//! RFC 3339 variants Trakt occasionally returns, such as datetimes without fractional seconds,
+//! with non-UTC offsets, or without an offset at all, in which case UTC is assumed. Without the
+//! feature, only ISO 8601 is accepted.
+
+use std::num::NonZeroU8;
+
+use serde::{ser, Deserialize, Deserializer, Serializer};
+use time::{
+    format_description::well_known::{
+        iso8601::{Config, EncodedConfig, TimePrecision},
+        Iso8601,
+    },
+    OffsetDateTime, UtcOffset,
+};
+
+const SERIALIZE_CONFIG: EncodedConfig = Config::DEFAULT
+    .set_year_is_six_digits(false)
+    .set_time_precision(TimePrecision::Second {
+        decimal_digits: NonZeroU8::new(3),
+    })
+    .encode();
+
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let formatted = datetime
+        .to_offset(UtcOffset::UTC)
+        .format(&Iso8601::<SERIALIZE_CONFIG>)
+        .map_err(ser::Error::custom)?;
+    serializer.serialize_str(&formatted)
+}
+
+#[cfg(not(feature = "lenient-datetime"))]
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    time::serde::iso8601::deserialize(deserializer)
+}
+
+#[cfg(feature = "lenient-datetime")]
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    use std::fmt;
+
+    use serde::de;
+
+    struct Visitor;
+
+    impl de::Visitor<'_> for Visitor {
+        type Value = OffsetDateTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an ISO 8601 or RFC 3339 datetime")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            parse_lenient(v).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(Visitor)
+}
+
+/// Parses `s` as ISO 8601, falling back to RFC 3339 and datetimes without an offset.
+#[cfg(feature = "lenient-datetime")]
+fn parse_lenient(s: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    use time::{
+        format_description::well_known::{Iso8601, Rfc3339},
+        macros::format_description,
+        PrimitiveDateTime,
+    };
+
+    let err = match OffsetDateTime::parse(s, &Iso8601::DEFAULT) {
+        Ok(datetime) => return Ok(datetime),
+        Err(err) => err,
+    };
+    if let Ok(datetime) = OffsetDateTime::parse(s, &Rfc3339) {
+        return Ok(datetime);
+    }
+
+    let formats = [
+        format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]"
+        ),
+        format_description!(
+            "[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]"
+        ),
+    ];
+    formats
+        .iter()
+        .find_map(|format| PrimitiveDateTime::parse(s, format).ok())
+        .map(PrimitiveDateTime::assume_utc)
+        .ok_or(err)
+}
+
+/// Deserializes a datetime with [`deserialize`].
+struct DateTime(OffsetDateTime);
+
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+pub mod option {
+    //! (De)serialization of optional datetimes.
+
+    use serde::{Deserialize, Deserializer, Serializer};
+    use time::OffsetDateTime;
+
+    use super::DateTime;
+
+    // Signature required by `#[serde(with)]`
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        datetime: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match datetime {
+            Some(datetime) => super::serialize(datetime, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        Option::<DateTime>::deserialize(deserializer).map(|datetime| datetime.map(|dt| dt.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Test {
+        #[serde(with = "crate::datetime")]
+        at: OffsetDateTime,
+        #[serde(default, with = "crate::datetime::option")]
+        optional: Option<OffsetDateTime>,
+    }
+
+    fn parse(at: &str) -> Result<OffsetDateTime, serde_json::Error> {
+        serde_json::from_value::<Test>(serde_json::json!({ "at": at })).map(|test| test.at)
+    }
+
+    #[test]
+    fn test_iso8601() {
+        assert_eq!(
+            parse("2014-09-01T09:10:11.000Z").unwrap(),
+            datetime!(2014-09-01 09:10:11 UTC)
+        );
+
+        let test = Test {
+            at: datetime!(2014-09-01 09:10:11 UTC),
+            optional: None,
+        };
+        let json = serde_json::to_string(&test).unwrap();
+        assert_eq!(json, r#"{"at":"2014-09-01T09:10:11.000Z","optional":null}"#);
+        assert_eq!(serde_json::from_str::<Test>(&json).unwrap(), test);
+
+        let test = Test {
+            at: datetime!(2014-09-01 11:10:11.123_456 +02:00),
+            optional: Some(datetime!(2015-01-02 03:04:05 UTC)),
+        };
+        let json = serde_json::to_string(&test).unwrap();
+        assert_eq!(
+            json,
+            r#"{"at":"2014-09-01T09:10:11.123Z","optional":"2015-01-02T03:04:05.000Z"}"#
+        );
+
+        let test: Test =
+            serde_json::from_str(r#"{"at":"2014-09-01T09:10:11Z","optional":null}"#).unwrap();
+        assert_eq!(test.optional, None);
+        let test: Test = serde_json::from_str(
+            r#"{"at":"2014-09-01T09:10:11Z","optional":"2015-01-02T03:04:05.000Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(test.optional, Some(datetime!(2015-01-02 03:04:05 UTC)));
+
+        assert!(parse("not a date").is_err());
+        assert!(parse("2014-13-01T09:10:11Z").is_err());
+    }
+
+    #[cfg(feature = "lenient-datetime")]
+    #[test]
+    fn test_lenient() {
+        assert_eq!(
+            parse("2014-09-01T09:10:11Z").unwrap(),
+            datetime!(2014-09-01 09:10:11 UTC)
+        );
+        assert_eq!(
+            parse("2014-09-01T09:10:11.123456Z").unwrap(),
+            datetime!(2014-09-01 09:10:11.123_456 UTC)
+        );
+        assert_eq!(
+            parse("2014-09-01T11:10:11+02:00").unwrap(),
+            datetime!(2014-09-01 09:10:11 UTC)
+        );
+        assert_eq!(
+            parse("2014-09-01T09:10:11").unwrap(),
+            datetime!(2014-09-01 09:10:11 UTC)
+        );
+        assert_eq!(
+            parse("2014-09-01 09:10:11.000").unwrap(),
+            datetime!(2014-09-01 09:10:11 UTC)
+        );
+    }
+}
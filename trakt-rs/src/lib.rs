@@ -43,6 +43,7 @@
 //! // Create a request and convert it into an HTTP request
 //! let req = trakt_rs::api::movies::summary::Request {
 //!     id: trakt_rs::smo::Id::Imdb("tt123456".into()),
+//!     translations: None,
 //! };
 //! let http_req: http::Request<Vec<u8>> = req.try_into_http_request(ctx).unwrap();
 //!
@@ -54,6 +55,32 @@
 //!
 //! println!("Movie: {:?}", trakt_response.0);
 //! ```
+//!
+//! ### CLI
+//!
+//! The `cli` feature builds `trakt-cli`, a small client that executes GET endpoints by name.
+//! It uses `ureq` with `rustls`, and reads the client ID from `TRAKT_CLIENT_ID`.
+//!
+//! ```sh
+//! cargo install trakt-rs --features cli
+//! TRAKT_CLIENT_ID=client_id trakt-cli movies.summary id=tron-legacy-2010 extended=full
+//! ```
+//!
+//! ### Selecting Endpoints
+//!
+//! Each module of `api` has a cargo feature of the same name, e.g. `movies` or `sync`, and all of them
+//! are enabled by default through `all-apis`. To only compile the endpoints you use, disable the
+//! default features and enable the modules you need:
+//!
+//! ```toml
+//! trakt-rs = { version = "0.2", default-features = false, features = ["lenient-datetime", "movies", "sync"] }
+//! ```
+//!
+//! ### Async Runtimes
+//!
+//! Parsing large responses can block an async runtime for a noticeable amount of time.
+//! With the `tokio` feature, `spawn_parse` parses a response on tokio's blocking thread pool instead.
+#![forbid(unsafe_code)]
 #![warn(
     clippy::pedantic,
     clippy::nursery,
@@ -67,15 +94,129 @@
 #![allow(clippy::module_name_repetitions, clippy::redundant_pub_crate)]
 
 pub mod api;
+mod datetime;
 pub mod smo;
+#[cfg(feature = "users")]
+pub mod social;
+#[cfg(feature = "tokio")]
+mod spawn;
 #[cfg(test)]
 mod test;
 
+/// Derive macros used to define endpoints.
+///
+/// These are the same derives used by the endpoints in [`api`], and can be used to define
+/// endpoints that this crate does not cover.
+///
+/// When deriving outside of this crate, point the derives at the re-exported [`trakt_core`] with
+/// `#[trakt(crate = "trakt_rs::trakt_core")]` so that `trakt-core` doesn't need to be a direct
+/// dependency.
+///
+/// `#[trakt(example(request = "...", url = "..."))]` documents the `Request` impl with an example
+/// that constructs the request from the `request` expression and checks that its URL is `url`.
+/// The example is run as a doc test, so it stays in sync with the endpoint.
+///
+/// `Response` can also be derived for enums whose variants each wrap a single type. The body is
+/// deserialized into the first variant that matches it, which covers endpoints that return
+/// different shapes depending on the request.
+///
+/// `Response` also implements `TryFrom<http::Response<T>>`, so responses can be converted with
+/// `try_into()` and used where a `TryFrom` bound is expected.
+///
+/// # Example
+///
+/// ```
+/// use trakt_rs::{derive, smo::Id, Request};
+///
+/// #[derive(Debug, Clone, derive::Request)]
+/// #[trakt(
+///     crate = "trakt_rs::trakt_core",
+///     response = Response,
+///     endpoint = "/movies/{id}/undocumented",
+/// )]
+/// pub struct UndocumentedRequest {
+///     pub id: Id,
+///     pub limit: u32,
+/// }
+///
+/// #[derive(Debug, Clone, serde::Deserialize)]
+/// pub struct Item {
+///     pub name: String,
+/// }
+///
+/// #[derive(Debug, Clone, derive::Response)]
+/// #[trakt(crate = "trakt_rs::trakt_core")]
+/// pub struct Response(pub Vec<Item>);
+///
+/// let ctx = trakt_rs::Context {
+///     base_url: "https://api.trakt.tv",
+///     client_id: "client_id",
+///     oauth_token: None,
+/// };
+///
+/// let req = UndocumentedRequest {
+///     id: Id::Trakt(1),
+///     limit: 5,
+/// };
+/// let http_req: http::Request<Vec<u8>> = req.try_into_http_request(ctx).unwrap();
+/// assert_eq!(
+///     http_req.uri(),
+///     "https://api.trakt.tv/movies/1/undocumented?limit=5"
+/// );
+/// ```
+pub mod derive {
+    pub use trakt_macros::{Paginated, Request, Response};
+}
+
 pub use trakt_core::{
-    error, AuthRequirement, Context, EmojiString, Metadata, PaginatedResponse, Pagination,
-    PaginationResponse, Request, Response,
+    self, construct_req, construct_uri, construct_url, endpoint_params, error, handle_response,
+    handle_response_body, handle_response_body_with, handle_response_status, parse_from_header,
+    AuthRequirement, Context, EmojiString, Metadata, PaginatedResponse, Pagination,
+    PaginationResponse, ParseOptions, Request, RequestPlan, Response, ResponseContext, WithContext,
 };
 
+#[cfg(feature = "tokio")]
+pub use crate::spawn::spawn_parse;
+
+/// Error of a complete request/response round trip.
+///
+/// Wraps the errors of converting a request into an HTTP request and an HTTP response into a
+/// response, along with the error `E` of the HTTP client used to send the request. This allows
+/// using `?` on all three:
+///
+/// ```
+/// use trakt_rs::{api::movies::summary, smo::Id, Context, Request, Response};
+///
+/// fn send(req: http::Request<Vec<u8>>) -> Result<http::Response<Vec<u8>>, std::io::Error> {
+///     // Send the request using your preferred HTTP client
+///     # let body = br#"{"title":"TRON: Legacy","year":2010,"ids":{"trakt":1}}"#;
+///     # Ok(http::Response::builder().status(200).body(body.to_vec()).unwrap())
+/// }
+///
+/// fn movie(ctx: Context, id: Id) -> Result<summary::Response, trakt_rs::Error<std::io::Error>> {
+///     let req = summary::Request::new(id).try_into_http_request(ctx)?;
+///     let res = send(req).map_err(trakt_rs::Error::Transport)?;
+///     Ok(summary::Response::try_from_http_response(res)?)
+/// }
+///
+/// let ctx = Context {
+///     base_url: "https://api.trakt.tv",
+///     client_id: "client_id",
+///     oauth_token: None,
+/// };
+/// assert_eq!(movie(ctx, Id::Trakt(1)).unwrap().0.title, "TRON: Legacy");
+/// ```
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error<E = std::convert::Infallible> {
+    #[error("Request error: {0}")]
+    IntoHttp(#[from] error::IntoHttpError),
+    #[error("Response error: {0}")]
+    FromHttp(#[from] error::FromHttpError),
+    #[error("Transport error: {0}")]
+    Transport(#[source] E),
+}
+
 time::serde::format_description!(iso8601_date, Date, "[year]-[month]-[day]");
 
 #[cfg(test)]
@@ -112,4 +253,69 @@ mod tests {
             Date::from_calendar_date(2024, Month::April, 1).unwrap()
         );
     }
+
+    #[test]
+    #[cfg(feature = "all-apis")]
+    fn test_metadata_name() {
+        assert_eq!(
+            api::shows::trending::Request::METADATA.name(),
+            "shows.trending"
+        );
+        assert_eq!(
+            api::sync::favorites::get::Request::METADATA.name(),
+            "sync.favorites.get"
+        );
+        assert_eq!(api::auth::token::Request::METADATA.name(), "auth.token");
+    }
+
+    /// Requests whose fields all have sensible defaults, and so should implement `Default`.
+    #[test]
+    #[cfg(feature = "all-apis")]
+    fn test_requests_default() {
+        const CTX: trakt_core::Context = trakt_core::Context {
+            base_url: "https://api.trakt.tv",
+            client_id: "abc",
+            oauth_token: Some("token"),
+        };
+
+        macro_rules! assert_default {
+            ($($ty:ty => $url:literal),* $(,)?) => {
+                $(assert_eq!(
+                    trakt_core::Request::uri(&<$ty>::default(), CTX).unwrap(),
+                    $url,
+                    stringify!($ty),
+                );)*
+            };
+        }
+
+        assert_default!(
+            api::checkin::delete::Request => "https://api.trakt.tv/checkin",
+            api::movies::trending::Request => "https://api.trakt.tv/movies/trending?page=1&limit=10",
+            api::movies::popular::Request => "https://api.trakt.tv/movies/popular?page=1&limit=10",
+            api::movies::favorited::Request => "https://api.trakt.tv/movies/favorited?page=1&limit=10",
+            api::movies::played::Request => "https://api.trakt.tv/movies/played/weekly?page=1&limit=10",
+            api::movies::watched::Request => "https://api.trakt.tv/movies/watched/weekly?page=1&limit=10",
+            api::movies::collected::Request => "https://api.trakt.tv/movies/collected/weekly?page=1&limit=10",
+            api::movies::anticipated::Request => "https://api.trakt.tv/movies/anticipated?page=1&limit=10",
+            api::movies::boxoffice::Request => "https://api.trakt.tv/movies/boxoffice",
+            api::shows::trending::Request => "https://api.trakt.tv/shows/trending?page=1&limit=10",
+            api::shows::popular::Request => "https://api.trakt.tv/shows/popular?page=1&limit=10",
+            api::shows::favorited::Request => "https://api.trakt.tv/shows/favorited?page=1&limit=10",
+            api::shows::played::Request => "https://api.trakt.tv/shows/played/weekly?page=1&limit=10",
+            api::shows::watched::Request => "https://api.trakt.tv/shows/watched/weekly?page=1&limit=10",
+            api::shows::collected::Request => "https://api.trakt.tv/shows/collected/weekly?page=1&limit=10",
+            api::shows::anticipated::Request => "https://api.trakt.tv/shows/anticipated?page=1&limit=10",
+            api::networks::list::Request => "https://api.trakt.tv/networks",
+            api::recommendations::movies::Request => "https://api.trakt.tv/recommendations/movies?ignore_collected=false&ignore_watchlisted=false",
+            api::recommendations::shows::Request => "https://api.trakt.tv/recommendations/shows?ignore_collected=false&ignore_watchlisted=false",
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-apis")]
+    fn test_metadata_varies_with_auth() {
+        assert!(!api::shows::trending::Request::METADATA.varies_with_auth());
+        assert!(api::comments::get_replies::Request::METADATA.varies_with_auth());
+        assert!(api::users::settings::Request::METADATA.varies_with_auth());
+    }
 }
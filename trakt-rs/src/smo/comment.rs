@@ -0,0 +1,142 @@
+//! Comments, and the items they are on.
+
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use trakt_core::EmojiString;
+
+use super::{Episode, List, Movie, Season, Show, User};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Sort {
+    #[default]
+    Newest,
+    Oldest,
+    Likes,
+    Replies,
+    Highest,
+    Lowest,
+    Plays,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Comment {
+    pub id: u32,
+    pub parent_id: Option<u32>,
+    #[serde(with = "crate::datetime")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "crate::datetime")]
+    pub updated_at: OffsetDateTime,
+    pub comment: EmojiString,
+    pub spoiler: bool,
+    pub review: bool,
+    pub replies: u32,
+    pub likes: u32,
+    pub user_stats: UserStats,
+    pub user: User,
+    pub sharing: Option<Sharing>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct UserStats {
+    pub rating: u8,
+    pub play_count: u32,
+    pub completed_count: u32,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Sharing {
+    pub twitter: bool,
+    pub mastodon: bool,
+    pub tumblr: bool,
+}
+
+/// Optional fields sent along with the item of a checkin or scrobble request.
+///
+/// Fields that are `None` are left out.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize)]
+pub struct PlaybackBody {
+    /// Message shared along with the checkin or scrobble, if sharing is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Services to share the checkin or scrobble to, the user's defaults if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing: Option<Sharing>,
+    /// Version of the app sending the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_version: Option<String>,
+    /// Build date of the app sending the request.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "crate::iso8601_date::option"
+    )]
+    pub app_date: Option<Date>,
+}
+
+impl PlaybackBody {
+    /// Creates a body with all fields left out.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            message: None,
+            sharing: None,
+            app_version: None,
+            app_date: None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentType {
+    #[default]
+    All,
+    Reviews,
+    Shouts,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentItemType {
+    #[default]
+    All,
+    Movies,
+    Shows,
+    Seasons,
+    Episodes,
+    Lists,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub enum CommentWithItem {
+    Movie {
+        movie: Box<Movie>,
+        comment: Comment,
+    },
+    Show {
+        show: Box<Show>,
+        comment: Comment,
+    },
+    Season {
+        season: Box<Season>,
+        comment: Comment,
+    },
+    Episode {
+        episode: Box<Episode>,
+        comment: Comment,
+    },
+    List {
+        list: Box<List>,
+        comment: Comment,
+    },
+    /// Value added by Trakt after this version.
+    #[serde(other)]
+    Unknown,
+}
@@ -4,8 +4,9 @@ use serde::{
     de::{Error, MapAccess, SeqAccess, Unexpected},
     Deserialize, Deserializer,
 };
+use time::OffsetDateTime;
 
-use super::{Distribution, TwoLetter};
+use super::{Distribution, FollowResult, TwoLetter, User};
 
 impl<'de> Deserialize<'de> for TwoLetter {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -22,16 +23,16 @@ impl<'de> Deserialize<'de> for TwoLetter {
                 if value.len() != 2 {
                     return Err(E::invalid_length(value.len(), &"2"));
                 }
-                Ok(TwoLetter::new(value))
+                TwoLetter::try_new(value)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
             }
 
             fn visit_borrowed_bytes<E: Error>(self, v: &'a [u8]) -> Result<Self::Value, E> {
-                if v.len() != 2 {
+                let &[a, b] = v else {
                     return Err(E::invalid_length(v.len(), &"2"));
-                }
-                let s = std::str::from_utf8(v)
-                    .map_err(|_| E::invalid_value(Unexpected::Bytes(v), &self))?;
-                Ok(TwoLetter::new(s))
+                };
+                TwoLetter::from_bytes([a, b])
+                    .ok_or_else(|| E::invalid_value(Unexpected::Bytes(v), &self))
             }
         }
 
@@ -54,7 +55,7 @@ impl<'de> Deserialize<'de> for Distribution {
             {
                 struct FieldVisitor;
 
-                impl<'de> serde::de::Visitor<'de> for FieldVisitor {
+                impl serde::de::Visitor<'_> for FieldVisitor {
                     type Value = Field;
 
                     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
@@ -104,6 +105,9 @@ impl<'de> Deserialize<'de> for Distribution {
                 let mut distribution = [0; 10];
                 let mut i = 0;
                 while let Some(value) = seq.next_element()? {
+                    if i == distribution.len() {
+                        return Err(A::Error::invalid_length(i + 1, &self));
+                    }
                     distribution[i] = value;
                     i += 1;
                 }
@@ -123,6 +127,26 @@ impl<'de> Deserialize<'de> for Distribution {
     }
 }
 
+impl<'de> Deserialize<'de> for FollowResult {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            #[serde(default, with = "crate::datetime::option")]
+            approved_at: Option<OffsetDateTime>,
+            user: User,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(match raw.approved_at {
+            Some(approved_at) => Self::Followed {
+                approved_at,
+                user: raw.user,
+            },
+            None => Self::Pending { user: raw.user },
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -139,6 +163,15 @@ mod tests {
         let two: Result<TwoLetter, _> = serde_json::from_str(json);
         assert!(two.is_err());
 
+        // Not ASCII letters
+        let json = r#""é""#;
+        let two: Result<TwoLetter, _> = serde_json::from_str(json);
+        assert!(two.is_err());
+
+        let json = r#""1a""#;
+        let two: Result<TwoLetter, _> = serde_json::from_str(json);
+        assert!(two.is_err());
+
         let json = r#""deu""#;
         let two: Result<TwoLetter, _> = serde_json::from_str(json);
         assert!(two.is_err());
@@ -187,6 +220,10 @@ mod tests {
         let dist: Distribution = serde_json::from_value(json).unwrap();
         assert_eq!(dist, Distribution([1, 2, 3, 4, 5, 6, 7, 8, 9, 0]));
 
+        let json = json!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        let dist: Result<Distribution, _> = serde_json::from_value(json);
+        assert!(dist.is_err());
+
         let json = json!({
             "1": 1,
             "a": 2,
@@ -210,4 +247,57 @@ mod tests {
         let dist: Result<Distribution, _> = serde_json::from_value(json);
         assert!(dist.is_err());
     }
+
+    #[test]
+    fn follow_result() {
+        use time::macros::datetime;
+
+        let user = json!({ "username": "sean", "ids": { "slug": "sean" } });
+
+        let result: FollowResult = serde_json::from_value(json!({
+            "approved_at": "2014-11-15T09:41:34.704Z",
+            "user": user,
+        }))
+        .unwrap();
+        assert!(!result.is_pending());
+        assert_eq!(
+            result.approved_at(),
+            Some(datetime!(2014-11-15 09:41:34.704 UTC))
+        );
+        assert_eq!(result.user().username, "sean");
+
+        let result: FollowResult =
+            serde_json::from_value(json!({ "approved_at": null, "user": user })).unwrap();
+        assert!(result.is_pending());
+        assert_eq!(result.approved_at(), None);
+
+        let result: FollowResult = serde_json::from_value(json!({ "user": user })).unwrap();
+        assert!(result.is_pending());
+    }
+
+    #[test]
+    fn play_stats() {
+        use crate::smo::{Movie, PlayStats, Show};
+
+        let stats: PlayStats<Movie> = serde_json::from_value(json!({
+            "watcher_count": 66667,
+            "play_count": 109_736,
+            "collected_count": 27584,
+            "movie": trakt_fixtures::json(trakt_fixtures::MOVIE),
+        }))
+        .unwrap();
+        assert_eq!(stats.play_count, 109_736);
+        assert_eq!(stats.collector_count, None);
+        assert_eq!(stats.item.year, 2010);
+
+        let stats: PlayStats<Show> = serde_json::from_value(json!({
+            "watcher_count": 203_742,
+            "play_count": 8_784_154,
+            "collected_count": 1_845_508,
+            "collector_count": 71493,
+            "show": trakt_fixtures::json(trakt_fixtures::SHOW),
+        }))
+        .unwrap();
+        assert_eq!(stats.collector_count, Some(71493));
+    }
 }
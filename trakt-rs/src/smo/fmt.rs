@@ -0,0 +1,127 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::{Episode, Id, Movie, Period, Show, Slug, TwoLetter};
+
+impl Display for Movie {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.title, self.year)
+    }
+}
+
+impl Display for Show {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.title, self.year)
+    }
+}
+
+impl Display for Episode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "S{:02}E{:02} {}", self.season, self.number, self.title)
+    }
+}
+
+/// Formats the id the same way it appears in request URLs.
+impl Display for Id {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Trakt(id) | Self::Tvdb(id) | Self::Tmdb(id) => id.fmt(f),
+            Self::Slug(id) => f.write_str(id),
+            Self::Imdb(id) => f.write_str(id),
+        }
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+            Self::Yearly => "yearly",
+            Self::All => "all",
+        })
+    }
+}
+
+impl Display for Slug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Display for TwoLetter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_media_display() {
+        let movie: Movie = serde_json::from_value(json!({
+            "title": "TRON: Legacy",
+            "year": 2010,
+            "ids": {},
+        }))
+        .unwrap();
+        assert_eq!(movie.to_string(), "TRON: Legacy (2010)");
+
+        let show: Show = serde_json::from_value(json!({
+            "title": "Breaking Bad",
+            "year": 2008,
+            "ids": {},
+        }))
+        .unwrap();
+        assert_eq!(show.to_string(), "Breaking Bad (2008)");
+
+        let episode: Episode = serde_json::from_value(json!({
+            "season": 1,
+            "number": 2,
+            "title": "Cat's in the Bag...",
+            "ids": {},
+        }))
+        .unwrap();
+        assert_eq!(episode.to_string(), "S01E02 Cat's in the Bag...");
+
+        let episode: Episode = serde_json::from_value(json!({
+            "season": 12,
+            "number": 104,
+            "title": "Finale",
+            "ids": {},
+        }))
+        .unwrap();
+        assert_eq!(episode.to_string(), "S12E104 Finale");
+    }
+
+    #[test]
+    fn test_id_display() {
+        assert_eq!(Id::Trakt(1).to_string(), "1");
+        assert_eq!(
+            Id::Slug("tron-legacy-2010".parse().unwrap()).to_string(),
+            "tron-legacy-2010"
+        );
+        assert_eq!(Id::Imdb("tt1104001".into()).to_string(), "tt1104001");
+        assert_eq!(Id::Tmdb(20526).to_string(), "20526");
+    }
+
+    #[test]
+    fn test_period_display() {
+        assert_eq!(Period::Daily.to_string(), "daily");
+        assert_eq!(Period::All.to_string(), "all");
+        // Matches the serialized form used in URLs
+        assert_eq!(
+            serde_json::to_string(&Period::Monthly).unwrap(),
+            format!("\"{}\"", Period::Monthly)
+        );
+    }
+
+    #[test]
+    fn test_two_letter_display() {
+        assert_eq!(TwoLetter::new("us").to_string(), "us");
+    }
+}
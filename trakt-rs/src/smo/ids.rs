@@ -0,0 +1,296 @@
+//! Ids of items, and country and language codes.
+
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+
+use super::SmallString;
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Id {
+    Trakt(u64),
+    Slug(Slug),
+    Tvdb(u64),
+    Imdb(SmallString),
+    Tmdb(u64),
+}
+
+impl Id {
+    /// Creates a validated [`Id::Imdb`].
+    ///
+    /// IMDB ids consist of a 2 letter prefix followed by 7 or 8 digits. Titles (movies, shows, and
+    /// episodes) use the `tt` prefix, while people use the `nm` prefix.
+    ///
+    /// Use the [`Id::Imdb`] variant directly to skip validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdError::InvalidImdb`] if `id` is not a valid IMDB id.
+    pub fn imdb(id: &str) -> Result<Self, IdError> {
+        let digits = id
+            .strip_prefix("tt")
+            .or_else(|| id.strip_prefix("nm"))
+            .ok_or_else(|| IdError::InvalidImdb(id.into()))?;
+
+        if (7..=8).contains(&digits.len()) && digits.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(Self::Imdb(id.into()))
+        } else {
+            Err(IdError::InvalidImdb(id.into()))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum IdError {
+    #[error("Invalid IMDB id: {0}")]
+    InvalidImdb(SmallString),
+    #[error("Invalid slug: {0}")]
+    InvalidSlug(SmallString),
+}
+
+/// A URL-safe identifier of an item, list, or user, e.g. `tron-legacy-2010`.
+///
+/// Slugs created with [`Slug::new`] only contain lowercase ASCII letters, digits, hyphens, and
+/// underscores. Slugs returned by the API are not validated.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Slug(SmallString);
+
+impl Slug {
+    /// Creates a validated slug.
+    ///
+    /// Use [`Slug::normalize`] to create a slug from arbitrary text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdError::InvalidSlug`] if `slug` is empty or contains characters other than
+    /// lowercase ASCII letters, digits, hyphens, and underscores.
+    pub fn new(slug: &str) -> Result<Self, IdError> {
+        if !slug.is_empty() && slug.bytes().all(is_slug_byte) {
+            Ok(Self(slug.into()))
+        } else {
+            Err(IdError::InvalidSlug(slug.into()))
+        }
+    }
+
+    /// Creates a slug from arbitrary text, e.g. `"TRON: Legacy"` becomes `tron-legacy`.
+    ///
+    /// Letters are lowercased and every run of other characters, including hyphens, is replaced with
+    /// a single hyphen.
+    /// Leading and trailing hyphens are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdError::InvalidSlug`] if `text` has no characters that can be used in a slug.
+    pub fn normalize(text: &str) -> Result<Self, IdError> {
+        let mut slug = SmallString::default();
+        for c in text.chars().map(|c| c.to_ascii_lowercase()) {
+            if c != '-' && c.is_ascii() && is_slug_byte(c as u8) {
+                slug.push(c);
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        let len = slug.trim_end_matches('-').len();
+        slug.truncate(len);
+
+        if slug.is_empty() {
+            Err(IdError::InvalidSlug(text.into()))
+        } else {
+            Ok(Self(slug))
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+const fn is_slug_byte(b: u8) -> bool {
+    matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_')
+}
+
+impl Deref for Slug {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Slug {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::str::FromStr for Slug {
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&str> for Slug {
+    type Error = IdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Slug> for SmallString {
+    fn from(value: Slug) -> Self {
+        value.0
+    }
+}
+
+impl From<Id> for Ids {
+    fn from(value: Id) -> Self {
+        let mut ret = Self::default();
+        match value {
+            Id::Trakt(trakt) => ret.trakt = Some(trakt),
+            Id::Slug(slug) => ret.slug = Some(slug),
+            Id::Tvdb(tvdb) => ret.tvdb = Some(tvdb),
+            Id::Imdb(imdb) => ret.imdb = Some(imdb),
+            Id::Tmdb(tmdb) => ret.tmdb = Some(tmdb),
+        }
+        ret
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Ids {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trakt: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<Slug>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tvdb: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imdb: Option<SmallString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tmdb: Option<u64>,
+}
+
+/// 2-letter country code
+pub type Country = TwoLetter;
+
+/// 2-letter language code
+pub type Language = TwoLetter;
+
+/// 2-letter Codes
+///
+/// Always consists of 2 ASCII letters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TwoLetter([u8; 2]);
+
+impl TwoLetter {
+    /// Creates a `TwoLetter` from a 2 letter code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` isn't 2 ASCII letters. Use [`TwoLetter::try_new`] for untrusted input.
+    #[must_use]
+    pub const fn new(code: &str) -> Self {
+        match Self::try_new(code) {
+            Some(code) => code,
+            None => panic!("2 letter codes must consist of 2 ASCII letters"),
+        }
+    }
+
+    /// Creates a `TwoLetter` from a 2 letter code, returning `None` if `code` isn't 2 ASCII
+    /// letters.
+    #[must_use]
+    pub const fn try_new(code: &str) -> Option<Self> {
+        match code.as_bytes() {
+            &[a, b] => Self::from_bytes([a, b]),
+            _ => None,
+        }
+    }
+
+    /// Creates a `TwoLetter` from bytes, returning `None` if they aren't ASCII letters.
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 2]) -> Option<Self> {
+        if bytes[0].is_ascii_alphabetic() && bytes[1].is_ascii_alphabetic() {
+            Some(Self(bytes))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match std::str::from_utf8(&self.0) {
+            Ok(s) => s,
+            // Only ASCII letters are ever stored
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imdb_id() {
+        assert_eq!(Id::imdb("tt0111161"), Ok(Id::Imdb("tt0111161".into())));
+        assert_eq!(Id::imdb("tt10872600"), Ok(Id::Imdb("tt10872600".into())));
+        assert_eq!(Id::imdb("nm0000151"), Ok(Id::Imdb("nm0000151".into())));
+
+        for id in [
+            "",
+            "tt",
+            "111161",
+            "tt111161",
+            "tt123456789",
+            "xx0111161",
+            "tt01111a1",
+            "TT0111161",
+        ] {
+            assert_eq!(Id::imdb(id), Err(IdError::InvalidImdb(id.into())), "{id}");
+        }
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(
+            Slug::new("tron-legacy-2010").unwrap().as_str(),
+            "tron-legacy-2010"
+        );
+        assert_eq!("sean_r".parse::<Slug>().unwrap().as_str(), "sean_r");
+        for slug in ["", "TRON", "tron legacy", "tron/legacy", "trøn"] {
+            assert_eq!(
+                Slug::new(slug),
+                Err(IdError::InvalidSlug(slug.into())),
+                "{slug}"
+            );
+        }
+
+        assert_eq!(
+            Slug::normalize("TRON: Legacy").unwrap().as_str(),
+            "tron-legacy"
+        );
+        assert_eq!(
+            Slug::normalize("  The Lord of the Rings -- 2001 ")
+                .unwrap()
+                .as_str(),
+            "the-lord-of-the-rings-2001"
+        );
+        assert_eq!(Slug::normalize("Amélie").unwrap().as_str(), "am-lie");
+        assert_eq!(
+            Slug::normalize(" :: "),
+            Err(IdError::InvalidSlug(" :: ".into()))
+        );
+
+        // Slugs from the API aren't validated
+        let ids: Ids = serde_json::from_str(r#"{"slug":"Not A Slug"}"#).unwrap();
+        assert_eq!(ids.slug.as_deref(), Some("Not A Slug"));
+    }
+}
@@ -0,0 +1,171 @@
+//! Lists, and items in lists.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use trakt_core::EmojiString;
+
+use super::{Episode, Ids, Movie, Person, Season, Show, SmallString, User};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct List {
+    pub name: EmojiString,
+    pub description: EmojiString,
+    pub privacy: ListPrivacy,
+    pub share_link: String,
+    pub r#type: ListType,
+    pub display_numbers: bool,
+    pub allow_comments: bool,
+    pub sort_by: ListSortBy,
+    pub sort_how: ListSortHow,
+    #[serde(with = "crate::datetime")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "crate::datetime")]
+    pub updated_at: OffsetDateTime,
+    pub item_count: u64,
+    pub comment_count: u64,
+    pub likes: u64,
+    pub ids: Ids,
+    pub user: User,
+}
+
+/// An item in a list, e.g. a user's favorites or watchlist.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct ListItem {
+    pub rank: u32,
+    pub id: u64,
+    #[serde(with = "crate::datetime")]
+    pub listed_at: OffsetDateTime,
+    pub notes: Option<String>,
+    #[serde(flatten)]
+    pub item: ListedItem,
+}
+
+/// An item rated by a user.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct RatedItem {
+    #[serde(with = "crate::datetime")]
+    pub rated_at: OffsetDateTime,
+    /// Rating from 1 to 10.
+    pub rating: u8,
+    #[serde(flatten)]
+    pub item: ListedItem,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub enum ListedItem {
+    Movie {
+        movie: Box<Movie>,
+    },
+    Show {
+        show: Box<Show>,
+    },
+    Season {
+        season: Box<Season>,
+        show: Box<Show>,
+    },
+    Episode {
+        episode: Box<Episode>,
+        show: Box<Show>,
+    },
+    Person {
+        person: Box<Person>,
+    },
+    /// Value added by Trakt after this version.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ListType {
+    Personal,
+    Official,
+    Watchlist,
+    Favorites,
+    /// Value added by Trakt after this version.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ListSortBy {
+    Rank,
+    Added,
+    Title,
+    Released,
+    Runtime,
+    Popularity,
+    Percentage,
+    Votes,
+    MyRating,
+    Random,
+    Watched,
+    Collected,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ListSortHow {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ListPrivacy {
+    #[default]
+    Private,
+    Link,
+    Friends,
+    Public,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn listed_item_unknown() {
+        let item: ListedItem = serde_json::from_value(json!({ "type": "podcast" })).unwrap();
+        assert_eq!(item, ListedItem::Unknown);
+    }
+
+    #[test]
+    fn list_privacy_unknown() {
+        let privacy: ListPrivacy = serde_json::from_value(json!("public")).unwrap();
+        assert_eq!(privacy, ListPrivacy::Public);
+
+        let privacy: ListPrivacy = serde_json::from_value(json!("secret")).unwrap();
+        assert_eq!(privacy, ListPrivacy::Unknown("secret".into()));
+        assert_eq!(serde_json::to_value(&privacy).unwrap(), json!("secret"));
+    }
+
+    #[test]
+    fn list_sort_by_unknown() {
+        let sort: ListSortBy = serde_json::from_value(json!("my_rating")).unwrap();
+        assert_eq!(sort, ListSortBy::MyRating);
+
+        let sort: ListSortBy = serde_json::from_value(json!("loudness")).unwrap();
+        assert_eq!(sort, ListSortBy::Unknown("loudness".into()));
+        assert_eq!(serde_json::to_value(&sort).unwrap(), json!("loudness"));
+    }
+}
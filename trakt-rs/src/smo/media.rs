@@ -0,0 +1,449 @@
+//! Movies, shows, seasons, episodes, people, and their details.
+
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+
+use super::{Country, Ids, Language, List, Slug, SmallString};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Movie {
+    pub title: SmallString,
+    pub year: u16,
+    pub ids: Ids,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Show {
+    pub title: SmallString,
+    pub year: u16,
+    pub ids: Ids,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Season {
+    pub number: u16,
+    pub ids: Ids,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<SmallString>,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overview: Option<String>,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub episode_count: Option<u16>,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aired_episodes: Option<u16>,
+    /// Only present with [`Extended::FULL`], and `None` if the season hasn't aired yet.
+    #[serde(
+        default,
+        with = "crate::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub first_aired: Option<OffsetDateTime>,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<SmallString>,
+    /// Episodes of the season, only present with [`Extended::EPISODES`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub episodes: Option<Vec<Episode>>,
+}
+
+impl Season {
+    /// Whether this is the specials season, which Trakt numbers `0`.
+    ///
+    /// Specials are usually left out of progress, see e.g. the `specials` parameter of
+    /// [`watched_progress`](crate::api::shows::watched_progress).
+    #[must_use]
+    pub const fn is_special(&self) -> bool {
+        self.number == 0
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Episode {
+    pub season: u16,
+    pub number: u16,
+    pub title: SmallString,
+    pub ids: Ids,
+    /// Absolute number of the episode across all seasons, used by e.g. anime.
+    ///
+    /// Only present with [`Extended::FULL`], and `None` for shows without absolute numbering.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_abs: Option<u16>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Person {
+    pub name: SmallString,
+    pub ids: Ids,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub biography: Option<String>,
+    /// Only present with [`Extended::FULL`].
+    #[serde(
+        default,
+        with = "crate::iso8601_date::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub birthday: Option<Date>,
+    /// Only present with [`Extended::FULL`], and `None` if the person is alive.
+    #[serde(
+        default,
+        with = "crate::iso8601_date::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub death: Option<Date>,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthplace: Option<SmallString>,
+    /// Only present with [`Extended::FULL`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+}
+
+/// A value that applies to a single country, such as an alias or a certification.
+///
+/// Derefs to the value, so its fields can be accessed directly.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CountryTagged<T> {
+    #[serde(flatten)]
+    pub value: T,
+    /// `None` if Trakt doesn't know the country, e.g. for some translations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<Country>,
+}
+
+impl<T> CountryTagged<T> {
+    #[must_use]
+    pub const fn new(value: T, country: Country) -> Self {
+        Self {
+            value,
+            country: Some(country),
+        }
+    }
+}
+
+impl<T> Deref for CountryTagged<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// An alternative title of a movie or show in a country.
+pub type Alias = CountryTagged<AliasTitle>;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AliasTitle {
+    pub title: String,
+}
+
+/// A content certification of a movie or show in a country.
+pub type CountryCertification = CountryTagged<Certification>;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Certification {
+    pub certification: String,
+}
+
+/// A translation of a movie or show in a country.
+pub type CountryTranslation = CountryTagged<TranslationText>;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TranslationText {
+    pub title: Option<String>,
+    pub overview: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tagline: Option<String>,
+    pub language: Language,
+}
+
+/// A translation of the title and overview of a movie, show, or episode.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Translation {
+    pub title: Option<String>,
+    pub overview: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tagline: Option<String>,
+    pub language: Language,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<Country>,
+}
+
+/// An item along with the translations requested with the `translations` query parameter.
+///
+/// Derefs to the item, so its fields can be accessed directly.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Translated<T> {
+    #[serde(flatten)]
+    pub item: T,
+    /// Empty if no translations were requested, or none exist.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translations: Vec<Translation>,
+}
+
+impl<T> Deref for Translated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.item
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Studio {
+    pub name: SmallString,
+    pub country: Country,
+    pub ids: StudioIds,
+}
+
+/// Ids of a [`Studio`].
+///
+/// Unlike [`Ids`], studios only have trakt, slug and TMDB ids.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StudioIds {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trakt: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<Slug>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tmdb: Option<u64>,
+}
+
+/// A trailer, teaser, or other video of a movie or show.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Video {
+    pub title: String,
+    pub url: String,
+    pub site: VideoSite,
+    #[serde(rename = "type")]
+    pub tp: VideoType,
+    pub size: Option<u16>,
+    pub official: bool,
+    #[serde(with = "crate::datetime")]
+    pub published_at: OffsetDateTime,
+    pub country: Option<Country>,
+    pub language: Option<Language>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum VideoSite {
+    Youtube,
+    Vimeo,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum VideoType {
+    Trailer,
+    Teaser,
+    Clip,
+    Featurette,
+    Recap,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct EpisodeAirEvent {
+    #[serde(with = "crate::datetime")]
+    pub first_aired: OffsetDateTime,
+    pub episode: Episode,
+    pub show: Show,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct MovieReleaseEvent {
+    #[serde(with = "crate::iso8601_date")]
+    pub release_date: Date,
+    pub movie: Movie,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub enum Item {
+    Movie {
+        movie: Box<Movie>,
+    },
+    Show {
+        show: Box<Show>,
+    },
+    Season {
+        season: Box<Season>,
+    },
+    Episode {
+        episode: Box<Episode>,
+    },
+    List {
+        list: Box<List>,
+    },
+    Person {
+        person: Box<Person>,
+    },
+    /// Value added by Trakt after this version.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Item {
+    /// Title of the item, or the name of a list or person.
+    ///
+    /// `None` for seasons without a title and for unknown items.
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        match self {
+            Self::Movie { movie } => Some(&movie.title),
+            Self::Show { show } => Some(&show.title),
+            Self::Season { season } => season.title.as_deref(),
+            Self::Episode { episode } => Some(&episode.title),
+            Self::List { list } => Some(&list.name),
+            Self::Person { person } => Some(&person.name),
+            Self::Unknown => None,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// Extended info levels, requested with the `extended` query parameter.
+    ///
+    /// An empty set omits the parameter, returning the minimal info.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+    pub struct Extended: u8 {
+        const FULL = 0b0000_0001;
+        const EPISODES = 0b0000_0010;
+        const METADATA = 0b0000_0100;
+        const GUEST_STARS = 0b0000_1000;
+        const NOSEASONS = 0b0001_0000;
+    }
+}
+
+/// Media info of a collected item, returned with [`Extended::METADATA`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MediaMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<MediaFormat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<Resolution>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hdr: Option<Hdr>,
+    /// Audio codec, e.g. `dolby_atmos` or `dts_ma`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<SmallString>,
+    /// Audio channel layout, e.g. `5.1` or `7.1.4`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_channels: Option<SmallString>,
+    #[serde(rename = "3d", default)]
+    pub is_3d: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum MediaFormat {
+    Digital,
+    Bluray,
+    HdDvd,
+    Dvd,
+    Vcd,
+    Vhs,
+    Betamax,
+    LaserDisc,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Resolution {
+    #[serde(rename = "uhd_4k")]
+    Uhd4k,
+    #[serde(rename = "hd_1080p")]
+    Hd1080p,
+    #[serde(rename = "hd_1080i")]
+    Hd1080i,
+    #[serde(rename = "hd_720p")]
+    Hd720p,
+    #[serde(rename = "sd_480p")]
+    Sd480p,
+    #[serde(rename = "sd_480i")]
+    Sd480i,
+    #[serde(rename = "sd_576p")]
+    Sd576p,
+    #[serde(rename = "sd_576i")]
+    Sd576i,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Hdr {
+    DolbyVision,
+    Hdr10,
+    Hdr10Plus,
+    Hlg,
+    /// Value added by Trakt after this version.
+    #[serde(untagged)]
+    Unknown(SmallString),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn item_unknown() {
+        let item: Item = serde_json::from_value(json!({
+            "type": "podcast",
+            "podcast": { "title": "Unknown" }
+        }))
+        .unwrap();
+        assert_eq!(item, Item::Unknown);
+    }
+
+    #[test]
+    fn resolution_unknown() {
+        let resolution: Resolution = serde_json::from_value(json!("uhd_4k")).unwrap();
+        assert_eq!(resolution, Resolution::Uhd4k);
+
+        let resolution: Resolution = serde_json::from_value(json!("uhd_8k")).unwrap();
+        assert_eq!(resolution, Resolution::Unknown("uhd_8k".into()));
+        assert_eq!(serde_json::to_value(&resolution).unwrap(), json!("uhd_8k"));
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Serialize, Serializer};
 
-use super::TwoLetter;
+use super::{Extended, TwoLetter};
 
 impl Serialize for TwoLetter {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -8,6 +8,23 @@ impl Serialize for TwoLetter {
     }
 }
 
+impl Serialize for Extended {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Serialize as a comma-separated list of the flag names, dropping unknown bits
+        let joined = self
+            .iter_names()
+            .map(|(name, _)| name.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        if joined.is_empty() {
+            serializer.serialize_none()
+        } else {
+            serializer.serialize_str(&joined)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,4 +34,42 @@ mod tests {
         let json = serde_json::to_string(&TwoLetter::new("us")).unwrap();
         assert_eq!(json, "\"us\"");
     }
+
+    #[test]
+    fn test_serialize_extended() {
+        let json = serde_json::to_string(&Extended::FULL).unwrap();
+        assert_eq!(json, "\"full\"");
+
+        let json = serde_json::to_string(&(Extended::FULL | Extended::EPISODES)).unwrap();
+        assert_eq!(json, "\"full,episodes\"");
+
+        let json = serde_json::to_string(&(Extended::FULL | Extended::METADATA)).unwrap();
+        assert_eq!(json, "\"full,metadata\"");
+
+        let json = serde_json::to_string(&Extended::empty()).unwrap();
+        assert_eq!(json, "null");
+
+        let json = serde_json::to_string(&Extended::from_bits_retain(0b1000_0001)).unwrap();
+        assert_eq!(json, "\"full\"");
+
+        let json = serde_json::to_string(&Extended::from_bits_retain(0b1000_0000)).unwrap();
+        assert_eq!(json, "null");
+    }
+
+    #[test]
+    fn test_serialize_path_enums() {
+        use crate::smo::{CommentItemType, CommentType, Period, Sort};
+
+        let json = serde_json::to_string(&Period::All).unwrap();
+        assert_eq!(json, "\"all\"");
+
+        let json = serde_json::to_string(&Sort::Newest).unwrap();
+        assert_eq!(json, "\"newest\"");
+
+        let json = serde_json::to_string(&CommentType::Reviews).unwrap();
+        assert_eq!(json, "\"reviews\"");
+
+        let json = serde_json::to_string(&CommentItemType::Episodes).unwrap();
+        assert_eq!(json, "\"episodes\"");
+    }
 }
@@ -0,0 +1,125 @@
+//! Ratings, play counts, and other stats of items.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::SmallString;
+
+#[derive(
+    Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Period {
+    Daily,
+    #[default]
+    Weekly,
+    Monthly,
+    Yearly,
+    All,
+}
+
+/// Parses the lowercase form used in URLs, e.g. `weekly`, ignoring ASCII case.
+impl FromStr for Period {
+    type Err = ParsePeriodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Self::Daily,
+            Self::Weekly,
+            Self::Monthly,
+            Self::Yearly,
+            Self::All,
+        ]
+        .into_iter()
+        .find(|period| period.to_string().eq_ignore_ascii_case(s))
+        .ok_or_else(|| ParsePeriodError(s.into()))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+#[error("Invalid period: {0}")]
+pub struct ParsePeriodError(pub SmallString);
+
+/// Play, watch, and collection counts of a movie or show.
+///
+/// Returned by the most played, watched, and collected movies and shows, where `T` is
+/// [`Movie`](super::Movie) or [`Show`](super::Show).
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct PlayStats<T> {
+    pub watcher_count: u64,
+    pub play_count: u64,
+    pub collected_count: u64,
+    /// Only returned for shows.
+    #[serde(default)]
+    pub collector_count: Option<u64>,
+    #[serde(alias = "movie", alias = "show")]
+    pub item: T,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[non_exhaustive]
+pub struct Ratings {
+    pub rating: f32,
+    pub votes: u32,
+    /// All zeros if the API doesn't return a distribution, e.g. for items without votes.
+    #[serde(default)]
+    pub distribution: Distribution,
+}
+
+/// Number of votes for each rating from 1 to 10.
+///
+/// Deserializes from both a map keyed by rating and an array, see [`Ratings::distribution`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Distribution(pub [u32; 10]);
+
+impl Distribution {
+    /// Returns the number of votes for `rating`, or `None` if it isn't between 1 and 10.
+    #[must_use]
+    pub fn get(&self, rating: u8) -> Option<u32> {
+        let index = usize::from(rating).checked_sub(1)?;
+        self.0.get(index).copied()
+    }
+
+    /// Returns the total number of votes.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.0.iter().copied().map(u64::from).sum()
+    }
+
+    /// Returns the average rating, or `None` if there are no votes.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mean(&self) -> Option<f64> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+        let sum = (1..=10)
+            .zip(self.0)
+            .map(|(rating, votes)| rating * u64::from(votes))
+            .sum::<u64>();
+        Some(sum as f64 / total as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_period_from_str() {
+        assert_eq!("daily".parse(), Ok(Period::Daily));
+        assert_eq!("Monthly".parse(), Ok(Period::Monthly));
+        assert_eq!("ALL".parse(), Ok(Period::All));
+        assert_eq!(
+            "fortnightly".parse::<Period>(),
+            Err(ParsePeriodError("fortnightly".into()))
+        );
+
+        let period: Period = serde_json::from_str(r#""yearly""#).unwrap();
+        assert_eq!(period, Period::Yearly);
+        assert_eq!(period.to_string().parse(), Ok(period));
+    }
+}
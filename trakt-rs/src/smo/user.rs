@@ -0,0 +1,179 @@
+//! Users, and what they are watching or have watched.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use super::{Episode, Ids, Movie, Show, SmallString};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct User {
+    pub username: SmallString,
+    #[serde(default)]
+    pub private: bool,
+    /// Display name, `None` if the user hasn't set one.
+    #[serde(default)]
+    pub name: Option<SmallString>,
+    #[serde(default)]
+    pub vip: bool,
+    #[serde(default)]
+    pub vip_ep: bool,
+    pub ids: Ids,
+    /// Only present with [`Extended::FULL`](super::Extended::FULL).
+    #[serde(
+        default,
+        with = "crate::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub joined_at: Option<OffsetDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub about: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<SmallString>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age: Option<u16>,
+    /// Whether the user was a VIP before VIP was a paid subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vip_og: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vip_years: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub director: Option<bool>,
+}
+
+/// A user following, or followed by, another user.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Follow {
+    #[serde(with = "crate::datetime")]
+    pub followed_at: OffsetDateTime,
+    pub user: User,
+}
+
+/// A user that follows, and is followed by, another user.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Friend {
+    #[serde(with = "crate::datetime")]
+    pub friends_at: OffsetDateTime,
+    pub user: User,
+}
+
+/// Result of following a user.
+///
+/// Following a private user creates a follow request that is pending until they approve it.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum FollowResult {
+    /// The user is now followed.
+    Followed {
+        approved_at: OffsetDateTime,
+        user: User,
+    },
+    /// The follow request is waiting for the user's approval.
+    Pending { user: User },
+}
+
+impl FollowResult {
+    /// Returns the user that was followed.
+    #[must_use]
+    pub const fn user(&self) -> &User {
+        match self {
+            Self::Followed { user, .. } | Self::Pending { user } => user,
+        }
+    }
+
+    /// Returns when the follow was approved, or `None` if it's pending.
+    #[must_use]
+    pub const fn approved_at(&self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Followed { approved_at, .. } => Some(*approved_at),
+            Self::Pending { .. } => None,
+        }
+    }
+
+    /// Returns `true` if the follow request is waiting for approval.
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending { .. })
+    }
+}
+
+/// What a user is doing, or did, with a movie or episode.
+///
+/// Returned by scrobbles, and by the watching and history endpoints.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum WatchAction {
+    Start,
+    Pause,
+    Scrobble,
+    Checkin,
+    Watch,
+    /// Value added by Trakt after this version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A movie or episode a user is watching right now.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Watching {
+    #[serde(with = "crate::datetime")]
+    pub started_at: OffsetDateTime,
+    /// When the checkin or scrobble expires if it isn't stopped.
+    #[serde(with = "crate::datetime")]
+    pub expires_at: OffsetDateTime,
+    pub action: WatchAction,
+    #[serde(flatten)]
+    pub item: WatchingItem,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub enum WatchingItem {
+    Movie {
+        movie: Box<Movie>,
+    },
+    Episode {
+        episode: Box<Episode>,
+        show: Box<Show>,
+    },
+    /// Value added by Trakt after this version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A play of a movie or episode in a user's watched history.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct HistoryItem {
+    /// History id, used to remove the play from the history.
+    pub id: u64,
+    #[serde(with = "crate::datetime")]
+    pub watched_at: OffsetDateTime,
+    pub action: WatchAction,
+    #[serde(flatten)]
+    pub item: WatchingItem,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn watch_action_unknown() {
+        let action: WatchAction = serde_json::from_value(json!("checkin")).unwrap();
+        assert_eq!(action, WatchAction::Checkin);
+
+        let action: WatchAction = serde_json::from_value(json!("rewind")).unwrap();
+        assert_eq!(action, WatchAction::Unknown);
+    }
+}
@@ -0,0 +1,173 @@
+//! Social feed of what a user's friends are watching.
+//!
+//! Trakt has no endpoint for this, so the feed is built from a
+//! [`friends`](crate::api::users::friends) response and a
+//! [`watching`](crate::api::users::watching) request per friend:
+//!
+//! 1. Prepare the watching requests with [`requests`] and send them (e.g. concurrently).
+//! 2. Merge the responses, in the same order, into a feed with [`feed`].
+
+use std::cmp::Reverse;
+
+use bytes::BufMut;
+use time::OffsetDateTime;
+use trakt_core::{
+    error::{FromHttpError, IntoHttpError},
+    Context,
+};
+
+use crate::{
+    api::users::watching,
+    smo::{Friend, SmallString, User, Watching},
+};
+
+/// A friend and what they are watching right now.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FeedEntry {
+    pub user: User,
+    pub watching: Watching,
+}
+
+impl FeedEntry {
+    /// When the friend started watching.
+    #[must_use]
+    pub const fn started_at(&self) -> OffsetDateTime {
+        self.watching.started_at
+    }
+}
+
+/// Prepares a [`watching`] request for each of the given friends.
+///
+/// The returned requests are in the same order as `friends`.
+///
+/// # Errors
+///
+/// Returns an error if any of the requests fail to be constructed.
+pub fn requests<T: Default + BufMut>(
+    ctx: Context,
+    friends: &[Friend],
+) -> Result<Vec<http::Request<T>>, IntoHttpError> {
+    use trakt_core::Request as _;
+
+    friends
+        .iter()
+        .map(|friend| {
+            let id = friend
+                .user
+                .ids
+                .slug
+                .clone()
+                .map_or_else(|| friend.user.username.clone(), SmallString::from);
+            watching::Request { id }.try_into_http_request(ctx)
+        })
+        .collect()
+}
+
+/// Merges the responses of requests prepared by [`requests`] into a feed.
+///
+/// `responses` must be in the same order as `friends`. Friends that aren't watching anything are
+/// left out, and the feed is sorted by when each friend started watching, most recent first.
+///
+/// # Errors
+///
+/// Returns an error if any of the responses fail to be parsed.
+pub fn feed<T, I>(friends: Vec<Friend>, responses: I) -> Result<Vec<FeedEntry>, FromHttpError>
+where
+    T: AsRef<[u8]>,
+    I: IntoIterator<Item = http::Response<T>>,
+{
+    use trakt_core::Response as _;
+
+    let mut feed = Vec::new();
+    for (friend, response) in friends.into_iter().zip(responses) {
+        if let Some(watching) = watching::Response::try_from_http_response(response)?.0 {
+            feed.push(FeedEntry {
+                user: friend.user,
+                watching,
+            });
+        }
+    }
+    feed.sort_by_key(|entry| Reverse(entry.started_at()));
+
+    Ok(feed)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const CTX: Context = Context {
+        base_url: "https://api.trakt.tv",
+        client_id: "client_id",
+        oauth_token: None,
+    };
+
+    fn friend(username: &str, slug: Option<&str>) -> Friend {
+        let mut user = trakt_fixtures::json(trakt_fixtures::USER);
+        user["username"] = json!(username);
+        user["ids"]["slug"] = json!(slug);
+        serde_json::from_value(json!({
+            "friends_at": "2014-09-01T09:10:11.000Z",
+            "user": user,
+        }))
+        .unwrap()
+    }
+
+    fn watching(started_at: &str) -> http::Response<String> {
+        let body = json!({
+            "started_at": started_at,
+            "expires_at": "2014-10-23T08:36:02.000Z",
+            "action": "scrobble",
+            "type": "movie",
+            "movie": trakt_fixtures::json(trakt_fixtures::MOVIE),
+        });
+        http::Response::builder()
+            .status(200)
+            .body(body.to_string())
+            .unwrap()
+    }
+
+    #[test]
+    fn social_feed() {
+        let friends = vec![
+            friend("justin", Some("justin")),
+            friend("Sean Rudford", None),
+            friend("phil", Some("phil")),
+        ];
+
+        let reqs = requests::<Vec<u8>>(CTX, &friends).unwrap();
+        let urls = reqs.iter().map(|r| r.uri().to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            [
+                "https://api.trakt.tv/users/justin/watching",
+                "https://api.trakt.tv/users/Sean%20Rudford/watching",
+                "https://api.trakt.tv/users/phil/watching",
+            ]
+        );
+
+        let responses = [
+            watching("2014-10-23T06:44:02.000Z"),
+            http::Response::builder()
+                .status(204)
+                .body(String::new())
+                .unwrap(),
+            watching("2014-10-23T07:44:02.000Z"),
+        ];
+        let entries = feed(friends.clone(), responses).unwrap();
+        let users = entries
+            .iter()
+            .map(|e| e.user.username.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(users, ["phil", "justin"]);
+        assert!(entries[0].started_at() > entries[1].started_at());
+
+        let responses = [http::Response::builder()
+            .status(404)
+            .body(String::new())
+            .unwrap()];
+        assert!(feed(friends, responses).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+//! Parsing responses off of the async runtime.
+
+use trakt_core::{error::FromHttpError, Response};
+
+/// Parses `response` on tokio's blocking thread pool.
+///
+/// Deserializing very large bodies (e.g. a user's full watched history) can take long enough
+/// to stall other tasks on the runtime. This moves [`Response::try_from_http_response`] onto
+/// [`tokio::task::spawn_blocking`] and resolves to its result.
+///
+/// ```
+/// use trakt_rs::api::movies::summary;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let body = br#"{"title":"TRON: Legacy","year":2010,"ids":{"trakt":1}}"#.to_vec();
+/// let response = http::Response::builder().status(200).body(body).unwrap();
+///
+/// let response: summary::Response = trakt_rs::spawn_parse(response).await.unwrap();
+/// assert_eq!(response.0.title, "TRON: Legacy");
+/// # });
+/// ```
+///
+/// # Errors
+///
+/// Returns the error of [`Response::try_from_http_response`].
+///
+/// # Panics
+///
+/// Must be polled from within a tokio runtime. Panics if parsing panics, or if the runtime shuts
+/// down before parsing completes.
+pub async fn spawn_parse<R, T>(response: http::Response<T>) -> Result<R, FromHttpError>
+where
+    R: Response + Send + 'static,
+    T: AsRef<[u8]> + Send + 'static,
+{
+    match tokio::task::spawn_blocking(move || R::try_from_http_response(response)).await {
+        Ok(res) => res,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => panic!("response parsing was cancelled: {err}"),
+    }
+}
+
+#[cfg(all(test, feature = "users"))]
+mod tests {
+    use std::future::Future;
+
+    use super::*;
+    use crate::api::users::profile;
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(fut)
+    }
+
+    #[test]
+    fn spawn_parse_response() {
+        let response = http::Response::builder()
+            .status(200)
+            .body(trakt_fixtures::USER)
+            .unwrap();
+        let response: profile::Response = block_on(spawn_parse(response)).unwrap();
+        assert_eq!(response.0.username, "sean");
+
+        let response = http::Response::builder().status(404).body("").unwrap();
+        let response = block_on(spawn_parse::<profile::Response, _>(response));
+        assert!(matches!(response, Err(FromHttpError::Api(_))));
+    }
+}
@@ -1,7 +1,7 @@
-use trakt_core::{
-    error::{FromHttpError, IntoHttpError},
-    Context, Request, Response,
-};
+// Which helpers are used depends on the enabled API features.
+#![cfg_attr(not(feature = "all-apis"), allow(dead_code))]
+
+use trakt_core::{Context, Request, Response};
 
 pub fn assert_request<R, T>(ctx: Context, req: R, exp_url: &str, exp_body: &T)
 where
@@ -39,33 +39,12 @@ pub fn execute<R: Request>(ctx: Context, req: R) -> Result<R::Response, Error> {
     let (parts, body) = request.into_parts();
     let request = ureq::Request::from(parts);
 
-    let response = request.send_bytes(&body)?;
+    let response = request
+        .send_bytes(&body)
+        .map_err(|e| Error::Transport(Box::new(e)))?;
     let http_res: http::Response<Vec<u8>> = http::Response::from(response);
 
     Ok(Response::try_from_http_response(http_res)?)
 }
 
-#[derive(Debug)]
-pub enum Error {
-    Reqwest(Box<ureq::Error>),
-    IntoHttp(IntoHttpError),
-    FromHttp(FromHttpError),
-}
-
-impl From<ureq::Error> for Error {
-    fn from(e: ureq::Error) -> Self {
-        Self::Reqwest(Box::new(e))
-    }
-}
-
-impl From<IntoHttpError> for Error {
-    fn from(e: IntoHttpError) -> Self {
-        Self::IntoHttp(e)
-    }
-}
-
-impl From<FromHttpError> for Error {
-    fn from(e: FromHttpError) -> Self {
-        Self::FromHttp(e)
-    }
-}
+pub type Error = crate::Error<Box<ureq::Error>>;
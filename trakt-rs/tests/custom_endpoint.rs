@@ -0,0 +1,311 @@
+//! Defines endpoints outside of `trakt-rs` using the re-exported derives.
+
+use trakt_rs::{derive, smo::Id, Context, PaginatedResponse, Pagination, PaginationResponse};
+
+const CTX: Context = Context {
+    base_url: "https://api.trakt.tv",
+    client_id: "client_id",
+    oauth_token: Some("token"),
+};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Item {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, derive::Request)]
+#[trakt(
+    crate = "trakt_rs::trakt_core",
+    response = ItemResponse,
+    endpoint = "/custom/{id}/items",
+    auth = Required,
+)]
+pub struct ItemRequest {
+    pub id: Id,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Clone, derive::Response)]
+#[trakt(crate = "trakt_rs::trakt_core")]
+pub struct ItemResponse {
+    #[trakt(pagination)]
+    pub items: PaginationResponse<Item>,
+}
+
+#[derive(Debug, Clone, derive::Response)]
+#[trakt(crate = "trakt_rs::trakt_core")]
+pub struct ItemListResponse(#[trakt(pagination)] pub PaginationResponse<Item>);
+
+#[derive(Debug, Clone, derive::Request)]
+#[trakt(
+    crate = "trakt_rs::trakt_core",
+    response = ItemResponse,
+    endpoint = "/custom/{list_id}/search",
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchRequest {
+    pub list_id: u64,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    #[serde(serialize_with = "serialize_none_as_empty")]
+    pub query: Option<String>,
+}
+
+fn serialize_none_as_empty<S: serde::Serializer>(
+    value: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(value.as_deref().unwrap_or_default())
+}
+
+#[derive(Debug, Clone, derive::Request)]
+#[trakt(
+    crate = "trakt_rs::trakt_core",
+    response = DeleteResponse,
+    endpoint = "/custom/{id}",
+    method = DELETE,
+    auth = Required,
+)]
+pub struct DeleteRequest {
+    pub id: Id,
+}
+
+#[derive(Debug, Clone, derive::Response)]
+#[trakt(crate = "trakt_rs::trakt_core", expected = NO_CONTENT)]
+pub struct DeleteResponse;
+
+#[derive(Debug, Clone, derive::Response)]
+#[trakt(crate = "trakt_rs::trakt_core")]
+pub enum ShapeResponse {
+    Many(Vec<Item>),
+    One(Item),
+}
+
+#[derive(Debug, Clone, derive::Response)]
+#[trakt(crate = "trakt_rs::trakt_core", expected = CREATED)]
+pub enum CreatedShapeResponse {
+    One(Item),
+}
+
+#[test]
+fn custom_request() {
+    use trakt_rs::Request;
+
+    let req = ItemRequest {
+        id: Id::Slug("abc".parse().unwrap()),
+        pagination: Pagination::new(2, 5),
+    };
+    let http_req: http::Request<Vec<u8>> = req.try_into_http_request(CTX).unwrap();
+    assert_eq!(
+        http_req.uri(),
+        "https://api.trakt.tv/custom/abc/items?page=2&limit=5"
+    );
+    assert_eq!(http_req.method(), http::Method::GET);
+    assert_eq!(
+        http_req.headers().get("Authorization").unwrap(),
+        "Bearer token"
+    );
+
+    let req = DeleteRequest { id: Id::Trakt(1) };
+    let http_req: http::Request<Vec<u8>> = req.try_into_http_request(CTX).unwrap();
+    assert_eq!(http_req.uri(), "https://api.trakt.tv/custom/1");
+    assert_eq!(http_req.method(), http::Method::DELETE);
+
+    // URIs don't need the token
+    let req = ItemRequest {
+        id: Id::Trakt(1),
+        pagination: Pagination::new(1, 10),
+    };
+    let ctx = trakt_rs::Context {
+        oauth_token: None,
+        ..CTX
+    };
+    assert_eq!(
+        req.uri(ctx).unwrap(),
+        "https://api.trakt.tv/custom/1/items?page=1&limit=10"
+    );
+    assert!(req.try_into_http_request::<Vec<u8>>(ctx).is_err());
+
+    assert_eq!(ItemRequest::METADATA.name(), "custom_endpoint");
+    assert!(ItemRequest::METADATA.optional_params().is_empty());
+}
+
+#[test]
+fn custom_request_serde_attrs() {
+    use trakt_rs::Request;
+
+    let req = SearchRequest {
+        list_id: 1,
+        start_date: Some("2024-01-01".into()),
+        end_date: None,
+        query: None,
+    };
+    assert_eq!(
+        req.uri(CTX).unwrap(),
+        "https://api.trakt.tv/custom/1/search?startDate=2024-01-01&query="
+    );
+}
+
+#[test]
+fn custom_response() {
+    use trakt_rs::Response;
+
+    let response = http::Response::builder()
+        .status(200)
+        .header("X-Pagination-Page", "2")
+        .header("X-Pagination-Limit", "1")
+        .header("X-Pagination-Page-Count", "3")
+        .header("X-Pagination-Item-Count", "3")
+        .body(r#"[{"name":"item"}]"#)
+        .unwrap();
+    let response = ItemResponse::try_from_http_response(response).unwrap();
+    assert_eq!(response.items().len(), 1);
+    assert_eq!(response.items()[0].name, "item");
+    assert_eq!(response.next_page(), Some(Pagination::new(3, 1)));
+
+    let response = http::Response::builder()
+        .status(200)
+        .header("X-Pagination-Page", "1")
+        .header("X-Pagination-Limit", "1")
+        .header("X-Pagination-Page-Count", "3")
+        .header("X-Pagination-Item-Count", "3")
+        .body(r#"[{"name":"item"}]"#)
+        .unwrap();
+    let response = ItemListResponse::try_from_http_response(response).unwrap();
+    assert_eq!(response.items()[0].name, "item");
+    assert_eq!(response.0.total_pages(), 3);
+    assert_eq!(response.next_page(), Some(Pagination::new(2, 1)));
+
+    let response = http::Response::builder().status(204).body("").unwrap();
+    assert!(DeleteResponse::try_from_http_response(response).is_ok());
+}
+
+#[test]
+#[cfg(all(feature = "movies", feature = "sync"))]
+fn request_try_from() {
+    fn build<R>(req: R) -> Result<http::Request<Vec<u8>>, trakt_rs::error::IntoHttpError>
+    where
+        R: trakt_rs::Request,
+    {
+        trakt_rs::WithContext::from((req, CTX)).try_into()
+    }
+
+    let req = build(trakt_rs::api::movies::summary::Request::new(Id::Trakt(1))).unwrap();
+    assert_eq!(req.uri(), "https://api.trakt.tv/movies/1");
+
+    let ctx = Context {
+        oauth_token: None,
+        ..CTX
+    };
+    let req = trakt_rs::api::sync::last_activities::Request;
+    assert!(http::Request::<Vec<u8>>::try_from(trakt_rs::WithContext::new(req, ctx)).is_err());
+}
+
+#[test]
+#[cfg(feature = "movies")]
+fn response_try_from() {
+    fn parse<R>(body: &'static str) -> Result<R, R::Error>
+    where
+        R: TryFrom<http::Response<&'static str>>,
+    {
+        http::Response::builder()
+            .status(200)
+            .body(body)
+            .unwrap()
+            .try_into()
+    }
+
+    let response: ShapeResponse = parse(r#"{"name":"a"}"#).unwrap();
+    assert!(matches!(response, ShapeResponse::One(ref item) if item.name == "a"));
+    assert!(parse::<ShapeResponse>("").is_err());
+
+    // Responses defined in the crate are converted the same way
+    let response = http::Response::builder()
+        .status(200)
+        .body(trakt_fixtures::STUDIOS)
+        .unwrap();
+    let response = trakt_rs::api::movies::studios::Response::try_from(response).unwrap();
+    assert_eq!(response.0.len(), 2);
+}
+
+#[test]
+fn custom_enum_response() {
+    use trakt_rs::Response;
+
+    let response = http::Response::builder()
+        .status(200)
+        .body(r#"[{"name":"a"},{"name":"b"}]"#)
+        .unwrap();
+    let response = ShapeResponse::try_from_http_response(response).unwrap();
+    assert!(matches!(response, ShapeResponse::Many(ref items) if items.len() == 2));
+
+    let response = http::Response::builder()
+        .status(200)
+        .body(r#"{"name":"a"}"#)
+        .unwrap();
+    let response = ShapeResponse::try_from_http_response(response).unwrap();
+    assert!(matches!(response, ShapeResponse::One(ref item) if item.name == "a"));
+
+    let options = trakt_rs::ParseOptions {
+        max_body_size: Some(8),
+    };
+    let response = http::Response::builder()
+        .status(200)
+        .body(r#"{"name":"a"}"#)
+        .unwrap();
+    assert!(matches!(
+        ShapeResponse::try_from_http_response_with(response, &options),
+        Err(trakt_rs::error::FromHttpError::Deserialize(
+            trakt_rs::error::DeserializeError::BodyTooLarge { .. }
+        ))
+    ));
+
+    let response = http::Response::builder()
+        .status(200)
+        .body(r#""a""#)
+        .unwrap();
+    assert!(ShapeResponse::try_from_http_response(response).is_err());
+
+    let response = http::Response::builder()
+        .status(404)
+        .body(r#"{"name":"a"}"#)
+        .unwrap();
+    assert!(ShapeResponse::try_from_http_response(response).is_err());
+
+    let response = http::Response::builder()
+        .status(201)
+        .body(r#"{"name":"a"}"#)
+        .unwrap();
+    assert!(CreatedShapeResponse::try_from_http_response(response).is_ok());
+    let response = http::Response::builder()
+        .status(200)
+        .body(r#"{"name":"a"}"#)
+        .unwrap();
+    assert!(CreatedShapeResponse::try_from_http_response(response).is_err());
+}
+
+/// Endpoints that don't fit the derives can still be implemented using the re-exported helpers.
+#[test]
+fn custom_helpers() {
+    #[derive(serde::Serialize)]
+    struct Path {
+        id: Id,
+    }
+
+    let url = trakt_rs::construct_url(
+        CTX.base_url,
+        "/custom/{id}",
+        &Path { id: Id::Trakt(1) },
+        &(),
+    )
+    .unwrap();
+    assert_eq!(url, "https://api.trakt.tv/custom/1");
+
+    let response = http::Response::builder()
+        .status(200)
+        .body("[1, 2]")
+        .unwrap();
+    let body: Vec<u32> = trakt_rs::handle_response_body(&response, http::StatusCode::OK).unwrap();
+    assert_eq!(body, [1, 2]);
+}
@@ -0,0 +1,85 @@
+//! Property tests for deserializing malformed API responses.
+
+use proptest::prelude::*;
+use serde_json::Value;
+use trakt_rs::smo::{CommentWithItem, Country, Distribution};
+
+/// Arbitrary JSON, biased towards the keys used by the models under test.
+fn json_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::from),
+        any::<i64>().prop_map(Value::from),
+        any::<u32>().prop_map(Value::from),
+        any::<f64>().prop_map(Value::from),
+        "\\PC{0,8}".prop_map(Value::from),
+        prop_oneof![Just("movie"), Just("show"), Just("list")].prop_map(Value::from),
+    ];
+    let key = prop_oneof![
+        Just("type".to_owned()),
+        Just("comment".to_owned()),
+        Just("movie".to_owned()),
+        Just("ids".to_owned()),
+        (0..=11u8).prop_map(|n| n.to_string()),
+        "\\PC{0,8}",
+    ];
+    leaf.prop_recursive(4, 64, 8, move |inner| {
+        prop_oneof![
+            proptest::collection::vec(inner.clone(), 0..12).prop_map(Value::Array),
+            proptest::collection::btree_map(key.clone(), inner, 0..12)
+                .prop_map(|map| Value::Object(map.into_iter().collect())),
+        ]
+    })
+}
+
+proptest! {
+    #[test]
+    fn two_letter(s in "\\PC{0,4}") {
+        let json = serde_json::to_string(&s).unwrap();
+        match serde_json::from_str::<Country>(&json) {
+            Ok(code) => prop_assert_eq!(code.as_str(), s.as_str()),
+            Err(_) => prop_assert!(s.len() != 2 || !s.bytes().all(|b| b.is_ascii_alphabetic())),
+        }
+    }
+
+    #[test]
+    fn two_letter_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..8)) {
+        if let Ok(code) = serde_json::from_slice::<Country>(&bytes) {
+            prop_assert!(code.as_str().bytes().all(|b| b.is_ascii_alphabetic()));
+        }
+    }
+
+    #[test]
+    fn distribution_seq(values in proptest::collection::vec(any::<u32>(), 0..16)) {
+        let res = serde_json::from_value::<Distribution>(Value::from(values.clone()));
+        if values.len() <= 10 {
+            let dist = res.unwrap();
+            prop_assert_eq!(&dist.0[..values.len()], values.as_slice());
+        } else {
+            prop_assert!(res.is_err());
+        }
+    }
+
+    #[test]
+    fn distribution_map(values in proptest::collection::btree_map("\\PC{0,3}", any::<u32>(), 0..12)) {
+        let valid = values
+            .keys()
+            .all(|k| k.parse::<u8>().is_ok_and(|n| (1..=10).contains(&n)));
+        let json = Value::Object(values.into_iter().map(|(k, v)| (k, v.into())).collect());
+        prop_assert_eq!(serde_json::from_value::<Distribution>(json).is_ok(), valid);
+    }
+
+    #[test]
+    fn arbitrary_json(value in json_value()) {
+        let _ = serde_json::from_value::<Distribution>(value.clone());
+        let _ = serde_json::from_value::<Country>(value.clone());
+        let _ = serde_json::from_value::<trakt_rs::EmojiString>(value.clone());
+        let _ = serde_json::from_value::<CommentWithItem>(value);
+    }
+
+    #[test]
+    fn arbitrary_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let _ = serde_json::from_slice::<Distribution>(&bytes);
+        let _ = serde_json::from_slice::<CommentWithItem>(&bytes);
+    }
+}